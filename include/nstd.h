@@ -1,17 +1,20 @@
 #ifndef NSTD_H
 #define NSTD_H
 #include "nstd/alloc.h"
+#include "nstd/condvar.h"
 #include "nstd/core.h"
 #include "nstd/cstring.h"
 #include "nstd/env.h"
 #include "nstd/fs.h"
 #include "nstd/heap_ptr.h"
 #include "nstd/io.h"
+#include "nstd/map.h"
 #include "nstd/math.h"
 #include "nstd/mutex.h"
 #include "nstd/nstd.h"
 #include "nstd/os.h"
 #include "nstd/proc.h"
+#include "nstd/rwlock.h"
 #include "nstd/shared_lib.h"
 #include "nstd/shared_ptr.h"
 #include "nstd/string.h"
@@ -6,6 +6,80 @@
 /// `nstd`'s default allocator.
 NSTDAPI const NSTDAllocator NSTD_ALLOCATOR;
 
+/// An allocator that fails every allocation request.
+///
+/// `allocate` and `allocate_zeroed` always return null, `reallocate` always returns
+/// `NSTD_ALLOC_ERROR_OUT_OF_MEMORY`, and `deallocate` accepts null pointers. This is useful for
+/// testing how code handles allocation failure.
+NSTDAPI const NSTDAllocator NSTD_NULL_ALLOCATOR;
+
+/// An allocator wrapper that keeps track of the number of live allocations and bytes in use.
+///
+/// Use `nstd_alloc_counting_allocator` to obtain an `NSTDAllocator` that forwards all requests to
+/// the wrapped allocator while updating these counters.
+///
+/// The fields of this structure are private. The counters are updated atomically and should only
+/// be read through `nstd_alloc_counting_bytes_in_use` and `nstd_alloc_counting_allocation_count`.
+typedef struct {
+    /// The wrapped allocator.
+    const NSTDAllocator *allocator;
+    /// The number of bytes currently allocated.
+    NSTDUInt bytes_in_use;
+    /// The number of live allocations.
+    NSTDUInt allocation_count;
+} NSTDCountingAllocator;
+
+/// Creates a new counting allocator that wraps `allocator`.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The allocator to forward allocation requests to.
+///
+/// # Returns
+///
+/// `NSTDCountingAllocator counting` - The new counting allocator with both of its counters set to
+/// zero.
+NSTDAPI NSTDCountingAllocator nstd_alloc_counting_new(const NSTDAllocator *allocator);
+
+/// Returns an `NSTDAllocator` that forwards allocation requests to the counting allocator's
+/// wrapped allocator while keeping track of them.
+///
+/// # Parameters:
+///
+/// - `const NSTDCountingAllocator *counting` - The counting allocator.
+///
+/// # Returns
+///
+/// `NSTDAllocator allocator` - The counting allocator's virtual function table.
+///
+/// # Safety
+///
+/// The returned allocator refers to `counting` directly, it must not be used after `counting` has
+/// been moved or freed.
+NSTDAPI NSTDAllocator nstd_alloc_counting_allocator(const NSTDCountingAllocator *counting);
+
+/// Returns the number of bytes currently allocated through a counting allocator.
+///
+/// # Parameters:
+///
+/// - `const NSTDCountingAllocator *counting` - The counting allocator.
+///
+/// # Returns
+///
+/// `NSTDUInt bytes_in_use` - The number of bytes that have been allocated but not yet freed.
+NSTDAPI NSTDUInt nstd_alloc_counting_bytes_in_use(const NSTDCountingAllocator *counting);
+
+/// Returns the number of live allocations made through a counting allocator.
+///
+/// # Parameters:
+///
+/// - `const NSTDCountingAllocator *counting` - The counting allocator.
+///
+/// # Returns
+///
+/// `NSTDUInt allocation_count` - The number of allocations that have not yet been freed.
+NSTDAPI NSTDUInt nstd_alloc_counting_allocation_count(const NSTDCountingAllocator *counting);
+
 /// Allocates a new block of memory.
 ///
 /// If allocation fails, a null pointer is returned.
@@ -0,0 +1,101 @@
+#ifndef NSTD_CONDVAR_H
+#define NSTD_CONDVAR_H
+#include "core/optional.h"
+#include "core/time.h"
+#include "mutex.h"
+#include "nstd.h"
+
+/// A condition variable used to block threads until a shared condition is met.
+///
+/// Condition variables are always used together with an `NSTDMutex`.
+typedef struct {
+    /// The Rust [Condvar].
+    NSTDAnyMut cvar;
+} NSTDCondVar;
+
+/// Represents an optional value of type `NSTDCondVar`.
+NSTDOptional(NSTDCondVar) NSTDOptionalCondVar;
+
+/// Creates a new condition variable.
+///
+/// # Returns
+///
+/// `NSTDOptionalCondVar cvar` - The new condition variable on success, or an uninitialized "none"
+/// variant on error.
+NSTDAPI NSTDOptionalCondVar nstd_condvar_new(void);
+
+/// Blocks the current thread until the condition variable is notified.
+///
+/// This function atomically unlocks the mutex guarded by `guard` and blocks the current thread.
+/// The mutex is locked again before this function returns.
+///
+/// Calls to this function may wake up spuriously, so the caller should re-check its condition
+/// after it returns.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+///
+/// - `NSTDMutexGuard guard` - The mutex guard.
+///
+/// # Returns
+///
+/// `NSTDOptionalMutexLockResult guard` - A handle to the mutex's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation may panic if `cvar` is used with more than one mutex.
+NSTDAPI NSTDOptionalMutexLockResult
+nstd_condvar_wait(const NSTDCondVar *cvar, NSTDMutexGuard guard);
+
+/// Blocks the current thread until the condition variable is notified or `duration` has elapsed.
+///
+/// This function atomically unlocks the mutex guarded by `guard` and blocks the current thread.
+/// The mutex is locked again before this function returns.
+///
+/// This function does not report whether or not the wait timed out, so the caller should re-check
+/// its condition after it returns.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+///
+/// - `NSTDMutexGuard guard` - The mutex guard.
+///
+/// - `NSTDDuration duration` - The maximum amount of time to block for.
+///
+/// # Returns
+///
+/// `NSTDOptionalMutexLockResult guard` - A handle to the mutex's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation will panic if `duration` is negative, overflows Rust's `Duration` structure, or
+/// is non-finite. It may also panic if `cvar` is used with more than one mutex.
+NSTDAPI NSTDOptionalMutexLockResult
+nstd_condvar_wait_timeout(const NSTDCondVar *cvar, NSTDMutexGuard guard, NSTDDuration duration);
+
+/// Wakes up one thread blocked on a condition variable.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+NSTDAPI void nstd_condvar_notify_one(const NSTDCondVar *cvar);
+
+/// Wakes up all threads blocked on a condition variable.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+NSTDAPI void nstd_condvar_notify_all(const NSTDCondVar *cvar);
+
+/// Frees an instance of `NSTDCondVar`.
+///
+/// # Parameters:
+///
+/// - `NSTDCondVar cvar` - The condition variable to free.
+NSTDAPI void nstd_condvar_free(NSTDCondVar cvar);
+
+#endif
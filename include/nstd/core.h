@@ -6,6 +6,7 @@
 #include "core/cty.h"
 #include "core/def.h"
 #include "core/fty.h"
+#include "core/hash.h"
 #include "core/ity.h"
 #include "core/math.h"
 #include "core/mem.h"
@@ -2,6 +2,7 @@
 #define NSTD_CORE_ALLOC_H
 #include "../nstd.h"
 #include "optional.h"
+#include "slice.h"
 
 /// Describes a valid layout for a block of memory.
 typedef struct {
@@ -30,6 +31,9 @@ typedef enum {
     NSTD_ALLOC_ERROR_INVALID_LAYOUT
 } NSTDAllocError;
 
+/// Represents an optional value of type `NSTDAllocError`.
+NSTDOptional(NSTDAllocError) NSTDOptionalAllocError;
+
 /// A structure of function pointers making up an allocator's virtual function table.
 typedef struct {
     /// An opaque pointer to the allocator's state.
@@ -223,4 +227,57 @@ NSTDAPI NSTDUInt nstd_core_alloc_layout_size(NSTDAllocLayout layout);
 /// `NSTDUInt align` - The layout's alignment.
 NSTDAPI NSTDUInt nstd_core_alloc_layout_align(NSTDAllocLayout layout);
 
+/// An allocator that satisfies allocation requests from a fixed, caller provided buffer.
+///
+/// Memory is handed out linearly from the start of the buffer. Freeing or shrinking the most
+/// recent allocation makes its memory available again, any other freed memory is only reclaimed
+/// once every allocation made after it has also been freed.
+///
+/// The fields of this structure are private and should only be initialized by
+/// `nstd_core_alloc_buffer_allocator_new`.
+typedef struct {
+    /// A pointer to the start of the buffer.
+    NSTDAnyMut buffer;
+    /// The buffer's length in bytes.
+    NSTDUInt len;
+    /// The number of bytes from the start of the buffer that are in use.
+    ///
+    /// This field is updated atomically by the allocator and must never be read or written
+    /// directly.
+    NSTDUInt offset;
+} NSTDBufferAllocator;
+
+/// Creates a new allocator that hands out memory from `buffer`.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut buffer` - The memory buffer to allocate from.
+///
+/// # Returns
+///
+/// `NSTDBufferAllocator allocator` - The new buffer allocator.
+///
+/// # Safety
+///
+/// `buffer`'s data must remain valid for reads and writes, and must not be accessed through any
+/// other means, for as long as the allocator or any memory allocated by it is in use.
+NSTDAPI NSTDBufferAllocator nstd_core_alloc_buffer_allocator_new(NSTDSliceMut buffer);
+
+/// Returns an `NSTDAllocator` that allocates memory from a buffer allocator.
+///
+/// # Parameters:
+///
+/// - `const NSTDBufferAllocator *buffer` - The buffer allocator.
+///
+/// # Returns
+///
+/// `NSTDAllocator allocator` - The buffer allocator's virtual function table.
+///
+/// # Safety
+///
+/// The returned allocator refers to `buffer` directly, it must not be used after `buffer` has
+/// been moved or freed.
+NSTDAPI NSTDAllocator
+nstd_core_alloc_buffer_allocator_as_allocator(const NSTDBufferAllocator *buffer);
+
 #endif
@@ -0,0 +1,65 @@
+#ifndef NSTD_CORE_HASH_H
+#define NSTD_CORE_HASH_H
+#include "../nstd.h"
+#include "slice.h"
+
+/// A streaming 64-bit FNV-1a hasher.
+///
+/// FNV-1a is a fast non-cryptographic hash function, it must not be used where resistance to
+/// malicious input is required.
+typedef struct {
+    /// The current hash state.
+    NSTDUInt64 state;
+} NSTDHasher;
+
+/// Creates a new `NSTDHasher` in its initial state.
+///
+/// # Returns
+///
+/// `NSTDHasher hasher` - The new hasher.
+NSTDAPI NSTDHasher nstd_core_hash_new(void);
+
+/// Feeds all of the bytes covered by `bytes` into a hasher.
+///
+/// The slice's stride is ignored, every one of the slice's `len * stride` bytes is hashed.
+///
+/// # Parameters:
+///
+/// - `NSTDHasher *hasher` - The hasher.
+///
+/// - `const NSTDSlice *bytes` - The data to hash.
+///
+/// # Safety
+///
+/// `bytes`'s data must be valid for reads.
+NSTDAPI void nstd_core_hash_write(NSTDHasher *hasher, const NSTDSlice *bytes);
+
+/// Returns the hash of all of the data written to a hasher so far.
+///
+/// # Parameters:
+///
+/// - `const NSTDHasher *hasher` - The hasher.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The hash value.
+NSTDAPI NSTDUInt64 nstd_core_hash_finish(const NSTDHasher *hasher);
+
+/// Computes the 64-bit FNV-1a hash of all of the bytes covered by `bytes`.
+///
+/// This is equivalent to writing `bytes` to a new `NSTDHasher` and finishing it.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *bytes` - The data to hash.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The hash value.
+///
+/// # Safety
+///
+/// `bytes`'s data must be valid for reads.
+NSTDAPI NSTDUInt64 nstd_core_hash_bytes(const NSTDSlice *bytes);
+
+#endif
@@ -3,6 +3,16 @@
 #include "../nstd.h"
 #include "optional.h"
 
+/// Archimedes' constant (π) as an `NSTDFloat32`.
+#define NSTD_CORE_MATH_PI_F32 3.14159265358979323846264338327950288F
+/// Archimedes' constant (π) as an `NSTDFloat64`.
+#define NSTD_CORE_MATH_PI_F64 3.14159265358979323846264338327950288
+
+/// The full circle constant (τ = 2π) as an `NSTDFloat32`.
+#define NSTD_CORE_MATH_TAU_F32 6.28318530717958647692528676655900577F
+/// The full circle constant (τ = 2π) as an `NSTDFloat64`.
+#define NSTD_CORE_MATH_TAU_F64 6.28318530717958647692528676655900577
+
 /// Converts radians to degrees.
 ///
 /// # Parameters:
@@ -45,6 +55,39 @@ NSTDAPI NSTDFloat32 nstd_core_math_rad_f32(NSTDFloat32 deg);
 /// `NSTDFloat64 rad` - The degrees value converted to radians.
 NSTDAPI NSTDFloat64 nstd_core_math_rad_f64(NSTDFloat64 deg);
 
+/// Linearly interpolates between `a` and `b`.
+///
+/// This is exact at the endpoints (`t == 0` returns `a` & `t == 1` returns `b`).
+///
+/// # Parameters:
+///
+/// - `NSTDFloat32 a` - The start value.
+///
+/// - `NSTDFloat32 b` - The end value.
+///
+/// - `NSTDFloat32 t` - The interpolation factor, where 0 maps to `a` and 1 maps to `b`.
+///
+/// # Returns
+///
+/// `NSTDFloat32 v` - The interpolated value.
+NSTDAPI NSTDFloat32 nstd_core_math_lerp_f32(NSTDFloat32 a, NSTDFloat32 b, NSTDFloat32 t);
+/// Linearly interpolates between `a` and `b`.
+///
+/// This is exact at the endpoints (`t == 0` returns `a` & `t == 1` returns `b`).
+///
+/// # Parameters:
+///
+/// - `NSTDFloat64 a` - The start value.
+///
+/// - `NSTDFloat64 b` - The end value.
+///
+/// - `NSTDFloat64 t` - The interpolation factor, where 0 maps to `a` and 1 maps to `b`.
+///
+/// # Returns
+///
+/// `NSTDFloat64 v` - The interpolated value.
+NSTDAPI NSTDFloat64 nstd_core_math_lerp_f64(NSTDFloat64 a, NSTDFloat64 b, NSTDFloat64 t);
+
 /// Computes the absolute (positive) value of `x`.
 ///
 /// # Parameters:
@@ -217,6 +260,137 @@ NSTDAPI NSTDInt64 nstd_core_math_pow_i64(NSTDInt64 x, NSTDUInt32 exp);
 /// `NSTDUInt64 pow` - `x` raised to the power of `exp`.
 NSTDAPI NSTDUInt64 nstd_core_math_pow_u64(NSTDUInt64 x, NSTDUInt32 exp);
 
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalInt nstd_core_math_checked_pow_int(NSTDInt x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalUInt nstd_core_math_checked_pow_uint(NSTDUInt x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt8 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalInt8 nstd_core_math_checked_pow_i8(NSTDInt8 x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt8 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalUInt8 nstd_core_math_checked_pow_u8(NSTDUInt8 x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt16 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalInt16 nstd_core_math_checked_pow_i16(NSTDInt16 x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt16 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalUInt16 nstd_core_math_checked_pow_u16(NSTDUInt16 x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt32 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalInt32 nstd_core_math_checked_pow_i32(NSTDInt32 x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt32 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalUInt32 nstd_core_math_checked_pow_u32(NSTDUInt32 x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt64 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalInt64 nstd_core_math_checked_pow_i64(NSTDInt64 x, NSTDUInt32 exp);
+/// Raises the value `x` to the power of `exp`, checking for overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The value.
+///
+/// - `NSTDUInt32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt64 pow` - `x` raised to the power of `exp` on success, or an uninitialized
+/// "none" variant on overflow.
+NSTDAPI NSTDOptionalUInt64 nstd_core_math_checked_pow_u64(NSTDUInt64 x, NSTDUInt32 exp);
+
 /// Clamps the value `x` to the bounds `min` and `max`.
 ///
 /// # Parameters:
@@ -231,6 +405,10 @@ NSTDAPI NSTDUInt64 nstd_core_math_pow_u64(NSTDUInt64 x, NSTDUInt32 exp);
 ///
 /// `NSTDFloat32 v` - The clamped value.
 ///
+/// # Note
+///
+/// If `x` is NaN, NaN is returned.
+///
 /// # Panics
 ///
 /// Panics if `min` > `max`, `min` is NaN, or `max` is NaN.
@@ -249,6 +427,10 @@ NSTDAPI NSTDFloat32 nstd_core_math_clamp_f32(NSTDFloat32 x, NSTDFloat32 min, NST
 ///
 /// `NSTDFloat64 v` - The clamped value.
 ///
+/// # Note
+///
+/// If `x` is NaN, NaN is returned.
+///
 /// # Panics
 ///
 /// Panics if `min` > `max`, `min` is NaN, or `max` is NaN.
@@ -976,4 +976,1718 @@ NSTDAPI NSTDOptionalInt64 nstd_core_ops_shr_i64(NSTDInt64 x, NSTDUInt32 y);
 /// variant on overflow.
 NSTDAPI NSTDOptionalUInt64 nstd_core_ops_shr_u64(NSTDUInt64 x, NSTDUInt32 y);
 
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_saturating_add_int(NSTDInt x, NSTDInt y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_saturating_add_uint(NSTDUInt x, NSTDUInt y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_saturating_add_i8(NSTDInt8 x, NSTDInt8 y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_saturating_add_u8(NSTDUInt8 x, NSTDUInt8 y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_saturating_add_i16(NSTDInt16 x, NSTDInt16 y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The result of the operation.
+NSTDAPI NSTDUInt16 nstd_core_ops_saturating_add_u16(NSTDUInt16 x, NSTDUInt16 y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_saturating_add_i32(NSTDInt32 x, NSTDInt32 y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The result of the operation.
+NSTDAPI NSTDUInt32 nstd_core_ops_saturating_add_u32(NSTDUInt32 x, NSTDUInt32 y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_saturating_add_i64(NSTDInt64 x, NSTDInt64 y);
+/// Computes the saturating addition operation of `x` + `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The result of the operation.
+NSTDAPI NSTDUInt64 nstd_core_ops_saturating_add_u64(NSTDUInt64 x, NSTDUInt64 y);
+
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_saturating_sub_int(NSTDInt x, NSTDInt y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_saturating_sub_uint(NSTDUInt x, NSTDUInt y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_saturating_sub_i8(NSTDInt8 x, NSTDInt8 y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_saturating_sub_u8(NSTDUInt8 x, NSTDUInt8 y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_saturating_sub_i16(NSTDInt16 x, NSTDInt16 y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The result of the operation.
+NSTDAPI NSTDUInt16 nstd_core_ops_saturating_sub_u16(NSTDUInt16 x, NSTDUInt16 y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_saturating_sub_i32(NSTDInt32 x, NSTDInt32 y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The result of the operation.
+NSTDAPI NSTDUInt32 nstd_core_ops_saturating_sub_u32(NSTDUInt32 x, NSTDUInt32 y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_saturating_sub_i64(NSTDInt64 x, NSTDInt64 y);
+/// Computes the saturating subtraction operation of `x` - `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The result of the operation.
+NSTDAPI NSTDUInt64 nstd_core_ops_saturating_sub_u64(NSTDUInt64 x, NSTDUInt64 y);
+
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_saturating_mul_int(NSTDInt x, NSTDInt y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_saturating_mul_uint(NSTDUInt x, NSTDUInt y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_saturating_mul_i8(NSTDInt8 x, NSTDInt8 y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_saturating_mul_u8(NSTDUInt8 x, NSTDUInt8 y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_saturating_mul_i16(NSTDInt16 x, NSTDInt16 y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The result of the operation.
+NSTDAPI NSTDUInt16 nstd_core_ops_saturating_mul_u16(NSTDUInt16 x, NSTDUInt16 y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_saturating_mul_i32(NSTDInt32 x, NSTDInt32 y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The result of the operation.
+NSTDAPI NSTDUInt32 nstd_core_ops_saturating_mul_u32(NSTDUInt32 x, NSTDUInt32 y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_saturating_mul_i64(NSTDInt64 x, NSTDInt64 y);
+/// Computes the saturating multiplication operation of `x` * `y`.
+///
+/// The result is clamped to the type's minimum or maximum value on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The result of the operation.
+NSTDAPI NSTDUInt64 nstd_core_ops_saturating_mul_u64(NSTDUInt64 x, NSTDUInt64 y);
+
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_wrapping_add_int(NSTDInt x, NSTDInt y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_wrapping_add_uint(NSTDUInt x, NSTDUInt y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_wrapping_add_i8(NSTDInt8 x, NSTDInt8 y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_wrapping_add_u8(NSTDUInt8 x, NSTDUInt8 y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_wrapping_add_i16(NSTDInt16 x, NSTDInt16 y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The result of the operation.
+NSTDAPI NSTDUInt16 nstd_core_ops_wrapping_add_u16(NSTDUInt16 x, NSTDUInt16 y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_wrapping_add_i32(NSTDInt32 x, NSTDInt32 y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The result of the operation.
+NSTDAPI NSTDUInt32 nstd_core_ops_wrapping_add_u32(NSTDUInt32 x, NSTDUInt32 y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_wrapping_add_i64(NSTDInt64 x, NSTDInt64 y);
+/// Computes the wrapping addition operation of `x` + `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The result of the operation.
+NSTDAPI NSTDUInt64 nstd_core_ops_wrapping_add_u64(NSTDUInt64 x, NSTDUInt64 y);
+
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_wrapping_sub_int(NSTDInt x, NSTDInt y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_wrapping_sub_uint(NSTDUInt x, NSTDUInt y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_wrapping_sub_i8(NSTDInt8 x, NSTDInt8 y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_wrapping_sub_u8(NSTDUInt8 x, NSTDUInt8 y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_wrapping_sub_i16(NSTDInt16 x, NSTDInt16 y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The result of the operation.
+NSTDAPI NSTDUInt16 nstd_core_ops_wrapping_sub_u16(NSTDUInt16 x, NSTDUInt16 y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_wrapping_sub_i32(NSTDInt32 x, NSTDInt32 y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The result of the operation.
+NSTDAPI NSTDUInt32 nstd_core_ops_wrapping_sub_u32(NSTDUInt32 x, NSTDUInt32 y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_wrapping_sub_i64(NSTDInt64 x, NSTDInt64 y);
+/// Computes the wrapping subtraction operation of `x` - `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The result of the operation.
+NSTDAPI NSTDUInt64 nstd_core_ops_wrapping_sub_u64(NSTDUInt64 x, NSTDUInt64 y);
+
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_wrapping_mul_int(NSTDInt x, NSTDInt y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_wrapping_mul_uint(NSTDUInt x, NSTDUInt y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_wrapping_mul_i8(NSTDInt8 x, NSTDInt8 y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_wrapping_mul_u8(NSTDUInt8 x, NSTDUInt8 y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_wrapping_mul_i16(NSTDInt16 x, NSTDInt16 y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The result of the operation.
+NSTDAPI NSTDUInt16 nstd_core_ops_wrapping_mul_u16(NSTDUInt16 x, NSTDUInt16 y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_wrapping_mul_i32(NSTDInt32 x, NSTDInt32 y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The result of the operation.
+NSTDAPI NSTDUInt32 nstd_core_ops_wrapping_mul_u32(NSTDUInt32 x, NSTDUInt32 y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_wrapping_mul_i64(NSTDInt64 x, NSTDInt64 y);
+/// Computes the wrapping multiplication operation of `x` * `y`.
+///
+/// The result wraps around at the boundary of the type on overflow.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The result of the operation.
+NSTDAPI NSTDUInt64 nstd_core_ops_wrapping_mul_u64(NSTDUInt64 x, NSTDUInt64 y);
+
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The wrapped result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_overflowing_add_int(NSTDInt x, NSTDInt y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_overflowing_add_uint(NSTDUInt x, NSTDUInt y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_overflowing_add_i8(NSTDInt8 x, NSTDInt8 y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_overflowing_add_u8(NSTDUInt8 x, NSTDUInt8 y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_overflowing_add_i16(NSTDInt16 x, NSTDInt16 y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt16
+nstd_core_ops_overflowing_add_u16(NSTDUInt16 x, NSTDUInt16 y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_overflowing_add_i32(NSTDInt32 x, NSTDInt32 y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt32
+nstd_core_ops_overflowing_add_u32(NSTDUInt32 x, NSTDUInt32 y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_overflowing_add_i64(NSTDInt64 x, NSTDInt64 y, NSTDBool *overflowed);
+/// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt64
+nstd_core_ops_overflowing_add_u64(NSTDUInt64 x, NSTDUInt64 y, NSTDBool *overflowed);
+
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The wrapped result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_overflowing_sub_int(NSTDInt x, NSTDInt y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_overflowing_sub_uint(NSTDUInt x, NSTDUInt y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_overflowing_sub_i8(NSTDInt8 x, NSTDInt8 y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_overflowing_sub_u8(NSTDUInt8 x, NSTDUInt8 y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_overflowing_sub_i16(NSTDInt16 x, NSTDInt16 y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt16
+nstd_core_ops_overflowing_sub_u16(NSTDUInt16 x, NSTDUInt16 y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_overflowing_sub_i32(NSTDInt32 x, NSTDInt32 y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt32
+nstd_core_ops_overflowing_sub_u32(NSTDUInt32 x, NSTDUInt32 y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_overflowing_sub_i64(NSTDInt64 x, NSTDInt64 y, NSTDBool *overflowed);
+/// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt64
+nstd_core_ops_overflowing_sub_u64(NSTDUInt64 x, NSTDUInt64 y, NSTDBool *overflowed);
+
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt x` - The left operand.
+///
+/// - `NSTDInt y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt z` - The wrapped result of the operation.
+NSTDAPI NSTDInt nstd_core_ops_overflowing_mul_int(NSTDInt x, NSTDInt y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The left operand.
+///
+/// - `NSTDUInt y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt nstd_core_ops_overflowing_mul_uint(NSTDUInt x, NSTDUInt y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt8 x` - The left operand.
+///
+/// - `NSTDInt8 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt8 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt8 nstd_core_ops_overflowing_mul_i8(NSTDInt8 x, NSTDInt8 y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The left operand.
+///
+/// - `NSTDUInt8 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt8 nstd_core_ops_overflowing_mul_u8(NSTDUInt8 x, NSTDUInt8 y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt16 x` - The left operand.
+///
+/// - `NSTDInt16 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt16 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt16 nstd_core_ops_overflowing_mul_i16(NSTDInt16 x, NSTDInt16 y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The left operand.
+///
+/// - `NSTDUInt16 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt16
+nstd_core_ops_overflowing_mul_u16(NSTDUInt16 x, NSTDUInt16 y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt32 x` - The left operand.
+///
+/// - `NSTDInt32 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt32 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt32 nstd_core_ops_overflowing_mul_i32(NSTDInt32 x, NSTDInt32 y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The left operand.
+///
+/// - `NSTDUInt32 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt32
+nstd_core_ops_overflowing_mul_u32(NSTDUInt32 x, NSTDUInt32 y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 x` - The left operand.
+///
+/// - `NSTDInt64 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDInt64 z` - The wrapped result of the operation.
+NSTDAPI NSTDInt64 nstd_core_ops_overflowing_mul_i64(NSTDInt64 x, NSTDInt64 y, NSTDBool *overflowed);
+/// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+/// overflow occurred.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The left operand.
+///
+/// - `NSTDUInt64 y` - The right operand.
+///
+/// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+/// `NSTD_FALSE`.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The wrapped result of the operation.
+NSTDAPI NSTDUInt64
+nstd_core_ops_overflowing_mul_u64(NSTDUInt64 x, NSTDUInt64 y, NSTDBool *overflowed);
+
+/// Returns the number of ones in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_ones` - The number of ones in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_ones_uint(NSTDUInt x);
+/// Returns the number of ones in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_ones` - The number of ones in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_ones_u8(NSTDUInt8 x);
+/// Returns the number of ones in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_ones` - The number of ones in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_ones_u16(NSTDUInt16 x);
+/// Returns the number of ones in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_ones` - The number of ones in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_ones_u32(NSTDUInt32 x);
+/// Returns the number of ones in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_ones` - The number of ones in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_ones_u64(NSTDUInt64 x);
+
+/// Returns the number of zeros in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_zeros` - The number of zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_zeros_uint(NSTDUInt x);
+/// Returns the number of zeros in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_zeros` - The number of zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_zeros_u8(NSTDUInt8 x);
+/// Returns the number of zeros in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_zeros` - The number of zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_zeros_u16(NSTDUInt16 x);
+/// Returns the number of zeros in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_zeros` - The number of zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_zeros_u32(NSTDUInt32 x);
+/// Returns the number of zeros in the binary representation of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count_zeros` - The number of zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_count_zeros_u64(NSTDUInt64 x);
+
+/// Returns the number of leading zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 leading_zeros` - The number of leading zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_leading_zeros_uint(NSTDUInt x);
+/// Returns the number of leading zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 leading_zeros` - The number of leading zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_leading_zeros_u8(NSTDUInt8 x);
+/// Returns the number of leading zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 leading_zeros` - The number of leading zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_leading_zeros_u16(NSTDUInt16 x);
+/// Returns the number of leading zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 leading_zeros` - The number of leading zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_leading_zeros_u32(NSTDUInt32 x);
+/// Returns the number of leading zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 leading_zeros` - The number of leading zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_leading_zeros_u64(NSTDUInt64 x);
+
+/// Returns the number of trailing zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 trailing_zeros` - The number of trailing zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_trailing_zeros_uint(NSTDUInt x);
+/// Returns the number of trailing zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 trailing_zeros` - The number of trailing zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_trailing_zeros_u8(NSTDUInt8 x);
+/// Returns the number of trailing zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 trailing_zeros` - The number of trailing zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_trailing_zeros_u16(NSTDUInt16 x);
+/// Returns the number of trailing zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 trailing_zeros` - The number of trailing zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_trailing_zeros_u32(NSTDUInt32 x);
+/// Returns the number of trailing zeros in the binary representation of `x`.
+///
+/// If `x` is 0, this returns the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The value to inspect.
+///
+/// # Returns
+///
+/// `NSTDUInt32 trailing_zeros` - The number of trailing zeros in `x`.
+NSTDAPI NSTDUInt32 nstd_core_ops_trailing_zeros_u64(NSTDUInt64 x);
+
+/// Rotates the bits of `x` `n` bits to the left, wrapping the truncated bits around to the
+/// end of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The rotated value.
+NSTDAPI NSTDUInt nstd_core_ops_rotate_left_uint(NSTDUInt x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the left, wrapping the truncated bits around to the
+/// end of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The rotated value.
+NSTDAPI NSTDUInt8 nstd_core_ops_rotate_left_u8(NSTDUInt8 x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the left, wrapping the truncated bits around to the
+/// end of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The rotated value.
+NSTDAPI NSTDUInt16 nstd_core_ops_rotate_left_u16(NSTDUInt16 x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the left, wrapping the truncated bits around to the
+/// end of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The rotated value.
+NSTDAPI NSTDUInt32 nstd_core_ops_rotate_left_u32(NSTDUInt32 x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the left, wrapping the truncated bits around to the
+/// end of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The rotated value.
+NSTDAPI NSTDUInt64 nstd_core_ops_rotate_left_u64(NSTDUInt64 x, NSTDUInt32 n);
+
+/// Rotates the bits of `x` `n` bits to the right, wrapping the truncated bits around to the
+/// beginning of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt z` - The rotated value.
+NSTDAPI NSTDUInt nstd_core_ops_rotate_right_uint(NSTDUInt x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the right, wrapping the truncated bits around to the
+/// beginning of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt8 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt8 z` - The rotated value.
+NSTDAPI NSTDUInt8 nstd_core_ops_rotate_right_u8(NSTDUInt8 x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the right, wrapping the truncated bits around to the
+/// beginning of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt16 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt16 z` - The rotated value.
+NSTDAPI NSTDUInt16 nstd_core_ops_rotate_right_u16(NSTDUInt16 x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the right, wrapping the truncated bits around to the
+/// beginning of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt32 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt32 z` - The rotated value.
+NSTDAPI NSTDUInt32 nstd_core_ops_rotate_right_u32(NSTDUInt32 x, NSTDUInt32 n);
+/// Rotates the bits of `x` `n` bits to the right, wrapping the truncated bits around to the
+/// beginning of the value.
+///
+/// `n` is taken modulo the type's bit width.
+///
+/// # Parameters:
+///
+/// - `NSTDUInt64 x` - The value to rotate.
+///
+/// - `NSTDUInt32 n` - The number of bits to rotate by.
+///
+/// # Returns
+///
+/// `NSTDUInt64 z` - The rotated value.
+NSTDAPI NSTDUInt64 nstd_core_ops_rotate_right_u64(NSTDUInt64 x, NSTDUInt32 n);
+
 #endif
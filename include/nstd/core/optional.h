@@ -16,6 +16,18 @@
         } value;          \
     }
 
+/// Determines whether or not an `NSTDOptional` holds an initialized value.
+#define nstd_core_optional_is_some(opt) ((opt).status == NSTD_OPTIONAL_SOME)
+
+/// Determines whether or not an `NSTDOptional` is uninitialized.
+#define nstd_core_optional_is_none(opt) ((opt).status == NSTD_OPTIONAL_NONE)
+
+/// Returns the value held by an `NSTDOptional`, or `default` if it is uninitialized.
+///
+/// `opt` is evaluated more than once, so it must not have side effects.
+#define nstd_core_optional_unwrap_or(opt, default) \
+    (nstd_core_optional_is_some(opt) ? (opt).value.some : (default))
+
 /// Represents an optional value of type `NSTDAny`.
 NSTDOptional(NSTDAny) NSTDOptionalAny;
 /// Represents an optional value of type `NSTDAnyMut`.
@@ -65,4 +77,278 @@ NSTDOptional(NSTDAnyRef) NSTDOptionalAnyRef;
 /// Represents an optional value of type `NSTDAnyRefMut`.
 NSTDOptional(NSTDAnyRefMut) NSTDOptionalAnyRefMut;
 
+/// Applies `f` to the value held by an `NSTDOptionalInt`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDInt`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalInt opt` - The optional value.
+///
+/// - `NSTDInt (*f)(NSTDInt)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalInt nstd_core_optional_map_int(NSTDOptionalInt opt, NSTDInt (*f)(NSTDInt));
+
+/// Applies `f` to the value held by an `NSTDOptionalUInt`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDUInt`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalUInt opt` - The optional value.
+///
+/// - `NSTDUInt (*f)(NSTDUInt)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalUInt
+nstd_core_optional_map_uint(NSTDOptionalUInt opt, NSTDUInt (*f)(NSTDUInt));
+
+/// Applies `f` to the value held by an `NSTDOptionalInt8`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDInt8`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalInt8 opt` - The optional value.
+///
+/// - `NSTDInt8 (*f)(NSTDInt8)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt8 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalInt8 nstd_core_optional_map_i8(NSTDOptionalInt8 opt, NSTDInt8 (*f)(NSTDInt8));
+
+/// Applies `f` to the value held by an `NSTDOptionalUInt8`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDUInt8`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalUInt8 opt` - The optional value.
+///
+/// - `NSTDUInt8 (*f)(NSTDUInt8)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt8 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalUInt8
+nstd_core_optional_map_u8(NSTDOptionalUInt8 opt, NSTDUInt8 (*f)(NSTDUInt8));
+
+/// Applies `f` to the value held by an `NSTDOptionalInt16`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDInt16`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalInt16 opt` - The optional value.
+///
+/// - `NSTDInt16 (*f)(NSTDInt16)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt16 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalInt16
+nstd_core_optional_map_i16(NSTDOptionalInt16 opt, NSTDInt16 (*f)(NSTDInt16));
+
+/// Applies `f` to the value held by an `NSTDOptionalUInt16`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDUInt16`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalUInt16 opt` - The optional value.
+///
+/// - `NSTDUInt16 (*f)(NSTDUInt16)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt16 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalUInt16
+nstd_core_optional_map_u16(NSTDOptionalUInt16 opt, NSTDUInt16 (*f)(NSTDUInt16));
+
+/// Applies `f` to the value held by an `NSTDOptionalInt32`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDInt32`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalInt32 opt` - The optional value.
+///
+/// - `NSTDInt32 (*f)(NSTDInt32)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt32 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalInt32
+nstd_core_optional_map_i32(NSTDOptionalInt32 opt, NSTDInt32 (*f)(NSTDInt32));
+
+/// Applies `f` to the value held by an `NSTDOptionalUInt32`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDUInt32`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalUInt32 opt` - The optional value.
+///
+/// - `NSTDUInt32 (*f)(NSTDUInt32)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt32 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalUInt32
+nstd_core_optional_map_u32(NSTDOptionalUInt32 opt, NSTDUInt32 (*f)(NSTDUInt32));
+
+/// Applies `f` to the value held by an `NSTDOptionalInt64`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDInt64`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalInt64 opt` - The optional value.
+///
+/// - `NSTDInt64 (*f)(NSTDInt64)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalInt64 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalInt64
+nstd_core_optional_map_i64(NSTDOptionalInt64 opt, NSTDInt64 (*f)(NSTDInt64));
+
+/// Applies `f` to the value held by an `NSTDOptionalUInt64`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDUInt64`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalUInt64 opt` - The optional value.
+///
+/// - `NSTDUInt64 (*f)(NSTDUInt64)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt64 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalUInt64
+nstd_core_optional_map_u64(NSTDOptionalUInt64 opt, NSTDUInt64 (*f)(NSTDUInt64));
+
+/// Applies `f` to the value held by an `NSTDOptionalFloat32`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDFloat32`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalFloat32 opt` - The optional value.
+///
+/// - `NSTDFloat32 (*f)(NSTDFloat32)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalFloat32 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalFloat32
+nstd_core_optional_map_f32(NSTDOptionalFloat32 opt, NSTDFloat32 (*f)(NSTDFloat32));
+
+/// Applies `f` to the value held by an `NSTDOptionalFloat64`.
+///
+/// # Note
+///
+/// `f` must return another `NSTDFloat64`, mapping to a different type is not supported.
+///
+/// # Parameters:
+///
+/// - `NSTDOptionalFloat64 opt` - The optional value.
+///
+/// - `NSTDFloat64 (*f)(NSTDFloat64)` - The function to apply to the contained value.
+///
+/// # Returns
+///
+/// `NSTDOptionalFloat64 mapped` - The value returned from `f` if `opt` holds a value, otherwise an
+/// uninitialized "none" variant.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`f`).
+NSTDAPI NSTDOptionalFloat64
+nstd_core_optional_map_f64(NSTDOptionalFloat64 opt, NSTDFloat64 (*f)(NSTDFloat64));
+
 #endif
@@ -1,7 +1,9 @@
 #ifndef NSTD_CORE_SLICE_H
 #define NSTD_CORE_SLICE_H
 #include "../nstd.h"
+#include "def.h"
 #include "optional.h"
+#include "range.h"
 
 /// An immutable view into a sequence of values in memory.
 typedef struct {
@@ -119,6 +121,22 @@ NSTDAPI NSTDUInt nstd_core_slice_len(const NSTDSlice *slice);
 /// `NSTDUInt stride` - The size of each value in the slice.
 NSTDAPI NSTDUInt nstd_core_slice_stride(const NSTDSlice *slice);
 
+/// Checks that each value in a slice occupies exactly `expected` bytes.
+///
+/// This is the recommended guard for validating a slice's element layout at FFI boundaries,
+/// before the slice's elements are read as a specific type.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice.
+///
+/// - `NSTDUInt expected` - The expected size of each value in the slice.
+///
+/// # Returns
+///
+/// `NSTDBool is_valid` - True if `slice`'s stride is equal to `expected`.
+NSTDAPI NSTDBool nstd_core_slice_check_stride(const NSTDSlice *slice, NSTDUInt expected);
+
 /// Returns the alignment of each value in a slice.
 ///
 /// # Parameters:
@@ -168,6 +186,90 @@ NSTDAPI NSTDAny nstd_core_slice_first(const NSTDSlice *slice);
 /// slice is empty.
 NSTDAPI NSTDAny nstd_core_slice_last(const NSTDSlice *slice);
 
+/// Returns a view into the elements of `slice` within `range`.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to create the subslice from.
+///
+/// - `NSTDURange range` - The range of elements to include in the subslice.
+///
+/// # Returns
+///
+/// `NSTDOptionalSlice subslice` - The new subslice on success, or an uninitialized "none" variant
+/// if `range.start` is greater than `range.end` or `range.end` is greater than `slice.len`.
+NSTDAPI NSTDOptionalSlice nstd_core_slice_subslice(const NSTDSlice *slice, NSTDURange range);
+
+/// Determines whether or not `slice` begins with the elements of `prefix`.
+///
+/// Elements are compared byte by byte, and slices with differing strides never match.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to check.
+///
+/// - `const NSTDSlice *prefix` - The prefix to search for.
+///
+/// # Returns
+///
+/// `NSTDBool starts_with` - `NSTD_TRUE` if `slice` starts with `prefix`, an empty prefix always
+/// matches.
+///
+/// # Safety
+///
+/// Both `slice` and `prefix`'s data must be valid for reads.
+NSTDAPI NSTDBool nstd_core_slice_starts_with(const NSTDSlice *slice, const NSTDSlice *prefix);
+
+/// Determines whether or not `slice` ends with the elements of `suffix`.
+///
+/// Elements are compared byte by byte, and slices with differing strides never match.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to check.
+///
+/// - `const NSTDSlice *suffix` - The suffix to search for.
+///
+/// # Returns
+///
+/// `NSTDBool ends_with` - `NSTD_TRUE` if `slice` ends with `suffix`, an empty suffix always
+/// matches.
+///
+/// # Safety
+///
+/// Both `slice` and `suffix`'s data must be valid for reads.
+NSTDAPI NSTDBool nstd_core_slice_ends_with(const NSTDSlice *slice, const NSTDSlice *suffix);
+
+/// Invokes `callback` with each contiguous, overlapping window of `size` elements in `slice`.
+///
+/// `callback` is never invoked if `size` is greater than `slice`'s length.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to iterate over.
+///
+/// - `NSTDUInt size` - The number of elements in each window.
+///
+/// - `void (*callback)(const NSTDSlice *, NSTDAnyMut)` - The function to call with each window.
+///
+/// - `NSTDAnyMut data` - User data to pass to `callback`.
+///
+/// # Panics
+///
+/// This operation will panic if `size` is 0.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`callback`).
+///
+/// `slice`'s data must be valid for reads.
+NSTDAPI void nstd_core_slice_windows(
+    const NSTDSlice *slice,
+    NSTDUInt size,
+    void (*callback)(const NSTDSlice *, NSTDAnyMut),
+    NSTDAnyMut data
+);
+
 /// A view into a sequence of values in memory.
 typedef struct {
     /// A pointer to the first element in the slice.
@@ -306,6 +408,23 @@ NSTDAPI NSTDUInt nstd_core_slice_mut_len(const NSTDSliceMut *slice);
 /// `NSTDUInt stride` - The size of each value in the slice.
 NSTDAPI NSTDUInt nstd_core_slice_mut_stride(const NSTDSliceMut *slice);
 
+/// Checks that each value in a slice occupies exactly `expected` bytes.
+///
+/// This is the recommended guard for validating a slice's element layout at FFI boundaries,
+/// before the slice's elements are read as a specific type.
+///
+/// # Parameters:
+///
+/// - `const NSTDSliceMut *slice` - The slice.
+///
+/// - `NSTDUInt expected` - The expected size of each value in the slice.
+///
+/// # Returns
+///
+/// `NSTDBool is_valid` - True if `slice`'s stride is equal to `expected`.
+NSTDAPI NSTDBool
+nstd_core_slice_mut_check_stride(const NSTDSliceMut *slice, NSTDUInt expected);
+
 /// Returns the alignment of each value in a slice.
 ///
 /// # Parameters:
@@ -393,6 +512,21 @@ NSTDAPI NSTDAnyMut nstd_core_slice_mut_last(NSTDSliceMut *slice);
 /// slice is empty.
 NSTDAPI NSTDAny nstd_core_slice_mut_last_const(const NSTDSliceMut *slice);
 
+/// Returns a mutable view into the elements of `slice` within `range`.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *slice` - The slice to create the subslice from.
+///
+/// - `NSTDURange range` - The range of elements to include in the subslice.
+///
+/// # Returns
+///
+/// `NSTDOptionalSliceMut subslice` - The new subslice on success, or an uninitialized "none"
+/// variant if `range.start` is greater than `range.end` or `range.end` is greater than
+/// `slice.len`.
+NSTDAPI NSTDOptionalSliceMut nstd_core_slice_mut_subslice(NSTDSliceMut *slice, NSTDURange range);
+
 /// Copies data into `dest` from `src`. The number of bytes copied is determined by `src`.
 ///
 /// # Parameters:
@@ -414,4 +548,51 @@ NSTDAPI NSTDAny nstd_core_slice_mut_last_const(const NSTDSliceMut *slice);
 /// This function can cause undefined behavior if either `dest` or `src`'s data is invalid.
 NSTDAPI void nstd_core_slice_mut_copy(NSTDSliceMut *dest, const NSTDSlice *src);
 
+/// Rotates a slice in place such that the element at index `mid` becomes the first element.
+///
+/// `mid` is taken modulo the slice's length.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *slice` - The slice to rotate.
+///
+/// - `NSTDUInt mid` - The number of elements to rotate the slice to the left by.
+///
+/// # Safety
+///
+/// `slice`'s data must be valid for reads and writes.
+NSTDAPI void nstd_core_slice_mut_rotate_left(NSTDSliceMut *slice, NSTDUInt mid);
+
+/// Rotates a slice in place such that the last `k` elements become the first elements.
+///
+/// `k` is taken modulo the slice's length.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *slice` - The slice to rotate.
+///
+/// - `NSTDUInt k` - The number of elements to rotate the slice to the right by.
+///
+/// # Safety
+///
+/// `slice`'s data must be valid for reads and writes.
+NSTDAPI void nstd_core_slice_mut_rotate_right(NSTDSliceMut *slice, NSTDUInt k);
+
+/// Swaps the contents of two slices element-wise.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *x` - The first slice.
+///
+/// - `NSTDSliceMut *y` - The second slice.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the slices' lengths or strides differ.
+///
+/// # Safety
+///
+/// Both slices' data must be valid for reads and writes, and must not overlap.
+NSTDAPI NSTDErrorCode nstd_core_slice_mut_swap_with(NSTDSliceMut *x, NSTDSliceMut *y);
+
 #endif
@@ -185,6 +185,52 @@ NSTDAPI NSTDUInt nstd_core_str_len(const NSTDStr *str);
 /// `NSTDUInt byte_len` - The number of bytes in the string slice.
 NSTDAPI NSTDUInt nstd_core_str_byte_len(const NSTDStr *str);
 
+/// Converts a byte index into a string slice to the index of the Unicode character that starts at
+/// that byte.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice.
+///
+/// - `NSTDUInt byte_index` - The byte index to convert.
+///
+/// - `NSTDUInt *char_index` - Receives the character index on success.
+///
+/// # Returns
+///
+/// `NSTDBool is_boundary` - `NSTD_TRUE` on success, or `NSTD_FALSE` if `byte_index` does not lie on
+/// a character boundary, in which case `char_index` is left unchanged.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior in the event that `str`'s data is invalid.
+NSTDAPI NSTDBool
+nstd_core_str_byte_to_char_index(const NSTDStr *str, NSTDUInt byte_index, NSTDUInt *char_index);
+
+/// Converts the index of a Unicode character in a string slice to the byte index that the
+/// character starts at.
+///
+/// A `char_index` equal to the string slice's character count maps to its byte length.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice.
+///
+/// - `NSTDUInt char_index` - The character index to convert.
+///
+/// - `NSTDUInt *byte_index` - Receives the byte index on success.
+///
+/// # Returns
+///
+/// `NSTDBool in_bounds` - `NSTD_TRUE` on success, or `NSTD_FALSE` if `char_index` is greater than
+/// the number of characters in `str`, in which case `byte_index` is left unchanged.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior in the event that `str`'s data is invalid.
+NSTDAPI NSTDBool
+nstd_core_str_char_to_byte_index(const NSTDStr *str, NSTDUInt char_index, NSTDUInt *byte_index);
+
 /// Gets the `NSTDUnichar` at index `pos` in `str`.
 ///
 /// # Note
@@ -206,6 +252,29 @@ NSTDAPI NSTDUInt nstd_core_str_byte_len(const NSTDStr *str);
 /// This operation can cause undefined behavior in the event that `str`'s data is invalid.
 NSTDAPI NSTDOptionalUnichar nstd_core_str_get(const NSTDStr *str, NSTDUInt pos);
 
+/// Gets the byte at index `pos` in `str`.
+///
+/// # Note
+///
+/// Unlike `nstd_core_str_get`, `pos` refers to the byte index rather than the `NSTDUnichar`
+/// index, and the returned value is a raw UTF-8 code unit rather than a full character. This
+/// operation runs in constant time.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice to index.
+///
+/// - `NSTDUInt pos` - The index of the byte to get.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt8 byte` - The byte at index `pos`, or none if `pos` is out of range.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior in the event that `str`'s data is invalid.
+NSTDAPI NSTDOptionalUInt8 nstd_core_str_get_byte(const NSTDStr *str, NSTDUInt pos);
+
 /// Creates a substring of an existing string slice.
 ///
 /// # Parameters:
@@ -232,6 +301,47 @@ NSTDAPI NSTDOptionalUnichar nstd_core_str_get(const NSTDStr *str, NSTDUInt pos);
 /// `str`'s data must be valid for reads of at least `str.len` consecutive bytes.
 NSTDAPI NSTDOptionalStr nstd_core_str_substr(const NSTDStr *str, NSTDURange range);
 
+/// Determines whether or not two string slices are equal, ignoring ASCII case differences.
+///
+/// Non-ASCII characters are compared exactly.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The first string slice.
+///
+/// - `const NSTDStr *other` - The second string slice.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - `NSTD_TRUE` if the two string slices match when ignoring ASCII case.
+///
+/// # Safety
+///
+/// Both `str` and `other`'s data must be valid for reads.
+NSTDAPI NSTDBool nstd_core_str_eq_ignore_ascii_case(const NSTDStr *str, const NSTDStr *other);
+
+/// Computes the hash of a string slice's UTF-8 encoded bytes.
+///
+/// The result is the same as calling `nstd_core_hash_bytes` on `nstd_core_str_as_bytes(str)`.
+///
+/// # Note
+///
+/// The hash is only guaranteed to be stable within a single run of a program, it may change
+/// between versions of `nstd` and should not be persisted.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice to hash.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The string slice's hash value.
+///
+/// # Safety
+///
+/// `str`'s data must be valid for reads.
+NSTDAPI NSTDUInt64 nstd_core_str_hash(const NSTDStr *str);
+
 /// Attempts to parse a string slice as an `NSTDFloat32`.
 ///
 /// # Parameters:
@@ -83,4 +83,18 @@ NSTDAPI NSTDDuration nstd_core_time_duration_add(NSTDDuration lhs, NSTDDuration
 /// `NSTDDuration duration` - The result of the time span subtraction.
 NSTDAPI NSTDDuration nstd_core_time_duration_sub(NSTDDuration lhs, NSTDDuration rhs);
 
+/// Compares two time spans.
+///
+/// # Parameters:
+///
+/// - `NSTDDuration lhs` - The left-hand side operand.
+///
+/// - `NSTDDuration rhs` - The right-hand side operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `lhs` is shorter than `rhs`, a positive value if
+/// `lhs` is longer than `rhs`, or 0 if they are equal or either of them is NaN.
+NSTDAPI NSTDInt32 nstd_core_time_duration_compare(NSTDDuration lhs, NSTDDuration rhs);
+
 #endif
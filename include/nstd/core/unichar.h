@@ -2,6 +2,7 @@
 #define NSTD_CORE_UNICHAR_H
 #include "../nstd.h"
 #include "optional.h"
+#include "slice.h"
 
 /// Represents a unicode scalar value.
 typedef struct {
@@ -131,4 +132,36 @@ NSTDAPI NSTDBool nstd_core_unichar_is_control(NSTDUnichar chr);
 /// `NSTDBool is_digit` - `NSTD_TRUE` if `chr` is a digit.
 NSTDAPI NSTDBool nstd_core_unichar_is_digit(NSTDUnichar chr, NSTDUInt32 radix);
 
+/// Converts `chr` into a digit, depending on `radix`.
+///
+/// # Parameters:
+///
+/// - `NSTDUnichar chr` - The character to convert.
+///
+/// - `NSTDUInt32 radix` - The base, which must be within the range [2, 36].
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt32 digit` - The digit's numerical value on success, or an uninitialized "none"
+/// variant if `chr` is not a digit in base `radix` or `radix` is out of range.
+NSTDAPI NSTDOptionalUInt32 nstd_core_unichar_to_digit(NSTDUnichar chr, NSTDUInt32 radix);
+
+/// Encodes `chr` as UTF-8 into the start of `buffer`.
+///
+/// # Parameters:
+///
+/// - `NSTDUnichar chr` - The character to encode.
+///
+/// - `NSTDSliceMut *buffer` - The byte buffer to write the encoded character to.
+///
+/// # Returns
+///
+/// `NSTDUInt len` - The number of bytes written to `buffer`, this will be 0 if `buffer`'s stride
+/// is not 1 or it is too small to hold the encoded character.
+///
+/// # Safety
+///
+/// `buffer`'s data must be valid for writes.
+NSTDAPI NSTDUInt nstd_core_unichar_encode_utf8(NSTDUnichar chr, NSTDSliceMut *buffer);
+
 #endif
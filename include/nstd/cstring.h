@@ -4,6 +4,7 @@
 #include "core/cstr/cstr.h"
 #include "core/optional.h"
 #include "core/slice.h"
+#include "core/str.h"
 #include "nstd.h"
 #include "vec.h"
 
@@ -137,6 +138,18 @@ NSTDAPI const NSTDAllocator *nstd_cstring_allocator(const NSTDCString *cstring);
 /// `NSTDCStr cstr` - The new C string slice.
 NSTDAPI NSTDCStr nstd_cstring_as_cstr(const NSTDCString *cstring);
 
+/// Creates a string slice containing the contents of `cstring`, excluding the null byte.
+///
+/// # Parameters:
+///
+/// - `const NSTDCString *cstring` - The C string.
+///
+/// # Returns
+///
+/// `NSTDOptionalStr str` - The new string slice on success, or an uninitialized "none" variant if
+/// the C string is not valid UTF-8.
+NSTDAPI NSTDOptionalStr nstd_cstring_as_str(const NSTDCString *cstring);
+
 /// Returns an immutable byte slice of the C string's active data, including the null byte.
 ///
 /// # Parameters:
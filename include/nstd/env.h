@@ -66,6 +66,21 @@ NSTDAPI NSTDIOError nstd_env_set_current_dir(const NSTDStr *path);
 /// The user of this function must ensure that `key` is valid for reads.
 NSTDAPI NSTDIOStringResult nstd_env_var(const NSTDStr *key);
 
+/// Checks if a variable is set in the process environment.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *key` - The variable's key.
+///
+/// # Returns
+///
+/// `NSTDBool exists` - `NSTD_TRUE` if the variable is set, even if its value isn't valid Unicode.
+///
+/// # Safety
+///
+/// The user of this function must ensure that `key` is valid for reads.
+NSTDAPI NSTDBool nstd_env_var_exists(const NSTDStr *key);
+
 /// Sets an environment variable for the current process.
 ///
 /// # Parameters:
@@ -112,9 +127,9 @@ NSTDAPI void nstd_env_remove_var(const NSTDStr *key);
 ///
 /// `NSTDVec args` - The `NSTDString` arguments that the program was started with.
 ///
-/// # Panics
+/// # Note
 ///
-/// This operation will panic if any program arguments contain invalid Unicode.
+/// Invalid Unicode sequences in program arguments are replaced with `U+FFFD REPLACEMENT CHARACTER`.
 NSTDAPI NSTDVec nstd_env_args(void);
 
 /// Returns an `NSTDVec` of `NSTDString[2]` which each represent an environment variable from the
@@ -124,9 +139,9 @@ NSTDAPI NSTDVec nstd_env_args(void);
 ///
 /// `NSTDVec vars` - A list of the process environment variables.
 ///
-/// # Panics
+/// # Note
 ///
-/// This operation will panic if any environment variables contain invalid Unicode.
+/// Environment variables whose key or value contain invalid Unicode are skipped.
 NSTDAPI NSTDVec nstd_env_vars(void);
 
 #endif
@@ -35,6 +35,19 @@ typedef struct {
 /// A result type yielding an `NSTDFile` on success.
 NSTDResult(NSTDFile, NSTDIOError) NSTDFileResult;
 
+/// Describes where a file seek operation's offset is relative to.
+typedef enum {
+    /// The offset is relative to the start of the file, it must not be negative.
+    NSTD_SEEK_FROM_START,
+    /// The offset is relative to the current position in the file.
+    NSTD_SEEK_FROM_CURRENT,
+    /// The offset is relative to the end of the file.
+    NSTD_SEEK_FROM_END
+} NSTDSeekFrom;
+
+/// A result type yielding a file's new cursor position on success.
+NSTDResult(NSTDUInt64, NSTDIOError) NSTDFileSeekResult;
+
 /// Opens file on the filesystem and returns a handle to it.
 ///
 /// # Parameters:
@@ -176,6 +189,39 @@ NSTDAPI NSTDIOResult nstd_fs_file_read_to_string(NSTDFile *file, NSTDString *buf
 /// `buffer` must be valid for writes.
 NSTDAPI NSTDIOError nstd_fs_file_read_exact(NSTDFile *file, NSTDSliceMut *buffer);
 
+/// Moves a file's cursor to a new position.
+///
+/// # Parameters:
+///
+/// - `NSTDFile *file` - A handle to the file.
+///
+/// - `NSTDInt64 offset` - The number of bytes to move the cursor by.
+///
+/// - `NSTDSeekFrom whence` - The position that `offset` is relative to.
+///
+/// # Returns
+///
+/// `NSTDFileSeekResult pos` - The new cursor position from the start of the file on success, or
+/// the I/O operation error code on failure.
+NSTDAPI NSTDFileSeekResult
+nstd_fs_file_seek(NSTDFile *file, NSTDInt64 offset, NSTDSeekFrom whence);
+
+/// Truncates or extends a file to `len` bytes.
+///
+/// If the file is extended, the new bytes are filled with zeros. The file's cursor position is
+/// left unchanged.
+///
+/// # Parameters:
+///
+/// - `NSTDFile *file` - A handle to the file, it must be opened for writing.
+///
+/// - `NSTDUInt64 len` - The file's new length in bytes.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+NSTDAPI NSTDIOError nstd_fs_file_set_len(NSTDFile *file, NSTDUInt64 len);
+
 /// Closes a file handle.
 ///
 /// # Parameters:
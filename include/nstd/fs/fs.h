@@ -163,6 +163,14 @@ NSTDAPI NSTDIOStringResult nstd_fs_read_to_string(const NSTDStr *path);
 
 /// Overwrites the contents of a file.
 ///
+/// The file is created if it does not already exist, and truncated if it does. All of `content` is
+/// written, an error is returned if it can't be.
+///
+/// # Note
+///
+/// This operation is not atomic. If it fails or is interrupted part way through, the file may be
+/// left empty or only partially written.
+///
 /// # Parameters:
 ///
 /// - `const NSTDStr *path` - A path to the file to write to.
@@ -178,6 +186,23 @@ NSTDAPI NSTDIOStringResult nstd_fs_read_to_string(const NSTDStr *path);
 /// This operation can cause undefined behavior if either `path` or `content`'s data is invalid.
 NSTDAPI NSTDIOError nstd_fs_write(const NSTDStr *path, const NSTDSlice *content);
 
+/// Appends data to the end of a file, creating the file if it does not already exist.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file to append to.
+///
+/// - `const NSTDSlice *content` - The content to append to the file.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if either `path` or `content`'s data is invalid.
+NSTDAPI NSTDIOError nstd_fs_append(const NSTDStr *path, const NSTDSlice *content);
+
 /// Renames a file or directory, replacing the destination if it already exists.
 ///
 /// # Parameters:
@@ -212,6 +237,31 @@ NSTDAPI NSTDIOError nstd_fs_rename(const NSTDStr *from, const NSTDStr *to);
 /// This operation can cause undefined behavior if either `to` or `from`'s data is invalid.
 NSTDAPI NSTDIOError nstd_fs_copy(const NSTDStr *from, const NSTDStr *to);
 
+/// Creates a new symbolic link on the file system.
+///
+/// # Note
+///
+/// On Windows, creating a symbolic link may require elevated privileges or Developer Mode to be
+/// enabled. Windows also distinguishes file symbolic links from directory symbolic links, a
+/// directory link is created if `original` (resolved relative to `link`'s parent directory) is an
+/// existing directory, otherwise a file link is created.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *original` - The path that the link will point to.
+///
+/// - `const NSTDStr *link` - The path of the new symbolic link.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code. This will be `NSTD_IO_ERROR_UNSUPPORTED` on
+/// platforms other than Unix and Windows.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if either `original` or `link`'s data is invalid.
+NSTDAPI NSTDIOError nstd_fs_symlink(const NSTDStr *original, const NSTDStr *link);
+
 /// Returns the absolute path of a file system item.
 ///
 /// # Parameters:
@@ -228,6 +278,22 @@ NSTDAPI NSTDIOError nstd_fs_copy(const NSTDStr *from, const NSTDStr *to);
 /// This operation can cause undefined behavior if `path`'s data is invalid.
 NSTDAPI NSTDIOStringResult nstd_fs_absolute(const NSTDStr *path);
 
+/// Returns the path that a symbolic link points to.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the symbolic link.
+///
+/// # Returns
+///
+/// `NSTDIOStringResult target` - The path that the link points to, or the I/O operation error code
+/// on failure.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+NSTDAPI NSTDIOStringResult nstd_fs_read_link(const NSTDStr *path);
+
 /// Retrieves metadata about a file pointed to by `path`.
 ///
 /// # Parameters:
@@ -243,4 +309,122 @@ NSTDAPI NSTDIOStringResult nstd_fs_absolute(const NSTDStr *path);
 /// `path` must be valid for reads.
 NSTDAPI NSTDFileMetadataResult nstd_fs_metadata(const NSTDStr *path);
 
+/// Sets whether or not a file is read-only.
+///
+/// # Note
+///
+/// On Unix systems, making a file writable this way makes it writable by all users. Use
+/// `nstd_os_unix_fs_set_mode` for finer grained control.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file.
+///
+/// - `NSTDBool readonly` - `NSTD_TRUE` to make the file read-only, `NSTD_FALSE` to make it
+/// writable.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+NSTDAPI NSTDIOError nstd_fs_set_readonly(const NSTDStr *path, NSTDBool readonly);
+
+/// Invokes `callback` with the name of each entry within a directory.
+///
+/// # Note
+///
+/// The order of the entries is unspecified. Entries whose names contain invalid Unicode are
+/// skipped.
+///
+/// Symbolic links are not followed, so `callback` receives `NSTD_FALSE` for a symbolic link even
+/// if it points to a directory.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the directory to read.
+///
+/// - `void (*callback)(const NSTDStr *, NSTDBool, NSTDAnyMut)` - The function to call with each
+/// entry's name, whether or not the entry is a directory, and `data`.
+///
+/// - `NSTDAnyMut data` - User data to pass to `callback`.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// - This operation makes a direct call on a C function pointer (`callback`).
+///
+/// - This operation can cause undefined behavior if `path`'s data is invalid.
+///
+/// - The entry name passed to `callback` is only valid for the duration of the call.
+
+NSTDAPI NSTDIOError nstd_fs_read_dir(
+    const NSTDStr *path, void (*callback)(const NSTDStr *, NSTDBool, NSTDAnyMut), NSTDAnyMut data
+);
+
+/// Checks if a file system item exists.
+///
+/// # Note
+///
+/// Symbolic links are followed, and `NSTD_FALSE` is returned if the item's metadata cannot be
+/// retrieved for any reason, including insufficient permissions.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file system item.
+///
+/// # Returns
+///
+/// `NSTDBool exists` - `NSTD_TRUE` if `path` points to an existing file system item.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+NSTDAPI NSTDBool nstd_fs_exists(const NSTDStr *path);
+
+/// Checks if `path` points to a regular file.
+///
+/// # Note
+///
+/// Symbolic links are followed, and `NSTD_FALSE` is returned if the item's metadata cannot be
+/// retrieved for any reason, including insufficient permissions.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file system item.
+///
+/// # Returns
+///
+/// `NSTDBool is_file` - `NSTD_TRUE` if `path` points to a regular file.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+NSTDAPI NSTDBool nstd_fs_is_file(const NSTDStr *path);
+
+/// Checks if `path` points to a directory.
+///
+/// # Note
+///
+/// Symbolic links are followed, and `NSTD_FALSE` is returned if the item's metadata cannot be
+/// retrieved for any reason, including insufficient permissions.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file system item.
+///
+/// # Returns
+///
+/// `NSTDBool is_dir` - `NSTD_TRUE` if `path` points to a directory.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+NSTDAPI NSTDBool nstd_fs_is_dir(const NSTDStr *path);
+
 #endif
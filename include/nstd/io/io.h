@@ -36,7 +36,7 @@ typedef enum {
     NSTD_IO_ERROR_BLOCKING,
     /// Some input parameter was incorrect.
     NSTD_IO_ERROR_INVALID_INPUT,
-    /// Some input data was incorrect.
+    /// Some input data was incorrect, such as invalid UTF-8.
     NSTD_IO_ERROR_INVALID_DATA,
     /// The I/O operation's timeout expired, causing it to be canceled.
     NSTD_IO_ERROR_TIMED_OUT,
@@ -49,7 +49,9 @@ typedef enum {
     /// A reader unexpectedly reached the end of a file.
     NSTD_IO_ERROR_UNEXPECTED_EOF,
     /// An operation could not be completed, because it failed to allocate enough memory.
-    NSTD_IO_ERROR_OUT_OF_MEMORY
+    NSTD_IO_ERROR_OUT_OF_MEMORY,
+    /// A reader has reached the end of its stream.
+    NSTD_IO_ERROR_EOF
 } NSTDIOError;
 
 /// A result type that yields an [`NSTDUInt`] representing the number of bytes read or written by
@@ -64,6 +66,10 @@ NSTDResult(NSTDString, NSTDIOError) NSTDIOStringResult;
 
 /// Writes a string slice to stdout.
 ///
+/// # Note
+///
+/// Stdout is flushed once the string has been written, so there is no need to flush it manually.
+///
 /// # Parameters:
 ///
 /// - `const NSTDStr *output` - The string slice to write to stdout.
@@ -80,6 +86,10 @@ NSTDAPI NSTDIOError nstd_io_print(const NSTDStr *output);
 
 /// Writes a string slice to stdout followed by a new line.
 ///
+/// # Note
+///
+/// Stdout is flushed once the string has been written, so there is no need to flush it manually.
+///
 /// # Parameters:
 ///
 /// - `const NSTDStr *output` - The string slice to write to stdout.
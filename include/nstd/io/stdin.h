@@ -90,7 +90,8 @@ NSTDAPI NSTDIOResult nstd_io_stdin_read_to_string(NSTDStdin *handle, NSTDString
 /// # Note
 ///
 /// This function will return an error code of `NSTD_IO_ERROR_INVALID_INPUT` if the buffer's
-/// element size is not 1.
+/// element size is not 1, and `NSTD_IO_ERROR_UNEXPECTED_EOF` if the stream ends before `buffer` is
+/// filled.
 ///
 /// # Parameters:
 ///
@@ -109,6 +110,11 @@ NSTDAPI NSTDIOError nstd_io_stdin_read_exact(NSTDStdin *handle, NSTDSliceMut *bu
 
 /// Reads a line from stdin and appends it to `buffer`.
 ///
+/// # Note
+///
+/// The line's trailing newline is kept. Once the end of the stream has been reached, an error code
+/// of `NSTD_IO_ERROR_EOF` is returned.
+///
 /// # Parameters:
 ///
 /// - `NSTDStdin *handle` - A handle to stdin.
@@ -211,7 +217,8 @@ NSTDAPI NSTDIOResult nstd_io_stdin_lock_read_to_string(NSTDStdinLock *handle, NS
 /// # Note
 ///
 /// This function will return an error code of `NSTD_IO_ERROR_INVALID_INPUT` if the buffer's
-/// element size is not 1.
+/// element size is not 1, and `NSTD_IO_ERROR_UNEXPECTED_EOF` if the stream ends before `buffer` is
+/// filled.
 ///
 /// # Parameters:
 ///
@@ -228,6 +235,25 @@ NSTDAPI NSTDIOResult nstd_io_stdin_lock_read_to_string(NSTDStdinLock *handle, NS
 /// `buffer` must be valid for writes.
 NSTDAPI NSTDIOError nstd_io_stdin_lock_read_exact(NSTDStdinLock *handle, NSTDSliceMut *buffer);
 
+/// Reads a line from stdin and appends it to `buffer`.
+///
+/// # Note
+///
+/// The line's trailing newline is kept. Once the end of the stream has been reached, an error code
+/// of `NSTD_IO_ERROR_EOF` is returned.
+///
+/// # Parameters:
+///
+/// - `NSTDStdinLock *handle` - A locked handle to the standard input stream.
+///
+/// - `NSTDString *buffer` - The string buffer to extend with a line from stdin.
+///
+/// # Returns
+///
+/// `NSTDIOResult read` - The number of bytes read from `handle` on success, or the I/O operation
+/// error code on failure.
+NSTDAPI NSTDIOResult nstd_io_stdin_lock_read_line(NSTDStdinLock *handle, NSTDString *buffer);
+
 /// Frees and unlocks an instance of `NSTDStdinLock`.
 ///
 /// # Parameters:
@@ -0,0 +1,146 @@
+#ifndef NSTD_MAP_H
+#define NSTD_MAP_H
+#include "core/alloc.h"
+#include "nstd.h"
+#include "vec.h"
+
+/// An unordered hash map with fixed-size keys and values.
+///
+/// Keys are compared and hashed by their raw bytes. Collisions are resolved with open addressing
+/// (linear probing).
+typedef struct {
+    /// The state of each bucket.
+    NSTDVec states;
+    /// Each bucket's key.
+    NSTDVec keys;
+    /// Each bucket's value.
+    NSTDVec values;
+    /// The number of entries in the map.
+    NSTDUInt len;
+    /// The number of buckets whose entries have been removed.
+    NSTDUInt removed;
+} NSTDMap;
+
+/// Creates a new hash map without allocating any resources.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDUInt key_stride` - The size in bytes of each key in the map.
+///
+/// - `NSTDUInt key_align` - The alignment of each key in the map.
+///
+/// - `NSTDUInt value_stride` - The size in bytes of each value in the map.
+///
+/// - `NSTDUInt value_align` - The alignment of each value in the map.
+///
+/// # Returns
+///
+/// `NSTDMap map` - The new hash map.
+///
+/// # Panics
+///
+/// This operation will panic if either `key_align` or `value_align` is not a power of two, or if
+/// `key_stride` or `value_stride` are not multiples of their respective alignments.
+NSTDAPI NSTDMap nstd_map_new(
+    const NSTDAllocator *allocator, NSTDUInt key_stride, NSTDUInt key_align,
+    NSTDUInt value_stride, NSTDUInt value_align
+);
+
+/// Returns the number of entries in a hash map.
+///
+/// # Parameters:
+///
+/// - `const NSTDMap *map` - The hash map.
+///
+/// # Returns
+///
+/// `NSTDUInt len` - The number of key-value pairs stored in the map.
+NSTDAPI NSTDUInt nstd_map_len(const NSTDMap *map);
+
+/// Inserts a key-value pair into a hash map.
+///
+/// If the map already contains `key`, its value is overwritten with `value`.
+///
+/// # Parameters:
+///
+/// - `NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to copy into the map.
+///
+/// - `NSTDAny value` - A pointer to the value to copy into the map.
+///
+/// # Returns
+///
+/// `NSTDAllocError errc` - The allocation operation error code.
+///
+/// # Safety
+///
+/// - `key` must be valid for reads of the map's key stride.
+///
+/// - `value` must be valid for reads of the map's value stride, and must not point into the map's
+/// own buffers.
+NSTDAPI NSTDAllocError nstd_map_insert(NSTDMap *map, NSTDAny key, NSTDAny value);
+
+/// Returns an immutable pointer to the value associated with `key`.
+///
+/// # Parameters:
+///
+/// - `const NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDAny value` - A pointer to the value associated with `key`, or null if `map` does not
+/// contain `key`.
+///
+/// # Safety
+///
+/// `key` must be valid for reads of the map's key stride.
+NSTDAPI NSTDAny nstd_map_get(const NSTDMap *map, NSTDAny key);
+
+/// Returns a pointer to the value associated with `key`.
+///
+/// # Parameters:
+///
+/// - `NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDAnyMut value` - A pointer to the value associated with `key`, or null if `map` does not
+/// contain `key`.
+///
+/// # Safety
+///
+/// `key` must be valid for reads of the map's key stride.
+NSTDAPI NSTDAnyMut nstd_map_get_mut(NSTDMap *map, NSTDAny key);
+
+/// Removes a key-value pair from a hash map.
+///
+/// # Parameters:
+///
+/// - `NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to remove.
+///
+/// # Returns
+///
+/// `NSTDBool is_removed` - `NSTD_TRUE` if `map` contained `key`.
+///
+/// # Safety
+///
+/// `key` must be valid for reads of the map's key stride.
+NSTDAPI NSTDBool nstd_map_remove(NSTDMap *map, NSTDAny key);
+
+/// Frees an instance of `NSTDMap`.
+///
+/// # Parameters:
+///
+/// - `NSTDMap map` - The hash map to free.
+NSTDAPI void nstd_map_free(NSTDMap map);
+
+#endif
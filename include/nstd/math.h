@@ -90,8 +90,37 @@ NSTDAPI NSTDFloat32 nstd_math_pow_f32(NSTDFloat32 x, NSTDInt32 exp);
 /// `NSTDFloat64 pow` - `x` raised to the power of `exp`.
 NSTDAPI NSTDFloat64 nstd_math_pow_f64(NSTDFloat64 x, NSTDInt32 exp);
 
+/// Raises `x` to a floating point power.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat32 x` - The value.
+///
+/// - `NSTDFloat32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDFloat32 pow` - `x` raised to the power of `exp`.
+NSTDAPI NSTDFloat32 nstd_math_powf_f32(NSTDFloat32 x, NSTDFloat32 exp);
+/// Raises `x` to a floating point power.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat64 x` - The value.
+///
+/// - `NSTDFloat64 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDFloat64 pow` - `x` raised to the power of `exp`.
+NSTDAPI NSTDFloat64 nstd_math_powf_f64(NSTDFloat64 x, NSTDFloat64 exp);
+
 /// Computes the square root of `x`.
 ///
+/// # Note
+///
+/// If `x` is less than -0, NaN is returned.
+///
 /// # Parameters:
 ///
 /// - `NSTDFloat32 x` - The value.
@@ -102,6 +131,10 @@ NSTDAPI NSTDFloat64 nstd_math_pow_f64(NSTDFloat64 x, NSTDInt32 exp);
 NSTDAPI NSTDFloat32 nstd_math_sqrt_f32(NSTDFloat32 x);
 /// Computes the square root of `x`.
 ///
+/// # Note
+///
+/// If `x` is less than -0, NaN is returned.
+///
 /// # Parameters:
 ///
 /// - `NSTDFloat64 x` - The value.
@@ -111,7 +144,7 @@ NSTDAPI NSTDFloat32 nstd_math_sqrt_f32(NSTDFloat32 x);
 /// `NSTDFloat64 sqrt` - The square root of `x`.
 NSTDAPI NSTDFloat64 nstd_math_sqrt_f64(NSTDFloat64 x);
 
-/// Computes the sine of `x`.
+/// Computes the cube root of `x`.
 ///
 /// # Parameters:
 ///
@@ -119,13 +152,34 @@ NSTDAPI NSTDFloat64 nstd_math_sqrt_f64(NSTDFloat64 x);
 ///
 /// # Returns
 ///
+/// `NSTDFloat32 cbrt` - The cube root of `x`.
+NSTDAPI NSTDFloat32 nstd_math_cbrt_f32(NSTDFloat32 x);
+/// Computes the cube root of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat64 x` - The value.
+///
+/// # Returns
+///
+/// `NSTDFloat64 cbrt` - The cube root of `x`.
+NSTDAPI NSTDFloat64 nstd_math_cbrt_f64(NSTDFloat64 x);
+
+/// Computes the sine of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat32 x` - The angle in radians.
+///
+/// # Returns
+///
 /// `NSTDFloat32 sin` - The sine value of `x`.
 NSTDAPI NSTDFloat32 nstd_math_sin_f32(NSTDFloat32 x);
 /// Computes the sine of `x`.
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat64 x` - The value.
+/// - `NSTDFloat64 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -136,7 +190,7 @@ NSTDAPI NSTDFloat64 nstd_math_sin_f64(NSTDFloat64 x);
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat32 x` - The value.
+/// - `NSTDFloat32 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -146,7 +200,7 @@ NSTDAPI NSTDFloat32 nstd_math_cos_f32(NSTDFloat32 x);
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat64 x` - The value.
+/// - `NSTDFloat64 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -157,7 +211,7 @@ NSTDAPI NSTDFloat64 nstd_math_cos_f64(NSTDFloat64 x);
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat32 x` - The value.
+/// - `NSTDFloat32 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -167,7 +221,7 @@ NSTDAPI NSTDFloat32 nstd_math_tan_f32(NSTDFloat32 x);
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat64 x` - The value.
+/// - `NSTDFloat64 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -182,7 +236,7 @@ NSTDAPI NSTDFloat64 nstd_math_tan_f64(NSTDFloat64 x);
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 asin` - The arcsine value of `x`.
+/// `NSTDFloat32 asin` - The arcsine value of `x` in radians.
 NSTDAPI NSTDFloat32 nstd_math_asin_f32(NSTDFloat32 x);
 /// Computes the arcsine of `x`.
 ///
@@ -192,7 +246,7 @@ NSTDAPI NSTDFloat32 nstd_math_asin_f32(NSTDFloat32 x);
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 asin` - The arcsine value of `x`.
+/// `NSTDFloat64 asin` - The arcsine value of `x` in radians.
 NSTDAPI NSTDFloat64 nstd_math_asin_f64(NSTDFloat64 x);
 
 /// Computes the arccosine of `x`.
@@ -203,7 +257,7 @@ NSTDAPI NSTDFloat64 nstd_math_asin_f64(NSTDFloat64 x);
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 acos` - The arccosine value of `x`.
+/// `NSTDFloat32 acos` - The arccosine value of `x` in radians.
 NSTDAPI NSTDFloat32 nstd_math_acos_f32(NSTDFloat32 x);
 /// Computes the arccosine of `x`.
 ///
@@ -213,7 +267,7 @@ NSTDAPI NSTDFloat32 nstd_math_acos_f32(NSTDFloat32 x);
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 acos` - The arccosine value of `x`.
+/// `NSTDFloat64 acos` - The arccosine value of `x` in radians.
 NSTDAPI NSTDFloat64 nstd_math_acos_f64(NSTDFloat64 x);
 
 /// Computes the arctangent of `x`.
@@ -224,7 +278,7 @@ NSTDAPI NSTDFloat64 nstd_math_acos_f64(NSTDFloat64 x);
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 atan` - The arctangent value of `x`.
+/// `NSTDFloat32 atan` - The arctangent value of `x` in radians.
 NSTDAPI NSTDFloat32 nstd_math_atan_f32(NSTDFloat32 x);
 /// Computes the arctangent of `x`.
 ///
@@ -234,10 +288,10 @@ NSTDAPI NSTDFloat32 nstd_math_atan_f32(NSTDFloat32 x);
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 atan` - The arctangent value of `x`.
+/// `NSTDFloat64 atan` - The arctangent value of `x` in radians.
 NSTDAPI NSTDFloat64 nstd_math_atan_f64(NSTDFloat64 x);
 
-/// Computes the four quadrant arctangent of `x` & `y`.
+/// Computes the four quadrant arctangent of `x` & `y` in radians.
 ///
 /// # Parameters:
 ///
@@ -247,9 +301,9 @@ NSTDAPI NSTDFloat64 nstd_math_atan_f64(NSTDFloat64 x);
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 atan2` - The four quadrant arctangent of `x` & `y`.
+/// `NSTDFloat32 atan2` - The four quadrant arctangent of `x` & `y` in radians.
 NSTDAPI NSTDFloat32 nstd_math_atan2_f32(NSTDFloat32 x, NSTDFloat32 y);
-/// Computes the four quadrant arctangent of `x` & `y`.
+/// Computes the four quadrant arctangent of `x` & `y` in radians.
 ///
 /// # Parameters:
 ///
@@ -259,7 +313,7 @@ NSTDAPI NSTDFloat32 nstd_math_atan2_f32(NSTDFloat32 x, NSTDFloat32 y);
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 atan2` - The four quadrant arctangent of `x` & `y`.
+/// `NSTDFloat64 atan2` - The four quadrant arctangent of `x` & `y` in radians.
 NSTDAPI NSTDFloat64 nstd_math_atan2_f64(NSTDFloat64 x, NSTDFloat64 y);
 
 /// Computes the hyperbolic sine of `x`.
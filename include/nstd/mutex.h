@@ -56,6 +56,15 @@ NSTDAPI NSTDOptionalMutex nstd_mutex_new(NSTDHeapPtr data);
 /// `NSTDBool is_poisoned` - A boolean value indicating whether or not `mutex` is poisoned.
 NSTDAPI NSTDBool nstd_mutex_is_poisoned(const NSTDMutex *mutex);
 
+/// Clears a mutex's poisoned state.
+///
+/// This allows a mutex to be marked as recovered after a thread panicked while holding its lock.
+///
+/// # Parameters:
+///
+/// - `const NSTDMutex *mutex` - The mutex.
+NSTDAPI void nstd_mutex_clear_poison(const NSTDMutex *mutex);
+
 /// Waits for a mutex lock to become acquired, returning a guard wrapping the protected data.
 ///
 /// Attempting to call this function on a thread that already owns the lock will either result in a
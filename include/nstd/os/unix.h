@@ -1,6 +1,7 @@
 #ifndef NSTD_OS_UNIX_H
 #define NSTD_OS_UNIX_H
 #include "unix/alloc.h"
+#include "unix/fs.h"
 #include "unix/io.h"
 #include "unix/mutex.h"
 #include "unix/shared_lib.h"
@@ -0,0 +1,48 @@
+#ifndef NSTD_OS_UNIX_FS_H
+#define NSTD_OS_UNIX_FS_H
+#include "../../core/result.h"
+#include "../../nstd.h"
+#include "io.h"
+
+/// A result type that yields a Unix file mode on success and a Unix I/O operation error code on
+/// failure.
+NSTDResult(NSTDUInt32, NSTDUnixIOError) NSTDUnixFileModeResult;
+
+/// Returns the mode (permission bits and file type) of the file at `path`.
+///
+/// Symbolic links are followed.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar *path` - A null terminated path to the file.
+///
+/// # Returns
+///
+/// `NSTDUnixFileModeResult mode` - The file's `st_mode` on success, or the I/O operation error code
+/// on failure.
+///
+/// # Safety
+///
+/// `path` must be a valid null terminated C string.
+NSTDAPI NSTDUnixFileModeResult nstd_os_unix_fs_mode(const NSTDChar *path);
+
+/// Sets the permission bits of the file at `path`, for example octal 755 to make it executable.
+///
+/// Symbolic links are followed.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar *path` - A null terminated path to the file.
+///
+/// - `NSTDUInt32 mode` - The file's new permission bits.
+///
+/// # Returns
+///
+/// `NSTDUnixIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// `path` must be a valid null terminated C string.
+NSTDAPI NSTDUnixIOError nstd_os_unix_fs_set_mode(const NSTDChar *path, NSTDUInt32 mode);
+
+#endif
@@ -1,7 +1,9 @@
 #ifndef NSTD_OS_UNIX_SHARED_LIB_H
 #define NSTD_OS_UNIX_SHARED_LIB_H
+#include "../../core/def.h"
 #include "../../core/optional.h"
 #include "../../nstd.h"
+#include "../../string.h"
 
 /// Represents an owned handle to a dynamically loaded library.
 typedef struct {
@@ -27,6 +29,24 @@ NSTDOptional(NSTDUnixSharedLib) NSTDUnixOptionalSharedLib;
 /// See <https://man7.org/linux/man-pages/man3/dlopen.3.html>.
 NSTDAPI NSTDUnixOptionalSharedLib nstd_os_unix_shared_lib_load(const NSTDChar *path);
 
+/// Loads a dynamically loaded shared library with a custom set of `dlopen` flags.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar *path` - A path to the shared library to load.
+///
+/// - `NSTDInt32 flags` - The flags to pass to `dlopen`, such as `RTLD_NOW | RTLD_LOCAL`.
+///
+/// # Returns
+///
+/// `NSTDUnixOptionalSharedLib lib` - A handle to the loaded library.
+///
+/// # Safety
+///
+/// See <https://man7.org/linux/man-pages/man3/dlopen.3.html>.
+NSTDAPI NSTDUnixOptionalSharedLib
+nstd_os_unix_shared_lib_load_with_flags(const NSTDChar *path, NSTDInt32 flags);
+
 /// Returns a raw handle to a dynamically loaded library.
 ///
 /// # Parameters:
@@ -72,6 +92,37 @@ NSTDAPI NSTDAny nstd_os_unix_shared_lib_get(const NSTDUnixSharedLib *lib, const
 /// See <https://man7.org/linux/man-pages/man3/dlsym.3.html>.
 NSTDAPI NSTDAnyMut nstd_os_unix_shared_lib_get_mut(NSTDUnixSharedLib *lib, const NSTDChar *symbol);
 
+/// Returns a description of the most recent error that occurred while loading a shared library or
+/// retrieving one of its symbols.
+///
+/// Calling this function clears the error, so subsequent calls will return "none" until another
+/// error occurs.
+///
+/// # Returns
+///
+/// `NSTDOptionalString error` - A human readable description of the last error, or an uninitialized
+/// "none" variant if no error has occurred since the last call.
+///
+/// # Safety
+///
+/// See <https://man7.org/linux/man-pages/man3/dlerror.3.html>.
+NSTDAPI NSTDOptionalString nstd_os_unix_shared_lib_last_error(void);
+
+/// Closes a loaded shared library, returning the status code reported by `dlclose`.
+///
+/// # Parameters:
+///
+/// - `NSTDUnixSharedLib lib` - A handle to the loaded library to unload.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if unloading the library fails.
+///
+/// # Safety
+///
+/// See <https://man7.org/linux/man-pages/man3/dlclose.3p.html>.
+NSTDAPI NSTDErrorCode nstd_os_unix_shared_lib_close(NSTDUnixSharedLib lib);
+
 /// Closes and frees a loaded shared library.
 ///
 /// # Parameters:
@@ -1,7 +1,9 @@
 #ifndef NSTD_OS_WINDOWS_SHARED_LIB_H
 #define NSTD_OS_WINDOWS_SHARED_LIB_H
 #include "../../core/optional.h"
+#include "../../core/str.h"
 #include "../../nstd.h"
+#include "../../string.h"
 #include "windows.h"
 
 /// A handle to a loaded library.
@@ -15,6 +17,10 @@ NSTDOptional(NSTDWindowsSharedLib) NSTDWindowsOptionalSharedLib;
 
 /// Loads a shared library/module by name.
 ///
+/// This function uses the standard DLL search order, which may include directories such as the
+/// current working directory. Use `nstd_os_windows_shared_lib_load_ex` with a flag such as
+/// `LOAD_LIBRARY_SEARCH_SYSTEM32` to constrain where the library is searched for.
+///
 /// # Parameters:
 ///
 /// - `const NSTDChar16 *name` - The name of the module to load.
@@ -29,6 +35,45 @@ NSTDOptional(NSTDWindowsSharedLib) NSTDWindowsOptionalSharedLib;
 /// <https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryw>.
 NSTDAPI NSTDWindowsOptionalSharedLib nstd_os_windows_shared_lib_load(const NSTDChar16 *name);
 
+/// Loads a shared library/module by name with a custom set of `LoadLibraryExW` flags.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar16 *name` - The name of the module to load.
+///
+/// - `NSTDUInt32 flags` - The flags to pass to `LoadLibraryExW`, such as
+/// `LOAD_LIBRARY_SEARCH_SYSTEM32`.
+///
+/// # Returns
+///
+/// `NSTDWindowsOptionalSharedLib lib` - A handle to the shared library.
+///
+/// # Safety
+///
+/// See
+/// <https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryexw>.
+NSTDAPI NSTDWindowsOptionalSharedLib
+nstd_os_windows_shared_lib_load_ex(const NSTDChar16 *name, NSTDUInt32 flags);
+
+/// Loads a shared library/module by a UTF-8 encoded name.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *name` - The name of the module to load.
+///
+/// # Returns
+///
+/// `NSTDWindowsOptionalSharedLib lib` - A handle to the shared library, or an uninitialized "none"
+/// variant if converting `name` to UTF-16 or loading the library fails.
+///
+/// # Safety
+///
+/// - `name`'s data must be valid for reads.
+///
+/// - See
+/// <https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryw>.
+NSTDAPI NSTDWindowsOptionalSharedLib nstd_os_windows_shared_lib_load_utf8(const NSTDStr *name);
+
 /// Returns a raw handle to a dynamically loaded library.
 ///
 /// # Parameters:
@@ -79,6 +124,20 @@ nstd_os_windows_shared_lib_get(const NSTDWindowsSharedLib *lib, const NSTDChar *
 NSTDAPI NSTDAnyMut
 nstd_os_windows_shared_lib_get_mut(NSTDWindowsSharedLib *lib, const NSTDChar *symbol);
 
+/// Returns a description of the calling thread's last error code, as set by a failed attempt to
+/// load a shared library or retrieve one of its symbols.
+///
+/// # Returns
+///
+/// `NSTDOptionalString error` - A human readable description of the last error, or an uninitialized
+/// "none" variant if the thread's last error code is not set.
+///
+/// # Safety
+///
+/// See
+/// <https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew>.
+NSTDAPI NSTDOptionalString nstd_os_windows_shared_lib_last_error(void);
+
 /// Unloads and frees a dynamically loaded shared library.
 ///
 /// # Parameters:
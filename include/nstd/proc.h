@@ -5,6 +5,7 @@
 #include "core/str.h"
 #include "io/io.h"
 #include "nstd.h"
+#include "vec.h"
 
 /// A handle to a child process.
 typedef struct {
@@ -15,6 +16,20 @@ typedef struct {
 /// Represents an optional value of type `NSTDChildProcess`.
 NSTDOptional(NSTDChildProcess) NSTDOptionalChildProcess;
 
+/// The output of a child process that has run to completion.
+typedef struct {
+    /// The child process's exit code, or an uninitialized "none" variant if the process was
+    /// terminated by a signal.
+    NSTDOptionalInt32 exit_code;
+    /// The data that the child process wrote to its standard output stream.
+    NSTDVec stdout_bytes;
+    /// The data that the child process wrote to its standard error stream.
+    NSTDVec stderr_bytes;
+} NSTDProcessOutput;
+
+/// Represents an optional value of type `NSTDProcessOutput`.
+NSTDOptional(NSTDProcessOutput) NSTDOptionalProcessOutput;
+
 /// Spawns a new child process with the name `program` and returns a handle to it.
 ///
 /// # Parameters:
@@ -29,7 +44,8 @@ NSTDOptional(NSTDChildProcess) NSTDOptionalChildProcess;
 /// # Returns
 ///
 /// `NSTDOptionalChildProcess child` - A handle to the new child process on success, or an
-/// uninitialized "none" variant if spawning the child process fails.
+/// uninitialized "none" variant if spawning the child process fails or either `args` or `vars`
+/// have an incorrect stride.
 ///
 /// # Safety
 ///
@@ -38,6 +54,32 @@ NSTDOptional(NSTDChildProcess) NSTDOptionalChildProcess;
 NSTDAPI NSTDOptionalChildProcess
 nstd_proc_spawn(const NSTDStr *program, const NSTDSlice *args, const NSTDSlice *vars);
 
+/// Runs a child process to completion, capturing its standard output and error streams.
+///
+/// The child process's standard input stream is not inherited from the current process.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *program` - A path to the program to run as a child process.
+///
+/// - `const NSTDSlice *args` - A slice of `NSTDStr` arguments to pass to the program.
+///
+/// - `const NSTDSlice *vars` - A slice of `NSTDStr[2]` key/value environment variables to
+/// give to the program.
+///
+/// # Returns
+///
+/// `NSTDOptionalProcessOutput output` - The child process's output on success, or an
+/// uninitialized "none" variant if running the child process fails or either `args` or `vars`
+/// have an incorrect stride.
+///
+/// # Safety
+///
+/// The user must ensure that all of `program`, `args`, and `vars` and their data remain valid for
+/// reads while this function is executing.
+NSTDAPI NSTDOptionalProcessOutput
+nstd_proc_output(const NSTDStr *program, const NSTDSlice *args, const NSTDSlice *vars);
+
 /// Returns the OS-assigned ID of a child process.
 ///
 /// # Parameters:
@@ -83,7 +125,7 @@ NSTDAPI void nstd_proc_free(NSTDChildProcess handle);
 /// # Parameters:
 ///
 /// - `NSTDInt32 exit_code` - The process exit code.
-NSTDAPI NSTDInt32 nstd_proc_exit(NSTDInt32 exit_code);
+NSTDAPI void nstd_proc_exit(NSTDInt32 exit_code);
 
 /// Terminates the program in an abnormal fashion.
 NSTDAPI void nstd_proc_abort(void);
@@ -0,0 +1,230 @@
+#ifndef NSTD_RWLOCK_H
+#define NSTD_RWLOCK_H
+#include "core/optional.h"
+#include "core/result.h"
+#include "heap_ptr.h"
+#include "nstd.h"
+
+/// A reader-writer lock useful for protecting shared data.
+///
+/// This type allows any number of readers or at most one writer to access the protected data at
+/// any point in time.
+typedef struct {
+    /// The Rust [RwLock].
+    NSTDAnyMut lock;
+} NSTDRwLock;
+
+/// Represents an optional value of type `NSTDRwLock`.
+NSTDOptional(NSTDRwLock) NSTDOptionalRwLock;
+
+/// A guard providing shared read access to a reader-writer lock's protected data.
+typedef struct {
+    /// The Rust [RwLockReadGuard].
+    NSTDAnyMut guard;
+} NSTDRwLockReadGuard;
+
+/// A lock result returned from `nstd_rwlock_read` containing the read guard whether or not the
+/// data is poisoned.
+NSTDResult(NSTDRwLockReadGuard, NSTDRwLockReadGuard) NSTDRwLockReadResult;
+
+/// An optional value of type `NSTDRwLockReadResult`.
+///
+/// This type is returned from `nstd_rwlock_try_read` where the uninitialized variant means that
+/// the function would block.
+NSTDOptional(NSTDRwLockReadResult) NSTDOptionalRwLockReadResult;
+
+/// A guard providing exclusive write access to a reader-writer lock's protected data.
+typedef struct {
+    /// The Rust [RwLockWriteGuard].
+    NSTDAnyMut guard;
+} NSTDRwLockWriteGuard;
+
+/// A lock result returned from `nstd_rwlock_write` containing the write guard whether or not the
+/// data is poisoned.
+NSTDResult(NSTDRwLockWriteGuard, NSTDRwLockWriteGuard) NSTDRwLockWriteResult;
+
+/// An optional value of type `NSTDRwLockWriteResult`.
+///
+/// This type is returned from `nstd_rwlock_try_write` where the uninitialized variant means that
+/// the function would block.
+NSTDOptional(NSTDRwLockWriteResult) NSTDOptionalRwLockWriteResult;
+
+/// Creates a new reader-writer lock.
+///
+/// # Parameters:
+///
+/// - `NSTDHeapPtr data` - The data to protect.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLock lock` - The new reader-writer lock protecting `data` on success, or an
+/// uninitialized "none" variant on error.
+NSTDAPI NSTDOptionalRwLock nstd_rwlock_new(NSTDHeapPtr data);
+
+/// Determines whether or not a reader-writer lock's data is poisoned.
+///
+/// Reader-writer locks are poisoned when a thread that owns a write guard panics. This function is
+/// useful for those that configure `nstd` to unwind the stack instead of aborting on panic.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDBool is_poisoned` - A boolean value indicating whether or not `lock` is poisoned.
+NSTDAPI NSTDBool nstd_rwlock_is_poisoned(const NSTDRwLock *lock);
+
+/// Clears a reader-writer lock's poisoned state.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+NSTDAPI void nstd_rwlock_clear_poison(const NSTDRwLock *lock);
+
+/// Waits for shared read access to a reader-writer lock, returning a guard wrapping the protected
+/// data.
+///
+/// Attempting to call this function on a thread that already owns a write guard will either
+/// result in a panic or a deadlock.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockReadResult guard` - A handle to the lock's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation may panic if a write guard is already held by the current thread.
+NSTDAPI NSTDOptionalRwLockReadResult nstd_rwlock_read(const NSTDRwLock *lock);
+
+/// The non-blocking variant of `nstd_rwlock_read` returning an uninitialized "none" result if the
+/// lock is held by a writer.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockReadResult guard` - A handle to the lock's protected data.
+NSTDAPI NSTDOptionalRwLockReadResult nstd_rwlock_try_read(const NSTDRwLock *lock);
+
+/// Waits for exclusive write access to a reader-writer lock, returning a guard wrapping the
+/// protected data.
+///
+/// Attempting to call this function on a thread that already owns a read or write guard will
+/// either result in a panic or a deadlock.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockWriteResult guard` - A handle to the lock's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation may panic if a guard is already held by the current thread.
+NSTDAPI NSTDOptionalRwLockWriteResult nstd_rwlock_write(const NSTDRwLock *lock);
+
+/// The non-blocking variant of `nstd_rwlock_write` returning an uninitialized "none" result if the
+/// lock is held by a reader or writer.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockWriteResult guard` - A handle to the lock's protected data.
+NSTDAPI NSTDOptionalRwLockWriteResult nstd_rwlock_try_write(const NSTDRwLock *lock);
+
+/// Returns a pointer to a reader-writer lock's raw data through a read guard.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLockReadGuard *guard` - A handle to the lock's protected data.
+///
+/// # Returns
+///
+/// `NSTDAny data` - A pointer to the lock's data.
+NSTDAPI NSTDAny nstd_rwlock_read_get(const NSTDRwLockReadGuard *guard);
+
+/// Returns a pointer to a reader-writer lock's raw data through a write guard.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLockWriteGuard *guard` - A handle to the lock's protected data.
+///
+/// # Returns
+///
+/// `NSTDAny data` - A pointer to the lock's data.
+NSTDAPI NSTDAny nstd_rwlock_write_get(const NSTDRwLockWriteGuard *guard);
+
+/// Returns a mutable pointer to a reader-writer lock's raw data through a write guard.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLockWriteGuard *guard` - A handle to the lock's protected data.
+///
+/// # Returns
+///
+/// `NSTDAnyMut data` - A mutable pointer to the lock's data.
+NSTDAPI NSTDAnyMut nstd_rwlock_write_get_mut(NSTDRwLockWriteGuard *guard);
+
+/// Consumes a reader-writer lock and returns the data it was protecting.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLock lock` - The reader-writer lock to take ownership of.
+///
+/// # Returns
+///
+/// `NSTDOptionalHeapPtr data` - Ownership of the lock's data, or an uninitialized "none" variant
+/// if the lock was poisoned.
+NSTDAPI NSTDOptionalHeapPtr nstd_rwlock_into_inner(NSTDRwLock lock);
+
+/// Releases shared read access to a reader-writer lock by consuming a read guard.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLockReadGuard guard` - The read guard.
+NSTDAPI void nstd_rwlock_read_unlock(NSTDRwLockReadGuard guard);
+
+/// Releases exclusive write access to a reader-writer lock by consuming a write guard.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLockWriteGuard guard` - The write guard.
+NSTDAPI void nstd_rwlock_write_unlock(NSTDRwLockWriteGuard guard);
+
+/// Frees an instance of `NSTDRwLock`.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLock lock` - The reader-writer lock to free.
+NSTDAPI void nstd_rwlock_free(NSTDRwLock lock);
+
+/// Frees an instance of `NSTDRwLock` after invoking `callback` with the lock's data.
+///
+/// `callback` will not be called if the lock is poisoned.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLock lock` - The reader-writer lock to free.
+///
+/// - `void (*callback)(NSTDAnyMut)` - The lock data's destructor.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`callback`).
+NSTDAPI void nstd_rwlock_drop(NSTDRwLock lock, void (*callback)(NSTDAnyMut));
+
+#endif
@@ -4,6 +4,7 @@
 #include "core/str.h"
 #include "nstd.h"
 #include "os/os.h"
+#include "string.h"
 #if NSTD_OS_WINDOWS
 #    include "os/windows/shared_lib.h"
 #endif
@@ -20,6 +21,11 @@ typedef NSTDWindowsSharedLib NSTDSharedLib;
 typedef NSTDAnyMut NSTDSharedLib;
 #endif
 
+/// Resolves all of the library's undefined symbols upon loading instead of lazily.
+///
+/// This is the default (and only) behavior on Windows, where this flag has no effect.
+#define NSTD_SHARED_LIB_NOW 1
+
 /// An optional handle to a shared library.
 ///
 /// This type is returned from `nstd_shared_lib_load`.
@@ -42,6 +48,43 @@ NSTDOptional(NSTDSharedLib) NSTDOptionalSharedLib;
 /// - The loaded library may have platform-specific initialization routines ran when it is loaded.
 NSTDAPI NSTDOptionalSharedLib nstd_shared_lib_load(const NSTDStr *path);
 
+/// Dynamically loads a shared library at runtime with a set of load flags.
+///
+/// On Windows `flags` is ignored, libraries are always loaded with `LoadLibraryW`'s default
+/// behavior.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the shared library.
+///
+/// - `NSTDUInt8 flags` - A bit mask of `NSTD_SHARED_LIB_*` flags.
+///
+/// # Returns
+///
+/// `NSTDOptionalSharedLib lib` - A handle to the dynamically loaded library, or none on error.
+///
+/// # Safety
+///
+/// - `path`'s data must be valid for reads.
+///
+/// - The loaded library may have platform-specific initialization routines ran when it is loaded.
+NSTDAPI NSTDOptionalSharedLib
+nstd_shared_lib_load_with_flags(const NSTDStr *path, NSTDUInt8 flags);
+
+/// Returns a raw native handle to a dynamically loaded library.
+///
+/// On Unix systems this is the handle returned from `dlopen`, and on Windows this is the module's
+/// `HMODULE`.
+///
+/// # Parameters:
+///
+/// - `const NSTDSharedLib *lib` - The loaded library.
+///
+/// # Returns
+///
+/// `NSTDAnyMut handle` - A raw handle to the dynamically loaded library.
+NSTDAPI NSTDAnyMut nstd_shared_lib_handle(const NSTDSharedLib *lib);
+
 /// Gets a pointer to a function or static variable in a dynamically loaded library by symbol name.
 ///
 /// # Parameters
@@ -77,6 +120,55 @@ NSTDAPI NSTDAny nstd_shared_lib_get(const NSTDSharedLib *lib, const NSTDChar *sy
 /// Undefined behavior may occur if `symbol`'s data is invalid.
 NSTDAPI NSTDAnyMut nstd_shared_lib_get_mut(NSTDSharedLib *lib, const NSTDChar *symbol);
 
+/// Gets a pointer to a function or static variable in a dynamically loaded library by symbol name,
+/// reporting whether or not the symbol was found.
+///
+/// Unlike `nstd_shared_lib_get`, this function can distinguish a missing symbol from a symbol
+/// whose address is null.
+///
+/// # Parameters
+///
+/// - `const NSTDSharedLib *lib` - The loaded library.
+///
+/// - `const NSTDChar *symbol` - The name of the function or variable to get a pointer to.
+///
+/// - `NSTDAny *ptr` - Receives the pointer to the function or variable on success.
+///
+/// # Returns
+///
+/// `NSTDBool found` - `NSTD_TRUE` if the symbol was found, or `NSTD_FALSE` if it wasn't, in which
+/// case `ptr` is left unchanged.
+///
+/// # Safety
+///
+/// - Undefined behavior may occur if `symbol`'s data is invalid.
+///
+/// - On Unix systems this function may not be thread safe, see
+/// <https://man7.org/linux/man-pages/man3/dlerror.3.html>.
+NSTDAPI NSTDBool
+nstd_shared_lib_get_checked(const NSTDSharedLib *lib, const NSTDChar *symbol, NSTDAny *ptr);
+
+/// Returns a description of the most recent error that occurred while loading a shared library or
+/// retrieving one of its symbols.
+///
+/// On Unix systems calling this function clears the stored error, so subsequent calls will return
+/// "none" until another error occurs.
+///
+/// Only errors reported by the platform's library loader are described. If `nstd_shared_lib_load`
+/// fails before the loader is called, such as when allocating a null terminated copy of its path
+/// fails, the returned description may be stale or "none".
+///
+/// # Returns
+///
+/// `NSTDOptionalString error` - A human readable description of the last error, or an uninitialized
+/// "none" variant if there is no error to report.
+///
+/// # Safety
+///
+/// On Unix systems this function may not be thread safe, see
+/// <https://man7.org/linux/man-pages/man3/dlerror.3.html>.
+NSTDAPI NSTDOptionalString nstd_shared_lib_last_error(void);
+
 /// Unloads and frees the resources of a dynamically loaded library.
 ///
 /// # Parameters:
@@ -14,6 +14,19 @@ typedef struct {
     NSTDAllocLayout layout;
 } NSTDSharedPtr;
 
+/// A non-owning reference to a shared object.
+///
+/// Weak pointers do not keep the shared object alive, they must be upgraded into an
+/// `NSTDSharedPtr` before the object can be accessed.
+typedef struct {
+    /// The memory allocator.
+    const NSTDAllocator *allocator;
+    /// A raw pointer to private data about the shared object.
+    NSTDAnyMut ptr;
+    /// The shared object's memory layout.
+    NSTDAllocLayout layout;
+} NSTDWeakPtr;
+
 /// Represents an optional value of type `NSTDSharedPtr`.
 NSTDOptional(NSTDSharedPtr) NSTDOptionalSharedPtr;
 
@@ -113,6 +126,36 @@ NSTDAPI NSTDUInt nstd_shared_ptr_size(const NSTDSharedPtr *shared_ptr);
 /// `NSTDAny ptr` - A raw pointer to the shared object.
 NSTDAPI NSTDAny nstd_shared_ptr_get(const NSTDSharedPtr *shared_ptr);
 
+/// Creates a new weak pointer to `shared_ptr`'s data.
+///
+/// # Parameters:
+///
+/// - `const NSTDSharedPtr *shared_ptr` - The shared object to create a weak pointer to.
+///
+/// # Returns
+///
+/// `NSTDWeakPtr weak` - A new weak pointer to the shared data.
+NSTDAPI NSTDWeakPtr nstd_shared_ptr_downgrade(const NSTDSharedPtr *shared_ptr);
+
+/// Attempts to create a new shared pointer from a weak pointer.
+///
+/// # Parameters:
+///
+/// - `const NSTDWeakPtr *weak` - The weak pointer.
+///
+/// # Returns
+///
+/// `NSTDOptionalSharedPtr shared_ptr` - A new pointer to the shared data, or an uninitialized
+/// "none" variant if the shared object has already been freed.
+NSTDAPI NSTDOptionalSharedPtr nstd_shared_ptr_weak_upgrade(const NSTDWeakPtr *weak);
+
+/// Frees an instance of `NSTDWeakPtr`.
+///
+/// # Parameters:
+///
+/// - `NSTDWeakPtr weak` - The weak pointer to free.
+NSTDAPI void nstd_shared_ptr_weak_free(NSTDWeakPtr weak);
+
 /// Frees an instance of `NSTDSharedPtr`.
 ///
 /// # Parameters:
@@ -133,4 +176,113 @@ NSTDAPI void nstd_shared_ptr_free(NSTDSharedPtr shared_ptr);
 /// This operation makes a direct call on a C function pointer (`callback`).
 NSTDAPI void nstd_shared_ptr_drop(NSTDSharedPtr shared_ptr, void (*callback)(NSTDAnyMut));
 
+
+/// A thread-safe reference counting smart pointer.
+///
+/// Unlike `NSTDSharedPtr`, this type's reference count is updated atomically, allowing it to be
+/// shared between threads.
+typedef struct {
+    /// The memory allocator.
+    const NSTDAllocator *allocator;
+    /// A raw pointer to private data about the shared object.
+    NSTDAnyMut ptr;
+    /// The shared object's memory layout.
+    NSTDAllocLayout layout;
+} NSTDAtomicSharedPtr;
+
+/// Represents an optional value of type `NSTDAtomicSharedPtr`.
+NSTDOptional(NSTDAtomicSharedPtr) NSTDOptionalAtomicSharedPtr;
+
+/// Creates a new initialized instance of a thread-safe shared pointer.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDAllocLayout layout` - The shared object's memory layout.
+///
+/// - `NSTDAny init` - A pointer to the object to initialize the shared pointer with.
+///
+/// # Returns
+///
+/// `NSTDOptionalAtomicSharedPtr shared_ptr` - The new shared pointer, or an uninitialized "none"
+/// variant if allocating fails.
+///
+/// # Safety
+///
+/// `init` must be a pointer to a value that is valid for reads based on `layout`.
+NSTDAPI NSTDOptionalAtomicSharedPtr
+nstd_shared_ptr_new_atomic(const NSTDAllocator *allocator, NSTDAllocLayout layout, NSTDAny init);
+
+/// Creates a new zero-initialized instance of a thread-safe shared pointer.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDAllocLayout layout` - The shared object's memory layout.
+///
+/// # Returns
+///
+/// `NSTDOptionalAtomicSharedPtr shared_ptr` - The yet to be shared pointer, or an uninitialized
+/// "none" variant if allocating fails.
+///
+/// # Safety
+///
+/// The data to be stored in the shared pointer must be safely representable by an all-zero byte
+/// pattern.
+NSTDAPI NSTDOptionalAtomicSharedPtr
+nstd_shared_ptr_new_atomic_zeroed(const NSTDAllocator *allocator, NSTDAllocLayout layout);
+
+/// Shares `shared_ptr`.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - The shared object to share.
+///
+/// # Returns
+///
+/// `NSTDAtomicSharedPtr shared` - A new pointer pointing to the shared data.
+NSTDAPI NSTDAtomicSharedPtr nstd_shared_ptr_atomic_share(const NSTDAtomicSharedPtr *shared_ptr);
+
+/// Returns the number of pointers that share `shared_ptr`'s data.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - An instance of a shared pointer.
+///
+/// # Returns
+///
+/// `NSTDUInt owners` - The number of pointers that share `shared_ptr`'s data.
+NSTDAPI NSTDUInt nstd_shared_ptr_atomic_owners(const NSTDAtomicSharedPtr *shared_ptr);
+
+/// Returns the size of the shared object.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - The shared pointer.
+///
+/// # Returns
+///
+/// `NSTDUInt size` - The size of the shared object.
+NSTDAPI NSTDUInt nstd_shared_ptr_atomic_size(const NSTDAtomicSharedPtr *shared_ptr);
+
+/// Returns an immutable raw pointer to the shared object.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - The shared pointer.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A raw pointer to the shared object.
+NSTDAPI NSTDAny nstd_shared_ptr_atomic_get(const NSTDAtomicSharedPtr *shared_ptr);
+
+/// Frees an instance of `NSTDAtomicSharedPtr`.
+///
+/// # Parameters:
+///
+/// - `NSTDAtomicSharedPtr shared_ptr` - The shared object to free.
+NSTDAPI void nstd_shared_ptr_atomic_free(NSTDAtomicSharedPtr shared_ptr);
+
 #endif
@@ -1,6 +1,7 @@
 #ifndef NSTD_STRING_H
 #define NSTD_STRING_H
 #include "core/alloc.h"
+#include "core/cstr.h"
 #include "core/def.h"
 #include "core/optional.h"
 #include "core/slice.h"
@@ -77,6 +78,26 @@ NSTDAPI NSTDOptionalString nstd_string_from_str(const NSTDAllocator *allocator,
 /// This operation will panic if `bytes`'s stride is not 1.
 NSTDAPI NSTDOptionalString nstd_string_from_bytes(NSTDVec bytes);
 
+/// Creates a new string from a C string slice, replacing any invalid UTF-8 sequences with the
+/// replacement character (U+FFFD).
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `const NSTDCStr *cstr` - The C string slice to convert.
+///
+/// # Returns
+///
+/// `NSTDOptionalString string` - The new string on success, or an uninitialized "none" variant if
+/// allocating fails.
+///
+/// # Safety
+///
+/// The caller of this function must ensure that `cstr`'s data is valid for reads.
+NSTDAPI NSTDOptionalString
+nstd_string_from_cstr_lossy(const NSTDAllocator *allocator, const NSTDCStr *cstr);
+
 /// Creates a deep copy of a string.
 ///
 /// # Parameters:
@@ -220,6 +241,29 @@ NSTDAPI NSTDAllocError nstd_string_push(NSTDString *string, NSTDUnichar chr);
 /// This function will cause undefined behavior in the case where `str`'s data is no longer valid.
 NSTDAPI NSTDAllocError nstd_string_push_str(NSTDString *string, const NSTDStr *str);
 
+/// Appends a slice of UTF-8 encoded bytes to the end of a string.
+///
+/// The bytes are validated before they're appended, `string` is left unchanged if they are not
+/// valid UTF-8.
+///
+/// # Parameters:
+///
+/// - `NSTDString *string` - The string.
+///
+/// - `const NSTDSlice *bytes` - The UTF-8 encoded bytes to append to the end of `string`.
+///
+/// # Returns
+///
+/// `NSTDOptionalAllocError errc` - An uninitialized "none" variant if `bytes` is not valid UTF-8,
+/// otherwise the allocation operation error code. The bytes were only appended if this is
+/// `NSTD_ALLOC_ERROR_NONE`, any other error code means that allocating failed and `string` is left
+/// unchanged.
+///
+/// # Safety
+///
+/// `bytes`'s data must be valid for reads.
+NSTDAPI NSTDOptionalAllocError nstd_string_push_bytes(NSTDString *string, const NSTDSlice *bytes);
+
 /// Removes the last character from a string and returns it.
 ///
 /// # Parameters:
@@ -231,6 +275,38 @@ NSTDAPI NSTDAllocError nstd_string_push_str(NSTDString *string, const NSTDStr *s
 /// `NSTDOptionalUnichar chr` - The removed character on success.
 NSTDAPI NSTDOptionalUnichar nstd_string_pop(NSTDString *string);
 
+/// Removes the character starting at byte `index` from a string and returns it.
+///
+/// All bytes after the removed character are shifted to the left.
+///
+/// # Parameters:
+///
+/// - `NSTDString *string` - The string.
+///
+/// - `NSTDUInt index` - The byte index of the character to remove.
+///
+/// # Returns
+///
+/// `NSTDOptionalUnichar chr` - The removed character on success, or an uninitialized "none"
+/// variant if `index` is equal to the string's byte length.
+///
+/// # Panics
+///
+/// This operation will panic if `index` does not lie on a character boundary or is greater than
+/// the string's byte length.
+NSTDAPI NSTDOptionalUnichar nstd_string_remove(NSTDString *string, NSTDUInt index);
+
+/// Shortens a string, keeping the first `len` Unicode characters.
+///
+/// This operation does nothing if `len` is greater than or equal to the string's current length.
+///
+/// # Parameters:
+///
+/// - `NSTDString *string` - The string to truncate.
+///
+/// - `NSTDUInt len` - The number of characters to keep.
+NSTDAPI void nstd_string_truncate(NSTDString *string, NSTDUInt len);
+
 /// Sets a string's length to zero.
 ///
 /// # Parameters:
@@ -370,6 +446,44 @@ NSTDAPI NSTDString nstd_string_from_i64(NSTDInt64 v);
 /// `NSTDString string` - The 64-bit unsigned integer value as a string.
 NSTDAPI NSTDString nstd_string_from_u64(NSTDUInt64 v);
 
+/// Creates a new `NSTDString` from an `NSTDFloat64` with a fixed number of fractional digits.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDFloat64 v` - The 64-bit floating-point value.
+///
+/// - `NSTDUInt precision` - The number of digits to write after the decimal point.
+///
+/// # Returns
+///
+/// `NSTDOptionalString string` - The 64-bit floating-point value as a string on success, or an
+/// uninitialized "none" variant if `precision` is greater than 65535 or allocating fails.
+NSTDAPI NSTDOptionalString nstd_string_from_f64_precision(
+    const NSTDAllocator *allocator, NSTDFloat64 v, NSTDUInt precision
+);
+
+/// Creates a new `NSTDString` from an `NSTDInt` using the given `radix`.
+///
+/// Digits greater than 9 are written as lowercase letters, and no prefix (such as "0x") is
+/// written. Negative values are prefixed with a '-' sign.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDInt v` - The arch-bit signed integer value.
+///
+/// - `NSTDUInt32 radix` - The base to write `v` in, in the range [2, 36].
+///
+/// # Returns
+///
+/// `NSTDOptionalString string` - The arch-bit signed integer value as a string on success, or an
+/// uninitialized "none" variant if `radix` is invalid or allocating fails.
+NSTDAPI NSTDOptionalString
+nstd_string_from_int_radix(const NSTDAllocator *allocator, NSTDInt v, NSTDUInt32 radix);
+
 /// Frees an instance of `NSTDString`.
 ///
 /// # Parameters:
@@ -7,6 +7,7 @@
 #include "heap_ptr.h"
 #include "io/io.h"
 #include "nstd.h"
+#include "time.h"
 
 /// Represents a running thread.
 typedef struct {
@@ -31,7 +32,7 @@ typedef struct {
 
 /// Describes the creation of a new thread.
 ///
-/// This type is passed to the `nstd_thread_spawn_with_desc` function.
+/// This type is passed to the `nstd_thread_spawn` function.
 typedef struct {
     /// The name of the thread.
     ///
@@ -43,6 +44,14 @@ typedef struct {
     NSTDUInt stack_size;
 } NSTDThreadDescriptor;
 
+/// A handle to a thread scope, used to spawn threads that may borrow non-`'static` data.
+///
+/// This type is passed to the callback given to `nstd_thread_scope`.
+typedef struct {
+    /// The Rust [Scope].
+    NSTDAny scope;
+} NSTDThreadScope;
+
 /// A thread function's return value.
 typedef NSTDOptionalHeapPtr NSTDThreadResult;
 
@@ -80,6 +89,59 @@ NSTDAPI NSTDOptionalThread nstd_thread_spawn(
     const NSTDThreadDescriptor *desc
 );
 
+/// Creates a thread scope and invokes `callback` with a handle to it.
+///
+/// Threads spawned within the scope with `nstd_thread_scope_spawn` may borrow data that outlives
+/// this function call, as all of them are joined before this function returns.
+///
+/// # Parameters:
+///
+/// - `void (*callback)(const NSTDThreadScope *, NSTDAnyMut)` - The function to invoke with the
+/// thread scope.
+///
+/// - `NSTDAnyMut data` - Data to pass to `callback`.
+///
+/// # Panics
+///
+/// Panics if any of the scope's threads panic.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`callback`).
+NSTDAPI void
+nstd_thread_scope(void (*callback)(const NSTDThreadScope *, NSTDAnyMut), NSTDAnyMut data);
+
+/// Spawns a new thread within a thread scope executing the function `thread_fn`.
+///
+/// The thread is joined automatically when the scope created by `nstd_thread_scope` ends.
+///
+/// # Parameters:
+///
+/// - `const NSTDThreadScope *scope` - The thread scope.
+///
+/// - `void (*thread_fn)(NSTDAnyMut)` - The thread function.
+///
+/// - `NSTDAnyMut data` - Data to send to the thread.
+///
+/// - `const NSTDThreadDescriptor *desc` - The thread descriptor. This value may be null.
+///
+/// # Returns
+///
+/// `NSTDBool is_spawned` - True if the thread was spawned successfully.
+///
+/// # Safety
+///
+/// - The caller of this function must guarantee that `thread_fn` is a valid function pointer.
+///
+/// - This operation can cause undefined behavior if `desc.name`'s data is invalid.
+///
+/// - The data that `data` points to must be able to be safely sent between threads, and must
+/// remain valid until the scope ends.
+NSTDAPI NSTDBool nstd_thread_scope_spawn(
+    const NSTDThreadScope *scope, void (*thread_fn)(NSTDAnyMut), NSTDAnyMut data,
+    const NSTDThreadDescriptor *desc
+);
+
 /// Returns a handle to the calling thread.
 ///
 /// # Returns
@@ -184,6 +246,23 @@ NSTDAPI void nstd_thread_handle_free(NSTDThreadHandle handle);
 /// Panics if `duration` is negative, overflows Rust's `Duration` structure, or is non-finite.
 NSTDAPI void nstd_thread_sleep(NSTDDuration duration);
 
+/// Puts the current thread to sleep until a monotonic `deadline` is reached.
+///
+/// This function returns immediately if `deadline` has already passed.
+///
+/// # Parameters:
+///
+/// - `NSTDInstant deadline` - The instant to sleep until.
+NSTDAPI void nstd_thread_sleep_until(NSTDInstant deadline);
+
+/// Yields the current thread's time slice, allowing other threads to run.
+///
+/// # Note
+///
+/// This is only a hint to the OS's scheduler, it may return immediately without the thread being
+/// descheduled.
+NSTDAPI void nstd_thread_yield(void);
+
 /// Returns the number of recommended threads that a program should use.
 ///
 /// # Returns
@@ -1,9 +1,11 @@
 #ifndef NSTD_TIME_H
 #define NSTD_TIME_H
+#include "core/alloc.h"
 #include "core/optional.h"
 #include "core/time.h"
 #include "nstd.h"
 #include "os/os.h"
+#include "string.h"
 #if NSTD_OS_UNIX
 #    include "os/unix/time.h"
 #endif
@@ -25,6 +27,23 @@ typedef struct {
 NSTDOptional(NSTDTime) NSTDOptionalTime;
 #endif
 
+/// A measurement of a monotonically nondecreasing clock.
+///
+/// Unlike `NSTDTime`, instants are unaffected by changes to the system clock, making them suitable
+/// for measuring elapsed time.
+typedef struct {
+    /// The time span since an unspecified point in time.
+    NSTDDuration duration;
+} NSTDInstant;
+
+/// A stopwatch that measures elapsed time with a monotonic clock.
+typedef struct {
+    /// The instant that the stopwatch was started.
+    NSTDInstant start;
+    /// The instant of the stopwatch's most recent lap.
+    NSTDInstant lap;
+} NSTDStopwatch;
+
 /// Returns the current system time as an `NSTDTime` object.
 ///
 /// # Returns
@@ -33,6 +52,24 @@ NSTDOptional(NSTDTime) NSTDOptionalTime;
 /// failure.
 NSTDAPI NSTDOptionalTime nstd_time_now(void);
 
+/// Creates an `NSTDTime` object from a point in time relative to the Unix epoch.
+///
+/// # Note
+///
+/// `NSTDTime` stores time as a 64-bit floating-point number of seconds, so some nanosecond
+/// precision may be lost for times far from the epoch.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 seconds` - The number of seconds since January 1st 1970, may be negative.
+///
+/// - `NSTDUInt32 nanoseconds` - The number of nanoseconds to add to `seconds`.
+///
+/// # Returns
+///
+/// `NSTDTime time` - The new time object.
+NSTDAPI NSTDTime nstd_time_from_unix(NSTDInt64 seconds, NSTDUInt32 nanoseconds);
+
 /// Returns the number of seconds stored in an `NSTDTime` object as an `NSTDFloat64`.
 ///
 /// # Parameters:
@@ -67,6 +104,18 @@ NSTDAPI NSTDInt64 nstd_time_seconds(NSTDTime time);
 /// `NSTDUInt32 nanoseconds` - The number of nanoseconds held in `time`.
 NSTDAPI NSTDUInt32 nstd_time_nanoseconds(NSTDTime time);
 
+/// Returns the total number of nanoseconds between the Unix epoch and an `NSTDTime` object.
+///
+/// # Parameters:
+///
+/// - `NSTDTime time` - The time object.
+///
+/// # Returns
+///
+/// `NSTDInt64 nanoseconds` - The number of nanoseconds since January 1st 1970, negative for times
+/// before the epoch. This value saturates if it can't fit in an `NSTDInt64`.
+NSTDAPI NSTDInt64 nstd_time_unix_nanos(NSTDTime time);
+
 /// Computes the addition of an `NSTDTime` object and an `NSTDDuration`.
 ///
 /// # Parameters:
@@ -93,4 +142,88 @@ NSTDAPI NSTDTime nstd_time_add(NSTDTime time, NSTDDuration duration);
 /// `NSTDTime time` - The result of the subtraction.
 NSTDAPI NSTDTime nstd_time_sub(NSTDTime time, NSTDDuration duration);
 
+/// Formats an `NSTDTime` object as an ISO 8601 timestamp, such as `2024-01-02T15:04:05Z`.
+///
+/// # Note
+///
+/// The timestamp is always expressed in UTC, and any fractional seconds are discarded.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDTime time` - The time object.
+///
+/// # Returns
+///
+/// `NSTDOptionalString timestamp` - The formatted timestamp on success, or an uninitialized "none"
+/// variant if allocating fails.
+NSTDAPI NSTDOptionalString nstd_time_format_iso8601(const NSTDAllocator *allocator, NSTDTime time);
+
+/// Returns the current instant of a monotonic clock.
+///
+/// Instants are measured with Rust's `std::time::Instant` and stored as the time span since the
+/// first instant taken by the current process. Because the span is stored as an `NSTDFloat64`,
+/// instants lose a small amount of precision the longer the process runs.
+///
+/// # Returns
+///
+/// `NSTDInstant now` - The current instant.
+NSTDAPI NSTDInstant nstd_time_now_monotonic(void);
+
+/// Returns the amount of time that has passed since `instant` was measured.
+///
+/// # Parameters:
+///
+/// - `NSTDInstant instant` - The instant to measure from.
+///
+/// # Returns
+///
+/// `NSTDDuration elapsed` - The time span since `instant`.
+NSTDAPI NSTDDuration nstd_time_instant_elapsed(NSTDInstant instant);
+
+/// Computes the addition of an `NSTDInstant` and an `NSTDDuration`.
+///
+/// # Parameters:
+///
+/// - `NSTDInstant instant` - The instant.
+///
+/// - `NSTDDuration duration` - The duration to add.
+///
+/// # Returns
+///
+/// `NSTDInstant instant` - The result of the addition.
+NSTDAPI NSTDInstant nstd_time_instant_add(NSTDInstant instant, NSTDDuration duration);
+
+/// Starts a new stopwatch.
+///
+/// # Returns
+///
+/// `NSTDStopwatch stopwatch` - A new stopwatch started at the current instant.
+NSTDAPI NSTDStopwatch nstd_time_stopwatch_start(void);
+
+/// Records a lap, returning the amount of time that has passed since the previous lap.
+///
+/// The first lap is measured from when the stopwatch was started.
+///
+/// # Parameters:
+///
+/// - `NSTDStopwatch *stopwatch` - The stopwatch.
+///
+/// # Returns
+///
+/// `NSTDDuration lap` - The time span since the previous lap.
+NSTDAPI NSTDDuration nstd_time_stopwatch_lap(NSTDStopwatch *stopwatch);
+
+/// Returns the amount of time that has passed since a stopwatch was started.
+///
+/// # Parameters:
+///
+/// - `const NSTDStopwatch *stopwatch` - The stopwatch.
+///
+/// # Returns
+///
+/// `NSTDDuration total` - The time span since `stopwatch` was started.
+NSTDAPI NSTDDuration nstd_time_stopwatch_total(const NSTDStopwatch *stopwatch);
+
 #endif
@@ -131,7 +131,7 @@ NSTDAPI NSTDOptionalTimedMutexLockResult nstd_timed_mutex_lock(const NSTDTimedMu
 NSTDAPI NSTDOptionalTimedMutexLockResult nstd_timed_mutex_try_lock(const NSTDTimedMutex *mutex);
 
 /// The timed variant of `nstd_timed_mutex_lock` returning an uninitialized "none" result if
-/// the mutex lock could not be acquired after a specified number of `seconds`.
+/// the mutex lock could not be acquired within the relative `duration`.
 ///
 /// Attempting to call this function on a thread that already owns the lock will result in
 /// undefined behavior.
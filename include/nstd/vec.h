@@ -3,6 +3,7 @@
 #include "core/alloc.h"
 #include "core/def.h"
 #include "core/optional.h"
+#include "core/result.h"
 #include "core/slice.h"
 #include "nstd.h"
 
@@ -25,6 +26,10 @@ typedef struct {
 /// Represents an optional value of type `NSTDVec`.
 NSTDOptional(NSTDVec) NSTDOptionalVec;
 
+/// A result type returned from `nstd_vec_relocate` that yields the relocated vector on success and
+/// the original vector on failure.
+NSTDResult(NSTDVec, NSTDVec) NSTDVecRelocateResult;
+
 /// Creates a new vector without allocating any resources.
 ///
 /// # Parameters:
@@ -65,6 +70,26 @@ NSTDAPI NSTDOptionalVec nstd_vec_new_with_cap(
     const NSTDAllocator *allocator, NSTDUInt stride, NSTDUInt align, NSTDUInt cap
 );
 
+/// Creates a new vector containing `len` zero-initialized values.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDUInt stride` - The size in bytes of each value in the vector.
+///
+/// - `NSTDUInt align` - The alignment of each value in the vector.
+///
+/// - `NSTDUInt len` - The number of zeroed values to create the vector with.
+///
+/// # Returns
+///
+/// `NSTDOptionalVec vec` - The new vector on success, or an uninitialized "none" variant if
+/// allocation fails or the memory layout is invalid.
+NSTDAPI NSTDOptionalVec nstd_vec_new_zeroed(
+    const NSTDAllocator *allocator, NSTDUInt stride, NSTDUInt align, NSTDUInt len
+);
+
 /// Creates a new vector from a slice.
 ///
 /// # Parameters:
@@ -106,6 +131,24 @@ NSTDAPI NSTDOptionalVec nstd_vec_clone(const NSTDVec *vec);
 /// `const NSTDAllocator *allocator` - The vector's allocator.
 NSTDAPI const NSTDAllocator *nstd_vec_allocator(const NSTDVec *vec);
 
+/// Moves a vector's data into a new buffer allocated with `allocator`, freeing the old buffer with
+/// the vector's previous allocator.
+///
+/// On success, the returned vector uses `allocator` for all future allocations and its capacity is
+/// equal to its length.
+///
+/// # Parameters:
+///
+/// - `NSTDVec vec` - The vector to relocate.
+///
+/// - `const NSTDAllocator *allocator` - The new memory allocator.
+///
+/// # Returns
+///
+/// `NSTDVecRelocateResult relocated` - The relocated vector on success, or the original vector,
+/// left unchanged, if allocating fails.
+NSTDAPI NSTDVecRelocateResult nstd_vec_relocate(NSTDVec vec, const NSTDAllocator *allocator);
+
 /// Returns the length of a vector.
 ///
 /// # Parameters:
@@ -121,6 +164,9 @@ NSTDAPI NSTDUInt nstd_vec_len(const NSTDVec *vec);
 ///
 /// This is the max number of values the vector can contain without reallocating.
 ///
+/// Vectors with a stride of 0 never need to allocate, so their capacity is always `NSTDUInt`'s max
+/// value.
+///
 /// # Parameters:
 ///
 /// - `const NSTDVec *vec` - The vector.
@@ -174,6 +220,19 @@ NSTDAPI NSTDSlice nstd_vec_as_slice(const NSTDVec *vec);
 /// `NSTDSliceMut slice` - A *mutable* view into the vector.
 NSTDAPI NSTDSliceMut nstd_vec_as_slice_mut(NSTDVec *vec);
 
+/// Returns an immutable byte slice over all of a vector's active elements, regardless of the
+/// vector's stride.
+///
+/// # Parameters:
+///
+/// - `const NSTDVec *vec` - The vector.
+///
+/// # Returns
+///
+/// `NSTDSlice bytes` - An *immutable* view into the vector's active bytes, with a stride and
+/// alignment of 1.
+NSTDAPI NSTDSlice nstd_vec_as_bytes(const NSTDVec *vec);
+
 /// Returns a pointer to a vector's raw data.
 ///
 /// # Parameters:
@@ -279,8 +338,29 @@ NSTDAPI NSTDAnyMut nstd_vec_get_mut(NSTDVec *vec, NSTDUInt pos);
 ///
 /// This operation is unsafe because undefined behavior can occur if the size of the value being
 /// pushed onto the vector is not equal to `vec`'s stride.
+///
+/// `value` must not point into `vec`'s own buffer, as the buffer may be reallocated before the
+/// value is copied. Use `nstd_vec_push_from_within` to push a copy of one of `vec`'s elements.
 NSTDAPI NSTDAllocError nstd_vec_push(NSTDVec *vec, NSTDAny value);
 
+/// Pushes a copy of the element at `index` onto the end of a vector.
+///
+/// Unlike passing a pointer to one of `vec`'s elements to `nstd_vec_push`, this is safe to use
+/// when pushing requires the vector to reallocate.
+///
+/// # Parameters:
+///
+/// - `NSTDVec *vec` - The vector.
+///
+/// - `NSTDUInt index` - The index of the element to copy.
+///
+/// # Returns
+///
+/// `NSTDAllocError errc` - The allocation operation error code. This will be
+/// `NSTD_ALLOC_ERROR_INVALID_LAYOUT` if `index` is out of the vector's bounds, in which case the
+/// vector is left unchanged.
+NSTDAPI NSTDAllocError nstd_vec_push_from_within(NSTDVec *vec, NSTDUInt index);
+
 /// Removes the last value of a vector and returns a pointer to it.
 ///
 /// # Note
@@ -322,6 +402,9 @@ NSTDAPI NSTDAny nstd_vec_pop(NSTDVec *vec);
 ///
 /// This operation is unsafe because undefined behavior can occur if the size of the value being
 /// inserted into the vector is not equal to `vec`'s stride.
+///
+/// `value` must not point into `vec`'s own buffer, as the buffer may be reallocated or shifted
+/// before the value is copied.
 NSTDAPI NSTDErrorCode nstd_vec_insert(NSTDVec *vec, NSTDAny value, NSTDUInt index);
 
 /// Removes the element at `index` in a vector.
@@ -358,6 +441,24 @@ NSTDAPI NSTDErrorCode nstd_vec_remove(NSTDVec *vec, NSTDUInt index);
 /// This operation can cause undefined behavior if `values`'s data is invalid.
 NSTDAPI NSTDAllocError nstd_vec_extend(NSTDVec *vec, const NSTDSlice *values);
 
+/// Copies a range of a vector's elements onto the end of the same vector.
+///
+/// # Parameters:
+///
+/// - `NSTDVec *vec` - The vector to extend.
+///
+/// - `NSTDUInt start` - The index of the first element to copy.
+///
+/// - `NSTDUInt len` - The number of elements to copy.
+///
+/// # Returns
+///
+/// `NSTDAllocError errc` - The allocation operation error code. This will be
+/// `NSTD_ALLOC_ERROR_INVALID_LAYOUT` if the range `[start, start + len)` is out of the vector's
+/// bounds or if the vector's new length would overflow, in which case the vector is left
+/// unchanged.
+NSTDAPI NSTDAllocError nstd_vec_extend_from_within(NSTDVec *vec, NSTDUInt start, NSTDUInt len);
+
 /// Shortens a vector, keeping the first `len` elements.
 ///
 /// # Note
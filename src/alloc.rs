@@ -15,7 +15,7 @@ use crate::{
         mem::{nstd_core_mem_copy, nstd_core_mem_dangling_mut},
         optional::NSTDOptional,
     },
-    NSTDAny, NSTDAnyMut, NSTD_NULL,
+    NSTDAny, NSTDAnyMut, NSTDUInt, NSTD_NULL,
 };
 use cfg_if::cfg_if;
 use core::{
@@ -23,6 +23,7 @@ use core::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
     ptr::addr_of,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 use nstdapi::nstdapi;
 
@@ -158,6 +159,250 @@ pub static NSTD_ALLOCATOR: NSTDAllocator = NSTDAllocator {
     deallocate,
 };
 
+/// The `NSTD_NULL_ALLOCATOR`'s `allocate` and `allocate_zeroed` functions.
+#[inline]
+const unsafe extern "C" fn null_allocate(_: NSTDAny, _: NSTDAllocLayout) -> NSTDAnyMut {
+    NSTD_NULL
+}
+
+/// The `NSTD_NULL_ALLOCATOR`'s `reallocate` function.
+#[inline]
+const unsafe extern "C" fn null_reallocate(
+    _: NSTDAny,
+    _: &mut NSTDAnyMut,
+    _: NSTDAllocLayout,
+    _: NSTDAllocLayout,
+) -> NSTDAllocError {
+    NSTDAllocError::NSTD_ALLOC_ERROR_OUT_OF_MEMORY
+}
+
+/// The `NSTD_NULL_ALLOCATOR`'s `deallocate` function.
+#[inline]
+const unsafe extern "C" fn null_deallocate(
+    _: NSTDAny,
+    ptr: NSTDAnyMut,
+    _: NSTDAllocLayout,
+) -> NSTDAllocError {
+    match ptr.is_null() {
+        true => NSTDAllocError::NSTD_ALLOC_ERROR_NONE,
+        false => NSTDAllocError::NSTD_ALLOC_ERROR_MEMORY_NOT_FOUND,
+    }
+}
+
+/// An allocator that fails every allocation request.
+///
+/// `allocate` and `allocate_zeroed` always return null, `reallocate` always returns
+/// `NSTD_ALLOC_ERROR_OUT_OF_MEMORY`, and `deallocate` accepts null pointers. This is useful for
+/// testing how code handles allocation failure.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_NULL_ALLOCATOR,
+///     core::alloc::{nstd_core_alloc_layout_new, NSTDAllocError::NSTD_ALLOC_ERROR_NONE},
+///     NSTD_NULL,
+/// };
+///
+/// unsafe {
+///     let layout = nstd_core_alloc_layout_new(16, 8).unwrap();
+///     let mem = (NSTD_NULL_ALLOCATOR.allocate)(NSTD_NULL_ALLOCATOR.state, layout);
+///     assert!(mem.is_null());
+///     let errc = (NSTD_NULL_ALLOCATOR.deallocate)(NSTD_NULL_ALLOCATOR.state, NSTD_NULL, layout);
+///     assert!(errc == NSTD_ALLOC_ERROR_NONE);
+/// }
+/// ```
+#[nstdapi]
+pub static NSTD_NULL_ALLOCATOR: NSTDAllocator = NSTDAllocator {
+    state: NSTD_NULL,
+    allocate: null_allocate,
+    allocate_zeroed: null_allocate,
+    reallocate: null_reallocate,
+    deallocate: null_deallocate,
+};
+
+/// An allocator wrapper that keeps track of the number of live allocations and bytes in use.
+///
+/// Use `nstd_alloc_counting_allocator` to obtain an `NSTDAllocator` that forwards all requests to
+/// the wrapped allocator while updating these counters.
+///
+/// The fields of this structure are private. The counters are updated atomically and should only
+/// be read through `nstd_alloc_counting_bytes_in_use` and `nstd_alloc_counting_allocation_count`.
+#[nstdapi]
+pub struct NSTDCountingAllocator<'a> {
+    /// The wrapped allocator.
+    allocator: &'a NSTDAllocator,
+    /// The number of bytes currently allocated.
+    bytes_in_use: AtomicUsize,
+    /// The number of live allocations.
+    allocation_count: AtomicUsize,
+}
+
+/// Forwards an `NSTDCountingAllocator`'s `allocate` call to the wrapped allocator.
+unsafe extern "C" fn counting_allocate(this: NSTDAny, layout: NSTDAllocLayout) -> NSTDAnyMut {
+    let this = &*this.cast::<NSTDCountingAllocator<'_>>();
+    let ptr = (this.allocator.allocate)(this.allocator.state, layout);
+    if !ptr.is_null() {
+        let size = nstd_core_alloc_layout_size(layout);
+        this.bytes_in_use.fetch_add(size, Ordering::Relaxed);
+        this.allocation_count.fetch_add(1, Ordering::Relaxed);
+    }
+    ptr
+}
+
+/// Forwards an `NSTDCountingAllocator`'s `allocate_zeroed` call to the wrapped allocator.
+unsafe extern "C" fn counting_allocate_zeroed(
+    this: NSTDAny,
+    layout: NSTDAllocLayout,
+) -> NSTDAnyMut {
+    let this = &*this.cast::<NSTDCountingAllocator<'_>>();
+    let ptr = (this.allocator.allocate_zeroed)(this.allocator.state, layout);
+    if !ptr.is_null() {
+        let size = nstd_core_alloc_layout_size(layout);
+        this.bytes_in_use.fetch_add(size, Ordering::Relaxed);
+        this.allocation_count.fetch_add(1, Ordering::Relaxed);
+    }
+    ptr
+}
+
+/// Forwards an `NSTDCountingAllocator`'s `reallocate` call to the wrapped allocator.
+unsafe extern "C" fn counting_reallocate(
+    this: NSTDAny,
+    ptr: &mut NSTDAnyMut,
+    old_layout: NSTDAllocLayout,
+    new_layout: NSTDAllocLayout,
+) -> NSTDAllocError {
+    let this = &*this.cast::<NSTDCountingAllocator<'_>>();
+    let errc = (this.allocator.reallocate)(this.allocator.state, ptr, old_layout, new_layout);
+    if errc == NSTDAllocError::NSTD_ALLOC_ERROR_NONE {
+        let old_size = nstd_core_alloc_layout_size(old_layout);
+        let new_size = nstd_core_alloc_layout_size(new_layout);
+        this.bytes_in_use.fetch_add(new_size, Ordering::Relaxed);
+        this.bytes_in_use.fetch_sub(old_size, Ordering::Relaxed);
+    }
+    errc
+}
+
+/// Forwards an `NSTDCountingAllocator`'s `deallocate` call to the wrapped allocator.
+unsafe extern "C" fn counting_deallocate(
+    this: NSTDAny,
+    ptr: NSTDAnyMut,
+    layout: NSTDAllocLayout,
+) -> NSTDAllocError {
+    let this = &*this.cast::<NSTDCountingAllocator<'_>>();
+    let errc = (this.allocator.deallocate)(this.allocator.state, ptr, layout);
+    if errc == NSTDAllocError::NSTD_ALLOC_ERROR_NONE {
+        let size = nstd_core_alloc_layout_size(layout);
+        this.bytes_in_use.fetch_sub(size, Ordering::Relaxed);
+        this.allocation_count.fetch_sub(1, Ordering::Relaxed);
+    }
+    errc
+}
+
+/// Creates a new counting allocator that wraps `allocator`.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The allocator to forward allocation requests to.
+///
+/// # Returns
+///
+/// `NSTDCountingAllocator counting` - The new counting allocator with both of its counters set to
+/// zero.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::{
+///         nstd_alloc_counting_allocation_count, nstd_alloc_counting_allocator,
+///         nstd_alloc_counting_bytes_in_use, nstd_alloc_counting_new, NSTD_ALLOCATOR,
+///     },
+///     core::alloc::{nstd_core_alloc_layout_new, NSTDAllocError::NSTD_ALLOC_ERROR_NONE},
+/// };
+///
+/// unsafe {
+///     let counting = nstd_alloc_counting_new(&NSTD_ALLOCATOR);
+///     let allocator = nstd_alloc_counting_allocator(&counting);
+///     let layout = nstd_core_alloc_layout_new(64, 8).unwrap();
+///     let mem = (allocator.allocate)(allocator.state, layout);
+///     assert!(!mem.is_null());
+///     assert!(nstd_alloc_counting_allocation_count(&counting) == 1);
+///     assert!(nstd_alloc_counting_bytes_in_use(&counting) == 64);
+///     assert!((allocator.deallocate)(allocator.state, mem, layout) == NSTD_ALLOC_ERROR_NONE);
+///     assert!(nstd_alloc_counting_allocation_count(&counting) == 0);
+///     assert!(nstd_alloc_counting_bytes_in_use(&counting) == 0);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_alloc_counting_new(allocator: &NSTDAllocator) -> NSTDCountingAllocator<'_> {
+    NSTDCountingAllocator {
+        allocator,
+        bytes_in_use: AtomicUsize::new(0),
+        allocation_count: AtomicUsize::new(0),
+    }
+}
+
+/// Returns an `NSTDAllocator` that forwards allocation requests to the counting allocator's
+/// wrapped allocator while keeping track of them.
+///
+/// # Parameters:
+///
+/// - `const NSTDCountingAllocator *counting` - The counting allocator.
+///
+/// # Returns
+///
+/// `NSTDAllocator allocator` - The counting allocator's virtual function table.
+///
+/// # Safety
+///
+/// The returned allocator refers to `counting` directly, it must not be used after `counting` has
+/// been moved or freed.
+#[inline]
+#[nstdapi]
+pub const unsafe fn nstd_alloc_counting_allocator(
+    counting: &NSTDCountingAllocator<'_>,
+) -> NSTDAllocator {
+    NSTDAllocator {
+        state: addr_of!(*counting).cast(),
+        allocate: counting_allocate,
+        allocate_zeroed: counting_allocate_zeroed,
+        reallocate: counting_reallocate,
+        deallocate: counting_deallocate,
+    }
+}
+
+/// Returns the number of bytes currently allocated through a counting allocator.
+///
+/// # Parameters:
+///
+/// - `const NSTDCountingAllocator *counting` - The counting allocator.
+///
+/// # Returns
+///
+/// `NSTDUInt bytes_in_use` - The number of bytes that have been allocated but not yet freed.
+#[inline]
+#[nstdapi]
+pub fn nstd_alloc_counting_bytes_in_use(counting: &NSTDCountingAllocator<'_>) -> NSTDUInt {
+    counting.bytes_in_use.load(Ordering::Relaxed)
+}
+
+/// Returns the number of live allocations made through a counting allocator.
+///
+/// # Parameters:
+///
+/// - `const NSTDCountingAllocator *counting` - The counting allocator.
+///
+/// # Returns
+///
+/// `NSTDUInt allocation_count` - The number of allocations that have not yet been freed.
+#[inline]
+#[nstdapi]
+pub fn nstd_alloc_counting_allocation_count(counting: &NSTDCountingAllocator<'_>) -> NSTDUInt {
+    counting.allocation_count.load(Ordering::Relaxed)
+}
+
 /// The `NSTDAllocator`'s `allocate` function.
 #[inline]
 unsafe extern "C" fn rust_allocate(_: NSTDAny, layout: NSTDAllocLayout) -> NSTDAnyMut {
@@ -0,0 +1,142 @@
+//! A condition variable used to block threads until a shared condition is met.
+use crate::{
+    alloc::CBox,
+    core::{optional::NSTDOptional, time::NSTDDuration},
+    mutex::{lock_result, NSTDMutexGuard, NSTDOptionalMutexLockResult},
+};
+use nstdapi::nstdapi;
+use std::sync::{Condvar, PoisonError};
+
+/// A condition variable used to block threads until a shared condition is met.
+///
+/// Condition variables are always used together with an `NSTDMutex`.
+#[nstdapi]
+pub struct NSTDCondVar {
+    /// The Rust [Condvar].
+    cvar: CBox<Condvar>,
+}
+
+/// Represents an optional value of type `NSTDCondVar`.
+pub type NSTDOptionalCondVar = NSTDOptional<NSTDCondVar>;
+
+/// Creates a new condition variable.
+///
+/// # Returns
+///
+/// `NSTDOptionalCondVar cvar` - The new condition variable on success, or an uninitialized "none"
+/// variant on error.
+#[inline]
+#[nstdapi]
+pub fn nstd_condvar_new() -> NSTDOptionalCondVar {
+    CBox::new(Condvar::new()).map_or(NSTDOptional::None, |cvar| {
+        NSTDOptional::Some(NSTDCondVar { cvar })
+    })
+}
+
+/// Blocks the current thread until the condition variable is notified.
+///
+/// This function atomically unlocks the mutex guarded by `guard` and blocks the current thread.
+/// The mutex is locked again before this function returns.
+///
+/// Calls to this function may wake up spuriously, so the caller should re-check its condition
+/// after it returns.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+///
+/// - `NSTDMutexGuard guard` - The mutex guard.
+///
+/// # Returns
+///
+/// `NSTDOptionalMutexLockResult guard` - A handle to the mutex's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation may panic if `cvar` is used with more than one mutex.
+#[inline]
+#[nstdapi]
+pub fn nstd_condvar_wait<'m, 'a>(
+    cvar: &NSTDCondVar,
+    guard: NSTDMutexGuard<'m, 'a>,
+) -> NSTDOptionalMutexLockResult<'m, 'a> {
+    lock_result(cvar.cvar.wait(guard.into_inner()))
+}
+
+/// Blocks the current thread until the condition variable is notified or `duration` has elapsed.
+///
+/// This function atomically unlocks the mutex guarded by `guard` and blocks the current thread.
+/// The mutex is locked again before this function returns.
+///
+/// This function does not report whether or not the wait timed out, so the caller should re-check
+/// its condition after it returns.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+///
+/// - `NSTDMutexGuard guard` - The mutex guard.
+///
+/// - `NSTDDuration duration` - The maximum amount of time to block for.
+///
+/// # Returns
+///
+/// `NSTDOptionalMutexLockResult guard` - A handle to the mutex's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation will panic if `duration` is negative, overflows Rust's `Duration` structure, or
+/// is non-finite. It may also panic if `cvar` is used with more than one mutex.
+#[inline]
+#[nstdapi]
+pub fn nstd_condvar_wait_timeout<'m, 'a>(
+    cvar: &NSTDCondVar,
+    guard: NSTDMutexGuard<'m, 'a>,
+    duration: NSTDDuration,
+) -> NSTDOptionalMutexLockResult<'m, 'a> {
+    let result = cvar
+        .cvar
+        .wait_timeout(guard.into_inner(), duration.into_duration());
+    lock_result(result.map(|(guard, _)| guard).map_err(|err| {
+        let (guard, _) = err.into_inner();
+        PoisonError::new(guard)
+    }))
+}
+
+/// Wakes up one thread blocked on a condition variable.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+#[inline]
+#[nstdapi]
+pub fn nstd_condvar_notify_one(cvar: &NSTDCondVar) {
+    cvar.cvar.notify_one();
+}
+
+/// Wakes up all threads blocked on a condition variable.
+///
+/// # Parameters:
+///
+/// - `const NSTDCondVar *cvar` - The condition variable.
+#[inline]
+#[nstdapi]
+pub fn nstd_condvar_notify_all(cvar: &NSTDCondVar) {
+    cvar.cvar.notify_all();
+}
+
+/// Frees an instance of `NSTDCondVar`.
+///
+/// # Parameters:
+///
+/// - `NSTDCondVar cvar` - The condition variable to free.
+#[inline]
+#[nstdapi]
+#[allow(
+    unused_variables,
+    clippy::missing_const_for_fn,
+    clippy::needless_pass_by_value
+)]
+pub fn nstd_condvar_free(cvar: NSTDCondVar) {}
@@ -7,6 +7,7 @@ pub mod cstr;
 pub mod cty;
 pub mod def;
 pub mod fty;
+pub mod hash;
 pub mod ity;
 pub mod math;
 pub mod mem;
@@ -1,7 +1,15 @@
 //! Provides useful types for memory allocation support.
 use crate::{
-    core::optional::{gen_optional, NSTDOptional},
-    NSTDAny, NSTDAnyMut, NSTDUInt, NSTD_INT_MAX,
+    core::{
+        mem::{nstd_core_mem_copy, nstd_core_mem_zero},
+        optional::{gen_optional, NSTDOptional},
+        slice::{nstd_core_slice_mut_as_const, nstd_core_slice_mut_as_ptr, NSTDSliceMut},
+    },
+    NSTDAny, NSTDAnyMut, NSTDUInt, NSTD_INT_MAX, NSTD_NULL,
+};
+use core::{
+    ptr::addr_of,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 use nstdapi::nstdapi;
 
@@ -34,6 +42,7 @@ pub enum NSTDAllocError {
     /// An allocation function received input parameters that resulted in an invalid memory layout.
     NSTD_ALLOC_ERROR_INVALID_LAYOUT,
 }
+gen_optional!(NSTDOptionalAllocError, NSTDAllocError);
 
 /// A structure of function pointers making up an allocator's virtual function table.
 #[nstdapi]
@@ -295,3 +304,229 @@ pub const fn nstd_core_alloc_layout_size(layout: NSTDAllocLayout) -> NSTDUInt {
 pub const fn nstd_core_alloc_layout_align(layout: NSTDAllocLayout) -> NSTDUInt {
     layout.align
 }
+
+/// An allocator that satisfies allocation requests from a fixed, caller provided buffer.
+///
+/// Memory is handed out linearly from the start of the buffer. Freeing or shrinking the most
+/// recent allocation makes its memory available again, any other freed memory is only reclaimed
+/// once every allocation made after it has also been freed.
+///
+/// The fields of this structure are private and should only be initialized by
+/// `nstd_core_alloc_buffer_allocator_new`.
+#[nstdapi]
+pub struct NSTDBufferAllocator {
+    /// A pointer to the start of the buffer.
+    buffer: NSTDAnyMut,
+    /// The buffer's length in bytes.
+    len: NSTDUInt,
+    /// The number of bytes from the start of the buffer that are in use, updated atomically.
+    offset: AtomicUsize,
+}
+impl NSTDBufferAllocator {
+    /// Reserves `size` bytes aligned to `align` at the end of the buffer's used memory.
+    ///
+    /// Returns a null pointer if the buffer does not have enough space left.
+    ///
+    /// # Safety
+    ///
+    /// `self.buffer` must be valid for `self.len` bytes.
+    unsafe fn bump(&self, size: NSTDUInt, align: NSTDUInt) -> NSTDAnyMut {
+        let mut offset = self.offset.load(Ordering::Relaxed);
+        loop {
+            let pad = self.buffer.cast::<u8>().add(offset).align_offset(align);
+            let Some(begin) = offset.checked_add(pad) else {
+                return NSTD_NULL;
+            };
+            let Some(end) = begin.checked_add(size) else {
+                return NSTD_NULL;
+            };
+            if end > self.len {
+                return NSTD_NULL;
+            }
+            match self.offset.compare_exchange_weak(
+                offset,
+                end,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return self.buffer.cast::<u8>().add(begin).cast(),
+                Err(current) => offset = current,
+            }
+        }
+    }
+
+    /// Returns `ptr`'s byte offset from the start of the buffer, or [`None`] if it does not point
+    /// into the buffer or one byte past its end.
+    #[inline]
+    fn offset_of(&self, ptr: NSTDAny) -> Option<NSTDUInt> {
+        let begin = (ptr as NSTDUInt).wrapping_sub(self.buffer as NSTDUInt);
+        match begin <= self.len {
+            true => Some(begin),
+            false => None,
+        }
+    }
+}
+
+/// The `NSTDBufferAllocator`'s `allocate` function.
+#[inline]
+unsafe extern "C" fn buffer_allocate(this: NSTDAny, layout: NSTDAllocLayout) -> NSTDAnyMut {
+    let this = &*this.cast::<NSTDBufferAllocator>();
+    let size = nstd_core_alloc_layout_size(layout);
+    let align = nstd_core_alloc_layout_align(layout);
+    this.bump(size, align)
+}
+
+/// The `NSTDBufferAllocator`'s `allocate_zeroed` function.
+#[inline]
+unsafe extern "C" fn buffer_allocate_zeroed(this: NSTDAny, layout: NSTDAllocLayout) -> NSTDAnyMut {
+    let ptr = buffer_allocate(this, layout);
+    if !ptr.is_null() {
+        nstd_core_mem_zero(ptr.cast(), nstd_core_alloc_layout_size(layout));
+    }
+    ptr
+}
+
+/// The `NSTDBufferAllocator`'s `reallocate` function.
+unsafe extern "C" fn buffer_reallocate(
+    this: NSTDAny,
+    ptr: &mut NSTDAnyMut,
+    old_layout: NSTDAllocLayout,
+    new_layout: NSTDAllocLayout,
+) -> NSTDAllocError {
+    if old_layout == new_layout {
+        return NSTDAllocError::NSTD_ALLOC_ERROR_NONE;
+    }
+    let buffer = &*this.cast::<NSTDBufferAllocator>();
+    let Some(begin) = buffer.offset_of(*ptr) else {
+        return NSTDAllocError::NSTD_ALLOC_ERROR_MEMORY_NOT_FOUND;
+    };
+    let old_size = nstd_core_alloc_layout_size(old_layout);
+    let new_size = nstd_core_alloc_layout_size(new_layout);
+    let new_align = nstd_core_alloc_layout_align(new_layout);
+    // Attempt to resize the most recent allocation in place.
+    if let (Some(old_end), Some(new_end)) =
+        (begin.checked_add(old_size), begin.checked_add(new_size))
+    {
+        if new_end <= buffer.len
+            && (*ptr).cast::<u8>().align_offset(new_align) == 0
+            && buffer
+                .offset
+                .compare_exchange(old_end, new_end, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            return NSTDAllocError::NSTD_ALLOC_ERROR_NONE;
+        }
+    }
+    // Move the allocation to the end of the buffer.
+    let new_mem = buffer.bump(new_size, new_align);
+    if new_mem.is_null() {
+        return NSTDAllocError::NSTD_ALLOC_ERROR_OUT_OF_MEMORY;
+    }
+    nstd_core_mem_copy(new_mem.cast(), (*ptr).cast(), old_size.min(new_size));
+    buffer_deallocate(this, *ptr, old_layout);
+    *ptr = new_mem;
+    NSTDAllocError::NSTD_ALLOC_ERROR_NONE
+}
+
+/// The `NSTDBufferAllocator`'s `deallocate` function.
+unsafe extern "C" fn buffer_deallocate(
+    this: NSTDAny,
+    ptr: NSTDAnyMut,
+    layout: NSTDAllocLayout,
+) -> NSTDAllocError {
+    let this = &*this.cast::<NSTDBufferAllocator>();
+    let Some(begin) = this.offset_of(ptr) else {
+        return NSTDAllocError::NSTD_ALLOC_ERROR_MEMORY_NOT_FOUND;
+    };
+    // Only the most recent allocation can be given back to the buffer.
+    if let Some(end) = begin.checked_add(nstd_core_alloc_layout_size(layout)) {
+        let _ = this
+            .offset
+            .compare_exchange(end, begin, Ordering::Relaxed, Ordering::Relaxed);
+    }
+    NSTDAllocError::NSTD_ALLOC_ERROR_NONE
+}
+
+/// Creates a new allocator that hands out memory from `buffer`.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut buffer` - The memory buffer to allocate from.
+///
+/// # Returns
+///
+/// `NSTDBufferAllocator allocator` - The new buffer allocator.
+///
+/// # Safety
+///
+/// `buffer`'s data must remain valid for reads and writes, and must not be accessed through any
+/// other means, for as long as the allocator or any memory allocated by it is in use.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{
+///     alloc::{
+///         nstd_core_alloc_buffer_allocator_as_allocator, nstd_core_alloc_buffer_allocator_new,
+///         nstd_core_alloc_layout_new, NSTDAllocError::NSTD_ALLOC_ERROR_NONE,
+///     },
+///     slice::nstd_core_slice_mut_new,
+/// };
+///
+/// let mut buf = [0u8; 48];
+/// unsafe {
+///     let slice = nstd_core_slice_mut_new(buf.as_mut_ptr().cast(), 1, 1, buf.len()).unwrap();
+///     let buffer = nstd_core_alloc_buffer_allocator_new(slice);
+///     let allocator = nstd_core_alloc_buffer_allocator_as_allocator(&buffer);
+///     let layout = nstd_core_alloc_layout_new(48, 1).unwrap();
+///     let mem = (allocator.allocate)(allocator.state, layout);
+///     assert!(!mem.is_null());
+///     assert!((allocator.allocate)(allocator.state, layout).is_null());
+///     assert!((allocator.deallocate)(allocator.state, mem, layout) == NSTD_ALLOC_ERROR_NONE);
+///     assert!(!(allocator.allocate)(allocator.state, layout).is_null());
+///
+///     let empty = nstd_core_alloc_layout_new(0, 1).unwrap();
+///     let end = (allocator.allocate)(allocator.state, empty);
+///     assert!(end == buf.as_mut_ptr().add(48).cast());
+///     assert!((allocator.deallocate)(allocator.state, end, empty) == NSTD_ALLOC_ERROR_NONE);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_core_alloc_buffer_allocator_new(
+    mut buffer: NSTDSliceMut,
+) -> NSTDBufferAllocator {
+    NSTDBufferAllocator {
+        buffer: nstd_core_slice_mut_as_ptr(&mut buffer),
+        len: nstd_core_slice_mut_as_const(&buffer).byte_len(),
+        offset: AtomicUsize::new(0),
+    }
+}
+
+/// Returns an `NSTDAllocator` that allocates memory from a buffer allocator.
+///
+/// # Parameters:
+///
+/// - `const NSTDBufferAllocator *buffer` - The buffer allocator.
+///
+/// # Returns
+///
+/// `NSTDAllocator allocator` - The buffer allocator's virtual function table.
+///
+/// # Safety
+///
+/// The returned allocator refers to `buffer` directly, it must not be used after `buffer` has
+/// been moved or freed.
+#[inline]
+#[nstdapi]
+pub const unsafe fn nstd_core_alloc_buffer_allocator_as_allocator(
+    buffer: &NSTDBufferAllocator,
+) -> NSTDAllocator {
+    NSTDAllocator {
+        state: addr_of!(*buffer).cast(),
+        allocate: buffer_allocate,
+        allocate_zeroed: buffer_allocate_zeroed,
+        reallocate: buffer_reallocate,
+        deallocate: buffer_deallocate,
+    }
+}
@@ -0,0 +1,153 @@
+//! Non-cryptographic hashing.
+use crate::{core::slice::NSTDSlice, NSTDUInt64};
+use nstdapi::nstdapi;
+
+/// The 64-bit FNV offset basis.
+const FNV_OFFSET_BASIS: NSTDUInt64 = 0xCBF2_9CE4_8422_2325;
+
+/// The 64-bit FNV prime.
+const FNV_PRIME: NSTDUInt64 = 0x0100_0000_01B3;
+
+/// A streaming 64-bit FNV-1a hasher.
+///
+/// FNV-1a is a fast non-cryptographic hash function, it must not be used where resistance to
+/// malicious input is required.
+#[nstdapi]
+#[derive(Clone, Copy)]
+pub struct NSTDHasher {
+    /// The current hash state.
+    state: NSTDUInt64,
+}
+
+/// Folds `bytes` into the FNV-1a hash `state`.
+#[inline]
+const fn fnv1a(mut state: NSTDUInt64, bytes: &[u8]) -> NSTDUInt64 {
+    let mut i = 0;
+    while i < bytes.len() {
+        state ^= bytes[i] as NSTDUInt64;
+        state = state.wrapping_mul(FNV_PRIME);
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            i += 1;
+        }
+    }
+    state
+}
+
+/// Creates a new `NSTDHasher` in its initial state.
+///
+/// # Returns
+///
+/// `NSTDHasher hasher` - The new hasher.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::hash::{nstd_core_hash_finish, nstd_core_hash_new};
+///
+/// let hasher = nstd_core_hash_new();
+/// assert!(nstd_core_hash_finish(&hasher) == 0xCBF29CE484222325);
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_core_hash_new() -> NSTDHasher {
+    NSTDHasher {
+        state: FNV_OFFSET_BASIS,
+    }
+}
+
+/// Feeds all of the bytes covered by `bytes` into a hasher.
+///
+/// The slice's stride is ignored, every one of the slice's `len * stride` bytes is hashed.
+///
+/// # Parameters:
+///
+/// - `NSTDHasher *hasher` - The hasher.
+///
+/// - `const NSTDSlice *bytes` - The data to hash.
+///
+/// # Safety
+///
+/// `bytes`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{
+///     hash::{
+///         nstd_core_hash_bytes, nstd_core_hash_finish, nstd_core_hash_new, nstd_core_hash_write,
+///     },
+///     slice::nstd_core_slice_new,
+/// };
+///
+/// unsafe {
+///     let hello = nstd_core_slice_new("Hello, ".as_ptr().cast(), 1, 1, 7).unwrap();
+///     let world = nstd_core_slice_new("world!".as_ptr().cast(), 1, 1, 6).unwrap();
+///     let all = nstd_core_slice_new("Hello, world!".as_ptr().cast(), 1, 1, 13).unwrap();
+///     let mut hasher = nstd_core_hash_new();
+///     nstd_core_hash_write(&mut hasher, &hello);
+///     nstd_core_hash_write(&mut hasher, &world);
+///     assert!(nstd_core_hash_finish(&hasher) == nstd_core_hash_bytes(&all));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn)]
+pub unsafe fn nstd_core_hash_write(hasher: &mut NSTDHasher, bytes: &NSTDSlice) {
+    hasher.state = fnv1a(hasher.state, bytes.as_bytes());
+}
+
+/// Returns the hash of all of the data written to a hasher so far.
+///
+/// # Parameters:
+///
+/// - `const NSTDHasher *hasher` - The hasher.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The hash value.
+#[inline]
+#[nstdapi]
+pub const fn nstd_core_hash_finish(hasher: &NSTDHasher) -> NSTDUInt64 {
+    hasher.state
+}
+
+/// Computes the 64-bit FNV-1a hash of all of the bytes covered by `bytes`.
+///
+/// This is equivalent to writing `bytes` to a new `NSTDHasher` and finishing it.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *bytes` - The data to hash.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The hash value.
+///
+/// # Safety
+///
+/// `bytes`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{hash::nstd_core_hash_bytes, slice::nstd_core_slice_new};
+///
+/// const STRIDE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// unsafe {
+///     let bytes = nstd_core_slice_new("a".as_ptr().cast(), 1, 1, 1).unwrap();
+///     assert!(nstd_core_hash_bytes(&bytes) == 0xAF63DC4C8601EC8C);
+///
+///     let arr = [1u16, 2, 3];
+///     let words = nstd_core_slice_new(arr.as_ptr().cast(), STRIDE, ALIGN, 3).unwrap();
+///     let bytes = nstd_core_slice_new(arr.as_ptr().cast(), 1, 1, 6).unwrap();
+///     assert!(nstd_core_hash_bytes(&words) == nstd_core_hash_bytes(&bytes));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const unsafe fn nstd_core_hash_bytes(bytes: &NSTDSlice) -> NSTDUInt64 {
+    fnv1a(FNV_OFFSET_BASIS, bytes.as_bytes())
+}
@@ -10,6 +10,16 @@ use crate::{
 };
 use nstdapi::nstdapi;
 
+/// Archimedes' constant (π) as an `NSTDFloat32`.
+pub const NSTD_CORE_MATH_PI_F32: NSTDFloat32 = core::f32::consts::PI;
+/// Archimedes' constant (π) as an `NSTDFloat64`.
+pub const NSTD_CORE_MATH_PI_F64: NSTDFloat64 = core::f64::consts::PI;
+
+/// The full circle constant (τ = 2π) as an `NSTDFloat32`.
+pub const NSTD_CORE_MATH_TAU_F32: NSTDFloat32 = core::f32::consts::TAU;
+/// The full circle constant (τ = 2π) as an `NSTDFloat64`.
+pub const NSTD_CORE_MATH_TAU_F64: NSTDFloat64 = core::f64::consts::TAU;
+
 /// Converts radians to degrees.
 ///
 /// # Parameters:
@@ -68,6 +78,81 @@ pub fn nstd_core_math_rad_f64(deg: NSTDFloat64) -> NSTDFloat64 {
     deg.to_radians()
 }
 
+/// Linearly interpolates between `a` and `b`.
+///
+/// This is exact at the endpoints (`t == 0` returns `a` & `t == 1` returns `b`).
+///
+/// # Parameters:
+///
+/// - `NSTDFloat32 a` - The start value.
+///
+/// - `NSTDFloat32 b` - The end value.
+///
+/// - `NSTDFloat32 t` - The interpolation factor, where 0 maps to `a` and 1 maps to `b`.
+///
+/// # Returns
+///
+/// `NSTDFloat32 v` - The interpolated value.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::math::nstd_core_math_lerp_f32;
+///
+/// # unsafe {
+/// assert!(nstd_core_math_lerp_f32(2.0, 10.0, 0.0) == 2.0);
+/// assert!(nstd_core_math_lerp_f32(2.0, 10.0, 0.25) == 4.0);
+/// assert!(nstd_core_math_lerp_f32(2.0, 10.0, 1.0) == 10.0);
+/// assert!(nstd_core_math_lerp_f32(0.1, -3.0, 1.0) == -3.0);
+/// # }
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::suboptimal_flops)]
+pub fn nstd_core_math_lerp_f32(a: NSTDFloat32, b: NSTDFloat32, t: NSTDFloat32) -> NSTDFloat32 {
+    match t < 0.5 {
+        true => a + (b - a) * t,
+        false => b - (b - a) * (1.0 - t),
+    }
+}
+/// Linearly interpolates between `a` and `b`.
+///
+/// This is exact at the endpoints (`t == 0` returns `a` & `t == 1` returns `b`).
+///
+/// # Parameters:
+///
+/// - `NSTDFloat64 a` - The start value.
+///
+/// - `NSTDFloat64 b` - The end value.
+///
+/// - `NSTDFloat64 t` - The interpolation factor, where 0 maps to `a` and 1 maps to `b`.
+///
+/// # Returns
+///
+/// `NSTDFloat64 v` - The interpolated value.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::math::nstd_core_math_lerp_f64;
+///
+/// # unsafe {
+/// assert!(nstd_core_math_lerp_f64(2.0, 10.0, 0.0) == 2.0);
+/// assert!(nstd_core_math_lerp_f64(2.0, 10.0, 0.25) == 4.0);
+/// assert!(nstd_core_math_lerp_f64(2.0, 10.0, 1.0) == 10.0);
+/// assert!(nstd_core_math_lerp_f64(0.1, -3.0, 1.0) == -3.0);
+/// # }
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::suboptimal_flops)]
+pub fn nstd_core_math_lerp_f64(a: NSTDFloat64, b: NSTDFloat64, t: NSTDFloat64) -> NSTDFloat64 {
+    match t < 0.5 {
+        true => a + (b - a) * t,
+        false => b - (b - a) * (1.0 - t),
+    }
+}
+
 /// Generates the `abs` functions.
 macro_rules! gen_abs {
     ($name: ident, $T: ty) => {
@@ -145,6 +230,66 @@ gen_pow!(nstd_core_math_pow_u32, NSTDUInt32);
 gen_pow!(nstd_core_math_pow_i64, NSTDInt64);
 gen_pow!(nstd_core_math_pow_u64, NSTDUInt64);
 
+/// Generates the `checked_pow` functions.
+macro_rules! gen_checked_pow {
+    ($name: ident, $T: ty, $Opt: ty) => {
+        /// Raises the value `x` to the power of `exp`, checking for overflow.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!("- `", stringify!($T), " x` - The value.")]
+        ///
+        /// - `NSTDUInt32 exp` - The exponent.
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!("`", stringify!($Opt), " pow` - `x` raised to the power of `exp` on success, or an uninitialized \"none\" variant on overflow.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::{math::", stringify!($name), ", optional::NSTDOptional};")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(2, 3) == NSTDOptional::Some(8));")]
+        #[doc = concat!("assert!(", stringify!($name), "(2, 5) == NSTDOptional::Some(32));")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX, 2) == NSTDOptional::None);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, exp: NSTDUInt32) -> $Opt {
+            match x.checked_pow(exp) {
+                Some(v) => NSTDOptional::Some(v),
+                _ => NSTDOptional::None,
+            }
+        }
+    };
+}
+gen_checked_pow!(nstd_core_math_checked_pow_int, NSTDInt, NSTDOptionalInt);
+gen_checked_pow!(nstd_core_math_checked_pow_uint, NSTDUInt, NSTDOptionalUInt);
+gen_checked_pow!(nstd_core_math_checked_pow_i8, NSTDInt8, NSTDOptionalInt8);
+gen_checked_pow!(nstd_core_math_checked_pow_u8, NSTDUInt8, NSTDOptionalUInt8);
+gen_checked_pow!(nstd_core_math_checked_pow_i16, NSTDInt16, NSTDOptionalInt16);
+gen_checked_pow!(
+    nstd_core_math_checked_pow_u16,
+    NSTDUInt16,
+    NSTDOptionalUInt16
+);
+gen_checked_pow!(nstd_core_math_checked_pow_i32, NSTDInt32, NSTDOptionalInt32);
+gen_checked_pow!(
+    nstd_core_math_checked_pow_u32,
+    NSTDUInt32,
+    NSTDOptionalUInt32
+);
+gen_checked_pow!(nstd_core_math_checked_pow_i64, NSTDInt64, NSTDOptionalInt64);
+gen_checked_pow!(
+    nstd_core_math_checked_pow_u64,
+    NSTDUInt64,
+    NSTDOptionalUInt64
+);
+
 /// Generates the `clamp` functions.
 macro_rules! gen_clamp {
     (
@@ -173,6 +318,10 @@ macro_rules! gen_clamp {
     };
 }
 gen_clamp!(
+    ///
+    /// # Note
+    ///
+    /// If `x` is NaN, NaN is returned.
     ///
     /// # Panics
     ///
@@ -187,12 +336,17 @@ gen_clamp!(
     /// assert!(nstd_core_math_clamp_f32(2.5, 3.0, 5.0) == 3.0);
     /// assert!(nstd_core_math_clamp_f32(4.0, 3.0, 5.0) == 4.0);
     /// assert!(nstd_core_math_clamp_f32(7.5, 3.0, 5.0) == 5.0);
+    /// assert!(nstd_core_math_clamp_f32(f32::NAN, 3.0, 5.0).is_nan());
     /// # }
     /// ```
     nstd_core_math_clamp_f32,
     NSTDFloat32
 );
 gen_clamp!(
+    ///
+    /// # Note
+    ///
+    /// If `x` is NaN, NaN is returned.
     ///
     /// # Panics
     ///
@@ -207,6 +361,7 @@ gen_clamp!(
     /// assert!(nstd_core_math_clamp_f64(2.5, 3.0, 5.0) == 3.0);
     /// assert!(nstd_core_math_clamp_f64(4.0, 3.0, 5.0) == 4.0);
     /// assert!(nstd_core_math_clamp_f64(7.5, 3.0, 5.0) == 5.0);
+    /// assert!(nstd_core_math_clamp_f64(f64::NAN, 3.0, 5.0).is_nan());
     /// # }
     /// ```
     nstd_core_math_clamp_f64,
@@ -8,7 +8,7 @@ use crate::{
         NSTDOptionalInt8, NSTDOptionalUInt, NSTDOptionalUInt16, NSTDOptionalUInt32,
         NSTDOptionalUInt64, NSTDOptionalUInt8,
     },
-    NSTDInt, NSTDInt16, NSTDInt32, NSTDInt64, NSTDInt8, NSTDUInt, NSTDUInt16, NSTDUInt32,
+    NSTDBool, NSTDInt, NSTDInt16, NSTDInt32, NSTDInt64, NSTDInt8, NSTDUInt, NSTDUInt16, NSTDUInt32,
     NSTDUInt64, NSTDUInt8,
 };
 use nstdapi::nstdapi;
@@ -381,3 +381,675 @@ gen_shr!(nstd_core_ops_shr_i32, NSTDInt32, NSTDOptionalInt32);
 gen_shr!(nstd_core_ops_shr_u32, NSTDUInt32, NSTDOptionalUInt32);
 gen_shr!(nstd_core_ops_shr_i64, NSTDInt64, NSTDOptionalInt64);
 gen_shr!(nstd_core_ops_shr_u64, NSTDUInt64, NSTDOptionalUInt64);
+
+/// Generates the saturating addition operator implementations.
+macro_rules! gen_saturating_add {
+    ($name: ident, $T: ty) => {
+        /// Computes the saturating addition operation of `x` + `y`.
+        ///
+        /// The result is clamped to the type's minimum or maximum value on overflow.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(4, 5) == 9);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX, 1) == ", stringify!($T), "::MAX);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, y: $T) -> $T {
+            x.saturating_add(y)
+        }
+    };
+}
+gen_saturating_add!(nstd_core_ops_saturating_add_int, NSTDInt);
+gen_saturating_add!(nstd_core_ops_saturating_add_uint, NSTDUInt);
+gen_saturating_add!(nstd_core_ops_saturating_add_i8, NSTDInt8);
+gen_saturating_add!(nstd_core_ops_saturating_add_u8, NSTDUInt8);
+gen_saturating_add!(nstd_core_ops_saturating_add_i16, NSTDInt16);
+gen_saturating_add!(nstd_core_ops_saturating_add_u16, NSTDUInt16);
+gen_saturating_add!(nstd_core_ops_saturating_add_i32, NSTDInt32);
+gen_saturating_add!(nstd_core_ops_saturating_add_u32, NSTDUInt32);
+gen_saturating_add!(nstd_core_ops_saturating_add_i64, NSTDInt64);
+gen_saturating_add!(nstd_core_ops_saturating_add_u64, NSTDUInt64);
+
+/// Generates the saturating subtraction operator implementations.
+macro_rules! gen_saturating_sub {
+    ($name: ident, $T: ty) => {
+        /// Computes the saturating subtraction operation of `x` - `y`.
+        ///
+        /// The result is clamped to the type's minimum or maximum value on overflow.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(5, 4) == 1);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MIN, 1) == ", stringify!($T), "::MIN);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, y: $T) -> $T {
+            x.saturating_sub(y)
+        }
+    };
+}
+gen_saturating_sub!(nstd_core_ops_saturating_sub_int, NSTDInt);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_uint, NSTDUInt);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_i8, NSTDInt8);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_u8, NSTDUInt8);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_i16, NSTDInt16);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_u16, NSTDUInt16);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_i32, NSTDInt32);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_u32, NSTDUInt32);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_i64, NSTDInt64);
+gen_saturating_sub!(nstd_core_ops_saturating_sub_u64, NSTDUInt64);
+
+/// Generates the saturating multiplication operator implementations.
+macro_rules! gen_saturating_mul {
+    ($name: ident, $T: ty) => {
+        /// Computes the saturating multiplication operation of `x` * `y`.
+        ///
+        /// The result is clamped to the type's minimum or maximum value on overflow.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(4, 5) == 20);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX, 2) == ", stringify!($T), "::MAX);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, y: $T) -> $T {
+            x.saturating_mul(y)
+        }
+    };
+}
+gen_saturating_mul!(nstd_core_ops_saturating_mul_int, NSTDInt);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_uint, NSTDUInt);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_i8, NSTDInt8);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_u8, NSTDUInt8);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_i16, NSTDInt16);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_u16, NSTDUInt16);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_i32, NSTDInt32);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_u32, NSTDUInt32);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_i64, NSTDInt64);
+gen_saturating_mul!(nstd_core_ops_saturating_mul_u64, NSTDUInt64);
+
+/// Generates the wrapping addition operator implementations.
+macro_rules! gen_wrapping_add {
+    ($name: ident, $T: ty) => {
+        /// Computes the wrapping addition operation of `x` + `y`.
+        ///
+        /// The result wraps around at the boundary of the type on overflow.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(4, 5) == 9);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX, 1) == ", stringify!($T), "::MIN);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, y: $T) -> $T {
+            x.wrapping_add(y)
+        }
+    };
+}
+gen_wrapping_add!(nstd_core_ops_wrapping_add_int, NSTDInt);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_uint, NSTDUInt);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_i8, NSTDInt8);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_u8, NSTDUInt8);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_i16, NSTDInt16);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_u16, NSTDUInt16);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_i32, NSTDInt32);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_u32, NSTDUInt32);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_i64, NSTDInt64);
+gen_wrapping_add!(nstd_core_ops_wrapping_add_u64, NSTDUInt64);
+
+/// Generates the wrapping subtraction operator implementations.
+macro_rules! gen_wrapping_sub {
+    ($name: ident, $T: ty) => {
+        /// Computes the wrapping subtraction operation of `x` - `y`.
+        ///
+        /// The result wraps around at the boundary of the type on overflow.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(5, 4) == 1);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MIN, 1) == ", stringify!($T), "::MAX);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, y: $T) -> $T {
+            x.wrapping_sub(y)
+        }
+    };
+}
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_int, NSTDInt);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_uint, NSTDUInt);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_i8, NSTDInt8);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_u8, NSTDUInt8);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_i16, NSTDInt16);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_u16, NSTDUInt16);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_i32, NSTDInt32);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_u32, NSTDUInt32);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_i64, NSTDInt64);
+gen_wrapping_sub!(nstd_core_ops_wrapping_sub_u64, NSTDUInt64);
+
+/// Generates the wrapping multiplication operator implementations.
+macro_rules! gen_wrapping_mul {
+    ($name: ident, $T: ty) => {
+        /// Computes the wrapping multiplication operation of `x` * `y`.
+        ///
+        /// The result wraps around at the boundary of the type on overflow.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(4, 5) == 20);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX, ", stringify!($T), "::MAX) == 1);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, y: $T) -> $T {
+            x.wrapping_mul(y)
+        }
+    };
+}
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_int, NSTDInt);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_uint, NSTDUInt);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_i8, NSTDInt8);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_u8, NSTDUInt8);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_i16, NSTDInt16);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_u16, NSTDUInt16);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_i32, NSTDInt32);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_u32, NSTDUInt32);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_i64, NSTDInt64);
+gen_wrapping_mul!(nstd_core_ops_wrapping_mul_u64, NSTDUInt64);
+
+/// Generates the overflowing addition operator implementations.
+macro_rules! gen_overflowing_add {
+    ($name: ident, $T: ty) => {
+        /// Computes the wrapping addition operation of `x` + `y`, reporting whether or not an
+        /// overflow occurred.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+        /// `NSTD_FALSE`.
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The wrapped result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::{core::ops::", stringify!($name), ", NSTD_FALSE};")]
+        ///
+        /// # unsafe {
+        /// let mut overflowed = NSTD_FALSE;
+        #[doc = concat!("assert!(", stringify!($name), "(4, 5, &mut overflowed) == 9 && overflowed == NSTD_FALSE);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX, 1, &mut overflowed) == ", stringify!($T), "::MIN && overflowed != NSTD_FALSE);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        #[allow(clippy::missing_const_for_fn)]
+        pub fn $name(x: $T, y: $T, overflowed: &mut NSTDBool) -> $T {
+            let (z, o) = x.overflowing_add(y);
+            *overflowed = o;
+            z
+        }
+    };
+}
+gen_overflowing_add!(nstd_core_ops_overflowing_add_int, NSTDInt);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_uint, NSTDUInt);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_i8, NSTDInt8);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_u8, NSTDUInt8);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_i16, NSTDInt16);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_u16, NSTDUInt16);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_i32, NSTDInt32);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_u32, NSTDUInt32);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_i64, NSTDInt64);
+gen_overflowing_add!(nstd_core_ops_overflowing_add_u64, NSTDUInt64);
+
+/// Generates the overflowing subtraction operator implementations.
+macro_rules! gen_overflowing_sub {
+    ($name: ident, $T: ty) => {
+        /// Computes the wrapping subtraction operation of `x` - `y`, reporting whether or not an
+        /// overflow occurred.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+        /// `NSTD_FALSE`.
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The wrapped result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::{core::ops::", stringify!($name), ", NSTD_FALSE};")]
+        ///
+        /// # unsafe {
+        /// let mut overflowed = NSTD_FALSE;
+        #[doc = concat!("assert!(", stringify!($name), "(5, 4, &mut overflowed) == 1 && overflowed == NSTD_FALSE);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MIN, 1, &mut overflowed) == ", stringify!($T), "::MAX && overflowed != NSTD_FALSE);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        #[allow(clippy::missing_const_for_fn)]
+        pub fn $name(x: $T, y: $T, overflowed: &mut NSTDBool) -> $T {
+            let (z, o) = x.overflowing_sub(y);
+            *overflowed = o;
+            z
+        }
+    };
+}
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_int, NSTDInt);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_uint, NSTDUInt);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_i8, NSTDInt8);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_u8, NSTDUInt8);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_i16, NSTDInt16);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_u16, NSTDUInt16);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_i32, NSTDInt32);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_u32, NSTDUInt32);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_i64, NSTDInt64);
+gen_overflowing_sub!(nstd_core_ops_overflowing_sub_u64, NSTDUInt64);
+
+/// Generates the overflowing multiplication operator implementations.
+macro_rules! gen_overflowing_mul {
+    ($name: ident, $T: ty) => {
+        /// Computes the wrapping multiplication operation of `x` * `y`, reporting whether or not an
+        /// overflow occurred.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The left operand.")]
+        ///
+        #[doc = concat!(" - `", stringify!($T), " y` - The right operand.")]
+        ///
+        /// - `NSTDBool *overflowed` - Set to `NSTD_TRUE` if the operation overflowed, otherwise
+        /// `NSTD_FALSE`.
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The wrapped result of the operation.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::{core::ops::", stringify!($name), ", NSTD_FALSE};")]
+        ///
+        /// # unsafe {
+        /// let mut overflowed = NSTD_FALSE;
+        #[doc = concat!("assert!(", stringify!($name), "(4, 5, &mut overflowed) == 20 && overflowed == NSTD_FALSE);")]
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX, ", stringify!($T), "::MAX, &mut overflowed) == 1 && overflowed != NSTD_FALSE);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        #[allow(clippy::missing_const_for_fn)]
+        pub fn $name(x: $T, y: $T, overflowed: &mut NSTDBool) -> $T {
+            let (z, o) = x.overflowing_mul(y);
+            *overflowed = o;
+            z
+        }
+    };
+}
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_int, NSTDInt);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_uint, NSTDUInt);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_i8, NSTDInt8);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_u8, NSTDUInt8);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_i16, NSTDInt16);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_u16, NSTDUInt16);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_i32, NSTDInt32);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_u32, NSTDUInt32);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_i64, NSTDInt64);
+gen_overflowing_mul!(nstd_core_ops_overflowing_mul_u64, NSTDUInt64);
+
+/// Generates the `count_ones` bit counting implementations.
+macro_rules! gen_count_ones {
+    ($name: ident, $T: ty) => {
+        /// Returns the number of ones in the binary representation of `x`.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The value to inspect.")]
+        ///
+        /// # Returns
+        ///
+        /// `NSTDUInt32 count_ones` - The number of ones in `x`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(0b1011) == 3);")]
+        #[doc = concat!("assert!(", stringify!($name), "(0) == 0);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T) -> NSTDUInt32 {
+            x.count_ones()
+        }
+    };
+}
+gen_count_ones!(nstd_core_ops_count_ones_uint, NSTDUInt);
+gen_count_ones!(nstd_core_ops_count_ones_u8, NSTDUInt8);
+gen_count_ones!(nstd_core_ops_count_ones_u16, NSTDUInt16);
+gen_count_ones!(nstd_core_ops_count_ones_u32, NSTDUInt32);
+gen_count_ones!(nstd_core_ops_count_ones_u64, NSTDUInt64);
+
+/// Generates the `count_zeros` bit counting implementations.
+macro_rules! gen_count_zeros {
+    ($name: ident, $T: ty) => {
+        /// Returns the number of zeros in the binary representation of `x`.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The value to inspect.")]
+        ///
+        /// # Returns
+        ///
+        /// `NSTDUInt32 count_zeros` - The number of zeros in `x`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX) == 0);")]
+        #[doc = concat!("assert!(", stringify!($name), "(0) == ", stringify!($T), "::BITS);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T) -> NSTDUInt32 {
+            x.count_zeros()
+        }
+    };
+}
+gen_count_zeros!(nstd_core_ops_count_zeros_uint, NSTDUInt);
+gen_count_zeros!(nstd_core_ops_count_zeros_u8, NSTDUInt8);
+gen_count_zeros!(nstd_core_ops_count_zeros_u16, NSTDUInt16);
+gen_count_zeros!(nstd_core_ops_count_zeros_u32, NSTDUInt32);
+gen_count_zeros!(nstd_core_ops_count_zeros_u64, NSTDUInt64);
+
+/// Generates the `leading_zeros` bit counting implementations.
+macro_rules! gen_leading_zeros {
+    ($name: ident, $T: ty) => {
+        /// Returns the number of leading zeros in the binary representation of `x`.
+        ///
+        /// If `x` is 0, this returns the type's bit width.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The value to inspect.")]
+        ///
+        /// # Returns
+        ///
+        /// `NSTDUInt32 leading_zeros` - The number of leading zeros in `x`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(", stringify!($T), "::MAX) == 0);")]
+        #[doc = concat!("assert!(", stringify!($name), "(1) == ", stringify!($T), "::BITS - 1);")]
+        #[doc = concat!("assert!(", stringify!($name), "(0) == ", stringify!($T), "::BITS);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T) -> NSTDUInt32 {
+            x.leading_zeros()
+        }
+    };
+}
+gen_leading_zeros!(nstd_core_ops_leading_zeros_uint, NSTDUInt);
+gen_leading_zeros!(nstd_core_ops_leading_zeros_u8, NSTDUInt8);
+gen_leading_zeros!(nstd_core_ops_leading_zeros_u16, NSTDUInt16);
+gen_leading_zeros!(nstd_core_ops_leading_zeros_u32, NSTDUInt32);
+gen_leading_zeros!(nstd_core_ops_leading_zeros_u64, NSTDUInt64);
+
+/// Generates the `trailing_zeros` bit counting implementations.
+macro_rules! gen_trailing_zeros {
+    ($name: ident, $T: ty) => {
+        /// Returns the number of trailing zeros in the binary representation of `x`.
+        ///
+        /// If `x` is 0, this returns the type's bit width.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The value to inspect.")]
+        ///
+        /// # Returns
+        ///
+        /// `NSTDUInt32 trailing_zeros` - The number of trailing zeros in `x`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(0b1000) == 3);")]
+        #[doc = concat!("assert!(", stringify!($name), "(1) == 0);")]
+        #[doc = concat!("assert!(", stringify!($name), "(0) == ", stringify!($T), "::BITS);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T) -> NSTDUInt32 {
+            x.trailing_zeros()
+        }
+    };
+}
+gen_trailing_zeros!(nstd_core_ops_trailing_zeros_uint, NSTDUInt);
+gen_trailing_zeros!(nstd_core_ops_trailing_zeros_u8, NSTDUInt8);
+gen_trailing_zeros!(nstd_core_ops_trailing_zeros_u16, NSTDUInt16);
+gen_trailing_zeros!(nstd_core_ops_trailing_zeros_u32, NSTDUInt32);
+gen_trailing_zeros!(nstd_core_ops_trailing_zeros_u64, NSTDUInt64);
+
+/// Generates the rotate left implementations.
+macro_rules! gen_rotate_left {
+    ($name: ident, $T: ty) => {
+        /// Rotates the bits of `x` `n` bits to the left, wrapping the truncated bits around to the
+        /// end of the value.
+        ///
+        /// `n` is taken modulo the type's bit width.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The value to rotate.")]
+        ///
+        /// - `NSTDUInt32 n` - The number of bits to rotate by.
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The rotated value.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(1, 4) == 16);")]
+        #[doc = concat!("assert!(", stringify!($name), "(1 << (", stringify!($T), "::BITS - 1), 1) == 1);")]
+        #[doc = concat!("assert!(", stringify!($name), "(1, ", stringify!($T), "::BITS + 1) == 2);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, n: NSTDUInt32) -> $T {
+            x.rotate_left(n)
+        }
+    };
+}
+gen_rotate_left!(nstd_core_ops_rotate_left_uint, NSTDUInt);
+gen_rotate_left!(nstd_core_ops_rotate_left_u8, NSTDUInt8);
+gen_rotate_left!(nstd_core_ops_rotate_left_u16, NSTDUInt16);
+gen_rotate_left!(nstd_core_ops_rotate_left_u32, NSTDUInt32);
+gen_rotate_left!(nstd_core_ops_rotate_left_u64, NSTDUInt64);
+
+/// Generates the rotate right implementations.
+macro_rules! gen_rotate_right {
+    ($name: ident, $T: ty) => {
+        /// Rotates the bits of `x` `n` bits to the right, wrapping the truncated bits around to the
+        /// beginning of the value.
+        ///
+        /// `n` is taken modulo the type's bit width.
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!(" - `", stringify!($T), " x` - The value to rotate.")]
+        ///
+        /// - `NSTDUInt32 n` - The number of bits to rotate by.
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(" `", stringify!($T), " z` - The rotated value.")]
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("use nstd_sys::", stringify!($T), ";")]
+        #[doc = concat!("use nstd_sys::core::ops::", stringify!($name), ";")]
+        ///
+        /// # unsafe {
+        #[doc = concat!("assert!(", stringify!($name), "(16, 4) == 1);")]
+        #[doc = concat!("assert!(", stringify!($name), "(1, 1) == 1 << (", stringify!($T), "::BITS - 1));")]
+        #[doc = concat!("assert!(", stringify!($name), "(2, ", stringify!($T), "::BITS + 1) == 1);")]
+        /// # }
+        /// ```
+        #[inline]
+        #[nstdapi]
+        pub const fn $name(x: $T, n: NSTDUInt32) -> $T {
+            x.rotate_right(n)
+        }
+    };
+}
+gen_rotate_right!(nstd_core_ops_rotate_right_uint, NSTDUInt);
+gen_rotate_right!(nstd_core_ops_rotate_right_u8, NSTDUInt8);
+gen_rotate_right!(nstd_core_ops_rotate_right_u16, NSTDUInt16);
+gen_rotate_right!(nstd_core_ops_rotate_right_u32, NSTDUInt32);
+gen_rotate_right!(nstd_core_ops_rotate_right_u64, NSTDUInt64);
@@ -103,3 +103,112 @@ pub type NSTDOptionalRefMut<'a, T> = NSTDOptional<NSTDRefMut<'a, T>>;
 pub type NSTDOptionalAnyRef<'a> = NSTDOptional<NSTDAnyRef<'a>>;
 /// Represents an optional value of type `NSTDAnyRefMut`.
 pub type NSTDOptionalAnyRefMut<'a> = NSTDOptional<NSTDAnyRefMut<'a>>;
+
+/// Generates the `nstd_core_optional_map_*` functions.
+macro_rules! gen_optional_map {
+    (
+        $(#[$meta:meta])*
+        $name: ident, $T: ty, $OptT: ty
+    ) => {
+        #[doc = concat!("Applies `f` to the value held by an `", stringify!($OptT), "`.")]
+        ///
+        /// # Note
+        ///
+        #[doc = concat!(
+            "`f` must return another `", stringify!($T), "`, mapping to a different type is not ",
+            "supported."
+        )]
+        ///
+        /// # Parameters:
+        ///
+        #[doc = concat!("- `", stringify!($OptT), " opt` - The optional value.")]
+        ///
+        #[doc = concat!(
+            "- `", stringify!($T), " (*f)(", stringify!($T), ")` - The function to apply to the ",
+            "contained value."
+        )]
+        ///
+        /// # Returns
+        ///
+        #[doc = concat!(
+            "`", stringify!($OptT), " mapped` - The value returned from `f` if `opt` holds a ",
+            "value, otherwise an uninitialized \"none\" variant."
+        )]
+        ///
+        /// # Safety
+        ///
+        /// This operation makes a direct call on a C function pointer (`f`).
+        ///
+        $(#[$meta])*
+        #[inline]
+        #[nstdapi]
+        pub unsafe fn $name(opt: $OptT, f: unsafe extern "C" fn($T) -> $T) -> $OptT {
+            match opt {
+                NSTDOptional::Some(value) => NSTDOptional::Some(f(value)),
+                NSTDOptional::None => NSTDOptional::None,
+            }
+        }
+    };
+}
+gen_optional_map!(
+    /// # Example
+    ///
+    /// ```
+    /// use nstd_sys::{
+    ///     core::{
+    ///         optional::{nstd_core_optional_map_int, NSTDOptional},
+    ///         str::{nstd_core_str_from_raw_cstr, nstd_core_str_to_int},
+    ///     },
+    ///     NSTDInt,
+    /// };
+    ///
+    /// unsafe extern "C" fn double(v: NSTDInt) -> NSTDInt {
+    ///     v * 2
+    /// }
+    ///
+    /// unsafe {
+    ///     let str = nstd_core_str_from_raw_cstr("21\0".as_ptr().cast()).unwrap();
+    ///     let v = nstd_core_optional_map_int(nstd_core_str_to_int(&str), double);
+    ///     assert!(v == NSTDOptional::Some(42));
+    ///
+    ///     let str = nstd_core_str_from_raw_cstr("twenty-one\0".as_ptr().cast()).unwrap();
+    ///     let v = nstd_core_optional_map_int(nstd_core_str_to_int(&str), double);
+    ///     assert!(v == NSTDOptional::None);
+    /// }
+    /// ```
+    nstd_core_optional_map_int,
+    NSTDInt,
+    NSTDOptionalInt
+);
+gen_optional_map!(nstd_core_optional_map_uint, NSTDUInt, NSTDOptionalUInt);
+gen_optional_map!(nstd_core_optional_map_i8, NSTDInt8, NSTDOptionalInt8);
+gen_optional_map!(nstd_core_optional_map_u8, NSTDUInt8, NSTDOptionalUInt8);
+gen_optional_map!(nstd_core_optional_map_i16, NSTDInt16, NSTDOptionalInt16);
+gen_optional_map!(nstd_core_optional_map_u16, NSTDUInt16, NSTDOptionalUInt16);
+gen_optional_map!(nstd_core_optional_map_i32, NSTDInt32, NSTDOptionalInt32);
+gen_optional_map!(nstd_core_optional_map_u32, NSTDUInt32, NSTDOptionalUInt32);
+gen_optional_map!(nstd_core_optional_map_i64, NSTDInt64, NSTDOptionalInt64);
+gen_optional_map!(nstd_core_optional_map_u64, NSTDUInt64, NSTDOptionalUInt64);
+gen_optional_map!(nstd_core_optional_map_f32, NSTDFloat32, NSTDOptionalFloat32);
+gen_optional_map!(
+    /// # Example
+    ///
+    /// ```
+    /// use nstd_sys::{
+    ///     core::optional::{nstd_core_optional_map_f64, NSTDOptional},
+    ///     NSTDFloat64,
+    /// };
+    ///
+    /// unsafe extern "C" fn negate(v: NSTDFloat64) -> NSTDFloat64 {
+    ///     -v
+    /// }
+    ///
+    /// unsafe {
+    ///     let v = nstd_core_optional_map_f64(NSTDOptional::Some(1.5), negate);
+    ///     assert!(v == NSTDOptional::Some(-1.5));
+    /// }
+    /// ```
+    nstd_core_optional_map_f64,
+    NSTDFloat64,
+    NSTDOptionalFloat64
+);
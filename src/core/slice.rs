@@ -1,10 +1,12 @@
 //! A view into a sequence of values in memory.
 use crate::{
     core::{
+        def::NSTDErrorCode,
         mem::{nstd_core_mem_copy, nstd_core_mem_is_aligned},
         optional::{gen_optional, NSTDOptional},
+        range::NSTDURange,
     },
-    NSTDAny, NSTDAnyMut, NSTDUInt, NSTD_INT_MAX, NSTD_NULL,
+    NSTDAny, NSTDAnyMut, NSTDBool, NSTDUInt, NSTD_INT_MAX, NSTD_NULL,
 };
 use nstdapi::nstdapi;
 
@@ -58,6 +60,16 @@ impl NSTDSlice {
             false => None,
         }
     }
+
+    /// Creates a Rust byte slice containing all of the bytes in this `NSTDSlice`.
+    ///
+    /// # Safety
+    ///
+    /// The `NSTDSlice`'s data must remain valid and unmodified while the returned slice is in use.
+    #[inline]
+    pub(crate) const unsafe fn as_bytes(&self) -> &[u8] {
+        core::slice::from_raw_parts(self.ptr.cast(), self.byte_len())
+    }
 }
 gen_optional!(NSTDOptionalSlice, NSTDSlice);
 
@@ -259,6 +271,42 @@ pub const fn nstd_core_slice_stride(slice: &NSTDSlice) -> NSTDUInt {
     slice.stride
 }
 
+/// Checks that each value in a slice occupies exactly `expected` bytes.
+///
+/// This is the recommended guard for validating a slice's element layout at FFI boundaries,
+/// before the slice's elements are read as a specific type.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice.
+///
+/// - `NSTDUInt expected` - The expected size of each value in the slice.
+///
+/// # Returns
+///
+/// `NSTDBool is_valid` - True if `slice`'s stride is equal to `expected`.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::slice::{nstd_core_slice_check_stride, nstd_core_slice_new};
+///
+/// const STRIDE: usize = core::mem::size_of::<u32>();
+/// const ALIGN: usize = core::mem::align_of::<u32>();
+///
+/// let arr = [1u32, 2, 3];
+/// unsafe {
+///     let slice = nstd_core_slice_new(arr.as_ptr().cast(), STRIDE, ALIGN, 3).unwrap();
+///     assert!(nstd_core_slice_check_stride(&slice, 4));
+///     assert!(!nstd_core_slice_check_stride(&slice, 8));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_core_slice_check_stride(slice: &NSTDSlice, expected: NSTDUInt) -> NSTDBool {
+    slice.stride == expected
+}
+
 /// Returns the alignment of each value in a slice.
 ///
 /// # Parameters:
@@ -408,6 +456,224 @@ pub const fn nstd_core_slice_last(slice: &NSTDSlice) -> NSTDAny {
     }
 }
 
+/// Returns a view into the elements of `slice` within `range`.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to create the subslice from.
+///
+/// - `NSTDURange range` - The range of elements to include in the subslice.
+///
+/// # Returns
+///
+/// `NSTDOptionalSlice subslice` - The new subslice on success, or an uninitialized "none" variant
+/// if `range.start` is greater than `range.end` or `range.end` is greater than `slice.len`.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{
+///     optional::NSTDOptional,
+///     range::NSTDURange,
+///     slice::{nstd_core_slice_get, nstd_core_slice_len, nstd_core_slice_new, nstd_core_slice_subslice},
+/// };
+///
+/// const STRIDE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// unsafe {
+///     let numbers: [u16; 5] = [1, 2, 3, 4, 5];
+///     let slice = nstd_core_slice_new(numbers.as_ptr().cast(), STRIDE, ALIGN, 5).unwrap();
+///
+///     let sub = nstd_core_slice_subslice(&slice, NSTDURange { start: 1, end: 4 }).unwrap();
+///     assert!(nstd_core_slice_len(&sub) == 3);
+///     assert!(*nstd_core_slice_get(&sub, 0).cast::<u16>() == 2);
+///     assert!(*nstd_core_slice_get(&sub, 2).cast::<u16>() == 4);
+///
+///     let range = NSTDURange { start: 3, end: 6 };
+///     assert!(matches!(nstd_core_slice_subslice(&slice, range), NSTDOptional::None));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::suspicious_operation_groupings)]
+pub const fn nstd_core_slice_subslice(slice: &NSTDSlice, range: NSTDURange) -> NSTDOptionalSlice {
+    match range.start <= range.end && range.end <= slice.len {
+        #[allow(clippy::arithmetic_side_effects)]
+        true => NSTDOptional::Some(NSTDSlice {
+            // SAFETY: `range.start` is within the slice's bounds.
+            ptr: unsafe { slice.ptr.add(range.start * slice.stride) },
+            len: range.end - range.start,
+            stride: slice.stride,
+            align: slice.align,
+        }),
+        false => NSTDOptional::None,
+    }
+}
+
+/// Determines whether or not `slice` begins with the elements of `prefix`.
+///
+/// Elements are compared byte by byte, and slices with differing strides never match.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to check.
+///
+/// - `const NSTDSlice *prefix` - The prefix to search for.
+///
+/// # Returns
+///
+/// `NSTDBool starts_with` - `NSTD_TRUE` if `slice` starts with `prefix`, an empty prefix always
+/// matches.
+///
+/// # Safety
+///
+/// Both `slice` and `prefix`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::slice::{nstd_core_slice_new, nstd_core_slice_starts_with},
+///     NSTD_FALSE,
+/// };
+///
+/// const STRIDE: usize = core::mem::size_of::<u32>();
+/// const ALIGN: usize = core::mem::align_of::<u32>();
+///
+/// let numbers: [u32; 4] = [1, 2, 3, 4];
+/// let start: [u32; 2] = [1, 2];
+/// let end: [u32; 2] = [3, 4];
+/// unsafe {
+///     let slice = nstd_core_slice_new(numbers.as_ptr().cast(), STRIDE, ALIGN, 4).unwrap();
+///     let start = nstd_core_slice_new(start.as_ptr().cast(), STRIDE, ALIGN, 2).unwrap();
+///     let end = nstd_core_slice_new(end.as_ptr().cast(), STRIDE, ALIGN, 2).unwrap();
+///     assert!(nstd_core_slice_starts_with(&slice, &start) != NSTD_FALSE);
+///     assert!(nstd_core_slice_starts_with(&slice, &end) == NSTD_FALSE);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_core_slice_starts_with(slice: &NSTDSlice, prefix: &NSTDSlice) -> NSTDBool {
+    slice.stride == prefix.stride && slice.as_bytes().starts_with(prefix.as_bytes())
+}
+
+/// Determines whether or not `slice` ends with the elements of `suffix`.
+///
+/// Elements are compared byte by byte, and slices with differing strides never match.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to check.
+///
+/// - `const NSTDSlice *suffix` - The suffix to search for.
+///
+/// # Returns
+///
+/// `NSTDBool ends_with` - `NSTD_TRUE` if `slice` ends with `suffix`, an empty suffix always
+/// matches.
+///
+/// # Safety
+///
+/// Both `slice` and `suffix`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::slice::{nstd_core_slice_ends_with, nstd_core_slice_new},
+///     NSTD_FALSE,
+/// };
+///
+/// const STRIDE: usize = core::mem::size_of::<u32>();
+/// const ALIGN: usize = core::mem::align_of::<u32>();
+///
+/// let numbers: [u32; 4] = [1, 2, 3, 4];
+/// let start: [u32; 2] = [1, 2];
+/// let end: [u32; 2] = [3, 4];
+/// unsafe {
+///     let slice = nstd_core_slice_new(numbers.as_ptr().cast(), STRIDE, ALIGN, 4).unwrap();
+///     let start = nstd_core_slice_new(start.as_ptr().cast(), STRIDE, ALIGN, 2).unwrap();
+///     let end = nstd_core_slice_new(end.as_ptr().cast(), STRIDE, ALIGN, 2).unwrap();
+///     assert!(nstd_core_slice_ends_with(&slice, &end) != NSTD_FALSE);
+///     assert!(nstd_core_slice_ends_with(&slice, &start) == NSTD_FALSE);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_core_slice_ends_with(slice: &NSTDSlice, suffix: &NSTDSlice) -> NSTDBool {
+    slice.stride == suffix.stride && slice.as_bytes().ends_with(suffix.as_bytes())
+}
+
+/// Invokes `callback` with each contiguous, overlapping window of `size` elements in `slice`.
+///
+/// `callback` is never invoked if `size` is greater than `slice`'s length.
+///
+/// # Parameters:
+///
+/// - `const NSTDSlice *slice` - The slice to iterate over.
+///
+/// - `NSTDUInt size` - The number of elements in each window.
+///
+/// - `void (*callback)(const NSTDSlice *, NSTDAnyMut)` - The function to call with each window.
+///
+/// - `NSTDAnyMut data` - User data to pass to `callback`.
+///
+/// # Panics
+///
+/// This operation will panic if `size` is 0.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`callback`).
+///
+/// `slice`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::slice::{nstd_core_slice_get, nstd_core_slice_new, nstd_core_slice_windows, NSTDSlice},
+///     NSTDAnyMut,
+/// };
+///
+/// const STRIDE: usize = core::mem::size_of::<u32>();
+/// const ALIGN: usize = core::mem::align_of::<u32>();
+///
+/// unsafe extern "C" fn sum(window: &NSTDSlice, data: NSTDAnyMut) {
+///     let first = *nstd_core_slice_get(window, 0).cast::<u32>();
+///     let second = *nstd_core_slice_get(window, 1).cast::<u32>();
+///     *data.cast::<u32>() += first * second;
+/// }
+///
+/// let numbers: [u32; 4] = [1, 2, 3, 4];
+/// let mut total = 0u32;
+/// unsafe {
+///     let slice = nstd_core_slice_new(numbers.as_ptr().cast(), STRIDE, ALIGN, 4).unwrap();
+///     nstd_core_slice_windows(&slice, 2, sum, (&mut total as *mut u32).cast());
+/// }
+/// assert!(total == 1 * 2 + 2 * 3 + 3 * 4);
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_core_slice_windows(
+    slice: &NSTDSlice,
+    size: NSTDUInt,
+    callback: unsafe extern "C" fn(&NSTDSlice, NSTDAnyMut),
+    data: NSTDAnyMut,
+) {
+    assert!(size != 0);
+    if let Some(count) = slice.len.checked_sub(size) {
+        let mut window = NSTDSlice {
+            ptr: slice.ptr,
+            len: size,
+            stride: slice.stride,
+            align: slice.align,
+        };
+        for _ in 0..=count {
+            callback(&window, data);
+            window.ptr = window.ptr.add(slice.stride);
+        }
+    }
+}
+
 /// A view into a sequence of values in memory.
 #[nstdapi]
 pub struct NSTDSliceMut {
@@ -439,6 +705,17 @@ impl NSTDSliceMut {
             false => None,
         }
     }
+
+    /// Creates a mutable Rust byte slice containing all of the bytes in this `NSTDSliceMut`.
+    ///
+    /// # Safety
+    ///
+    /// The `NSTDSliceMut`'s data must remain valid while the returned slice is in use.
+    #[inline]
+    #[allow(clippy::arithmetic_side_effects, clippy::missing_const_for_fn)]
+    unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        core::slice::from_raw_parts_mut(self.ptr.cast(), self.len * self.stride)
+    }
 }
 gen_optional!(NSTDOptionalSliceMut, NSTDSliceMut);
 
@@ -693,6 +970,45 @@ pub const fn nstd_core_slice_mut_stride(slice: &NSTDSliceMut) -> NSTDUInt {
     slice.stride
 }
 
+/// Checks that each value in a slice occupies exactly `expected` bytes.
+///
+/// This is the recommended guard for validating a slice's element layout at FFI boundaries,
+/// before the slice's elements are read as a specific type.
+///
+/// # Parameters:
+///
+/// - `const NSTDSliceMut *slice` - The slice.
+///
+/// - `NSTDUInt expected` - The expected size of each value in the slice.
+///
+/// # Returns
+///
+/// `NSTDBool is_valid` - True if `slice`'s stride is equal to `expected`.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::slice::{nstd_core_slice_mut_check_stride, nstd_core_slice_mut_new};
+///
+/// const STRIDE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// let mut arr = [1u16, 2, 3];
+/// unsafe {
+///     let slice = nstd_core_slice_mut_new(arr.as_mut_ptr().cast(), STRIDE, ALIGN, 3).unwrap();
+///     assert!(nstd_core_slice_mut_check_stride(&slice, 2));
+///     assert!(!nstd_core_slice_mut_check_stride(&slice, 1));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_core_slice_mut_check_stride(
+    slice: &NSTDSliceMut,
+    expected: NSTDUInt,
+) -> NSTDBool {
+    slice.stride == expected
+}
+
 /// Returns the alignment of each value in a slice.
 ///
 /// # Parameters:
@@ -946,6 +1262,61 @@ pub const fn nstd_core_slice_mut_last_const(slice: &NSTDSliceMut) -> NSTDAny {
     }
 }
 
+/// Returns a mutable view into the elements of `slice` within `range`.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *slice` - The slice to create the subslice from.
+///
+/// - `NSTDURange range` - The range of elements to include in the subslice.
+///
+/// # Returns
+///
+/// `NSTDOptionalSliceMut subslice` - The new subslice on success, or an uninitialized "none"
+/// variant if `range.start` is greater than `range.end` or `range.end` is greater than
+/// `slice.len`.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{
+///     range::NSTDURange,
+///     slice::{nstd_core_slice_mut_get, nstd_core_slice_mut_new, nstd_core_slice_mut_subslice},
+/// };
+///
+/// const STRIDE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// let mut numbers: [u16; 5] = [1, 2, 3, 4, 5];
+/// unsafe {
+///     let ptr = numbers.as_mut_ptr().cast();
+///     let mut slice = nstd_core_slice_mut_new(ptr, STRIDE, ALIGN, 5).unwrap();
+///     let range = NSTDURange { start: 3, end: 5 };
+///     let mut sub = nstd_core_slice_mut_subslice(&mut slice, range).unwrap();
+///     *nstd_core_slice_mut_get(&mut sub, 1).cast::<u16>() = 10;
+/// }
+/// assert!(numbers == [1, 2, 3, 4, 10]);
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn, clippy::suspicious_operation_groupings)]
+pub fn nstd_core_slice_mut_subslice(
+    slice: &mut NSTDSliceMut,
+    range: NSTDURange,
+) -> NSTDOptionalSliceMut {
+    match range.start <= range.end && range.end <= slice.len {
+        #[allow(clippy::arithmetic_side_effects)]
+        true => NSTDOptional::Some(NSTDSliceMut {
+            // SAFETY: `range.start` is within the slice's bounds.
+            ptr: unsafe { slice.ptr.add(range.start * slice.stride) },
+            len: range.end - range.start,
+            stride: slice.stride,
+            align: slice.align,
+        }),
+        false => NSTDOptional::None,
+    }
+}
+
 /// Copies data into `dest` from `src`. The number of bytes copied is determined by `src`.
 ///
 /// # Parameters:
@@ -997,3 +1368,129 @@ pub unsafe fn nstd_core_slice_mut_copy(dest: &mut NSTDSliceMut, src: &NSTDSlice)
     let src = nstd_core_slice_as_ptr(src).cast();
     nstd_core_mem_copy(dest, src, len);
 }
+
+/// Rotates a slice in place such that the element at index `mid` becomes the first element.
+///
+/// `mid` is taken modulo the slice's length.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *slice` - The slice to rotate.
+///
+/// - `NSTDUInt mid` - The number of elements to rotate the slice to the left by.
+///
+/// # Safety
+///
+/// `slice`'s data must be valid for reads and writes.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::slice::{nstd_core_slice_mut_new, nstd_core_slice_mut_rotate_left};
+///
+/// const STRIDE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// let mut arr: [u16; 5] = [1, 2, 3, 4, 5];
+/// unsafe {
+///     let mut slice = nstd_core_slice_mut_new(arr.as_mut_ptr().cast(), STRIDE, ALIGN, 5).unwrap();
+///     nstd_core_slice_mut_rotate_left(&mut slice, 7);
+/// }
+/// assert!(arr == [3, 4, 5, 1, 2]);
+/// ```
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn)]
+pub unsafe fn nstd_core_slice_mut_rotate_left(slice: &mut NSTDSliceMut, mid: NSTDUInt) {
+    if slice.len > 0 {
+        #[allow(clippy::arithmetic_side_effects)]
+        let mid = (mid % slice.len) * slice.stride;
+        slice.as_bytes_mut().rotate_left(mid);
+    }
+}
+
+/// Rotates a slice in place such that the last `k` elements become the first elements.
+///
+/// `k` is taken modulo the slice's length.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *slice` - The slice to rotate.
+///
+/// - `NSTDUInt k` - The number of elements to rotate the slice to the right by.
+///
+/// # Safety
+///
+/// `slice`'s data must be valid for reads and writes.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::slice::{nstd_core_slice_mut_new, nstd_core_slice_mut_rotate_right};
+///
+/// const STRIDE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// let mut arr: [u16; 5] = [1, 2, 3, 4, 5];
+/// unsafe {
+///     let mut slice = nstd_core_slice_mut_new(arr.as_mut_ptr().cast(), STRIDE, ALIGN, 5).unwrap();
+///     nstd_core_slice_mut_rotate_right(&mut slice, 2);
+/// }
+/// assert!(arr == [4, 5, 1, 2, 3]);
+/// ```
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn)]
+pub unsafe fn nstd_core_slice_mut_rotate_right(slice: &mut NSTDSliceMut, k: NSTDUInt) {
+    if slice.len > 0 {
+        #[allow(clippy::arithmetic_side_effects)]
+        let k = (k % slice.len) * slice.stride;
+        slice.as_bytes_mut().rotate_right(k);
+    }
+}
+
+/// Swaps the contents of two slices element-wise.
+///
+/// # Parameters:
+///
+/// - `NSTDSliceMut *x` - The first slice.
+///
+/// - `NSTDSliceMut *y` - The second slice.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the slices' lengths or strides differ.
+///
+/// # Safety
+///
+/// Both slices' data must be valid for reads and writes, and must not overlap.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::slice::{nstd_core_slice_mut_new, nstd_core_slice_mut_swap_with};
+///
+/// const STRIDE: usize = core::mem::size_of::<u32>();
+/// const ALIGN: usize = core::mem::align_of::<u32>();
+///
+/// let mut front = [1u32, 2, 3];
+/// let mut back = [4u32, 5, 6];
+/// let mut short = [7u32, 8];
+/// unsafe {
+///     let mut x = nstd_core_slice_mut_new(front.as_mut_ptr().cast(), STRIDE, ALIGN, 3).unwrap();
+///     let mut y = nstd_core_slice_mut_new(back.as_mut_ptr().cast(), STRIDE, ALIGN, 3).unwrap();
+///     let mut z = nstd_core_slice_mut_new(short.as_mut_ptr().cast(), STRIDE, ALIGN, 2).unwrap();
+///     assert!(nstd_core_slice_mut_swap_with(&mut x, &mut y) == 0);
+///     assert!(nstd_core_slice_mut_swap_with(&mut x, &mut z) != 0);
+/// }
+/// assert!(front == [4, 5, 6] && back == [1, 2, 3] && short == [7, 8]);
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_core_slice_mut_swap_with(
+    x: &mut NSTDSliceMut,
+    y: &mut NSTDSliceMut,
+) -> NSTDErrorCode {
+    if x.len != y.len || x.stride != y.stride {
+        return 1;
+    }
+    x.as_bytes_mut().swap_with_slice(y.as_bytes_mut());
+    0
+}
@@ -8,6 +8,7 @@ use crate::{
             NSTDCStr, NSTDCStrMut,
         },
         def::NSTDByte,
+        hash::nstd_core_hash_bytes,
         optional::{
             gen_optional, NSTDOptional, NSTDOptionalFloat32, NSTDOptionalFloat64, NSTDOptionalInt,
             NSTDOptionalInt16, NSTDOptionalInt32, NSTDOptionalInt64, NSTDOptionalInt8,
@@ -22,7 +23,7 @@ use crate::{
         },
         unichar::NSTDOptionalUnichar,
     },
-    NSTDChar, NSTDUInt, NSTD_INT_MAX,
+    NSTDBool, NSTDChar, NSTDUInt, NSTDUInt64, NSTD_FALSE, NSTD_INT_MAX, NSTD_TRUE,
 };
 use nstdapi::nstdapi;
 
@@ -469,6 +470,116 @@ pub const fn nstd_core_str_byte_len(str: &NSTDStr) -> NSTDUInt {
     str.len
 }
 
+/// Converts a byte index into a string slice to the index of the Unicode character that starts at
+/// that byte.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice.
+///
+/// - `NSTDUInt byte_index` - The byte index to convert.
+///
+/// - `NSTDUInt *char_index` - Receives the character index on success.
+///
+/// # Returns
+///
+/// `NSTDBool is_boundary` - `NSTD_TRUE` on success, or `NSTD_FALSE` if `byte_index` does not lie on
+/// a character boundary, in which case `char_index` is left unchanged.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior in the event that `str`'s data is invalid.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::str::{nstd_core_str_byte_to_char_index, nstd_core_str_from_raw_cstr},
+///     NSTD_FALSE, NSTD_TRUE,
+/// };
+///
+/// let s_str = "🦀🚀!\0";
+/// unsafe {
+///     let str = nstd_core_str_from_raw_cstr(s_str.as_ptr().cast()).unwrap();
+///     let mut char_index = 0;
+///     assert!(nstd_core_str_byte_to_char_index(&str, 8, &mut char_index) == NSTD_TRUE);
+///     assert!(char_index == 2);
+///     assert!(nstd_core_str_byte_to_char_index(&str, 9, &mut char_index) == NSTD_TRUE);
+///     assert!(char_index == 3);
+///     assert!(nstd_core_str_byte_to_char_index(&str, 5, &mut char_index) == NSTD_FALSE);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_core_str_byte_to_char_index(
+    str: &NSTDStr,
+    byte_index: NSTDUInt,
+    char_index: &mut NSTDUInt,
+) -> NSTDBool {
+    let str = str.as_str();
+    if str.is_char_boundary(byte_index) {
+        *char_index = str[..byte_index].chars().count();
+        return NSTD_TRUE;
+    }
+    NSTD_FALSE
+}
+
+/// Converts the index of a Unicode character in a string slice to the byte index that the
+/// character starts at.
+///
+/// A `char_index` equal to the string slice's character count maps to its byte length.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice.
+///
+/// - `NSTDUInt char_index` - The character index to convert.
+///
+/// - `NSTDUInt *byte_index` - Receives the byte index on success.
+///
+/// # Returns
+///
+/// `NSTDBool in_bounds` - `NSTD_TRUE` on success, or `NSTD_FALSE` if `char_index` is greater than
+/// the number of characters in `str`, in which case `byte_index` is left unchanged.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior in the event that `str`'s data is invalid.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::str::{nstd_core_str_char_to_byte_index, nstd_core_str_from_raw_cstr},
+///     NSTD_FALSE, NSTD_TRUE,
+/// };
+///
+/// let s_str = "🦀🚀!\0";
+/// unsafe {
+///     let str = nstd_core_str_from_raw_cstr(s_str.as_ptr().cast()).unwrap();
+///     let mut byte_index = 0;
+///     assert!(nstd_core_str_char_to_byte_index(&str, 2, &mut byte_index) == NSTD_TRUE);
+///     assert!(byte_index == 8);
+///     assert!(nstd_core_str_char_to_byte_index(&str, 3, &mut byte_index) == NSTD_TRUE);
+///     assert!(byte_index == 9);
+///     assert!(nstd_core_str_char_to_byte_index(&str, 4, &mut byte_index) == NSTD_FALSE);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_core_str_char_to_byte_index(
+    str: &NSTDStr,
+    char_index: NSTDUInt,
+    byte_index: &mut NSTDUInt,
+) -> NSTDBool {
+    let str = str.as_str();
+    let index = match str.char_indices().nth(char_index) {
+        Some((index, _)) => index,
+        None if str.chars().count() == char_index => str.len(),
+        None => return NSTD_FALSE,
+    };
+    *byte_index = index;
+    NSTD_TRUE
+}
+
 /// Gets the `NSTDUnichar` at index `pos` in `str`.
 ///
 /// # Note
@@ -509,6 +620,53 @@ pub unsafe fn nstd_core_str_get(str: &NSTDStr, pos: NSTDUInt) -> NSTDOptionalUni
         .map_or(NSTDOptional::None, |chr| NSTDOptional::Some(chr.into()))
 }
 
+/// Gets the byte at index `pos` in `str`.
+///
+/// # Note
+///
+/// Unlike `nstd_core_str_get`, `pos` refers to the byte index rather than the `NSTDUnichar`
+/// index, and the returned value is a raw UTF-8 code unit rather than a full character. This
+/// operation runs in constant time.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice to index.
+///
+/// - `NSTDUInt pos` - The index of the byte to get.
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt8 byte` - The byte at index `pos`, or none if `pos` is out of range.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior in the event that `str`'s data is invalid.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{
+///     optional::NSTDOptional,
+///     str::{nstd_core_str_from_raw_cstr, nstd_core_str_get_byte},
+/// };
+///
+/// let s_str = "🦀!\0";
+/// unsafe {
+///     let str = nstd_core_str_from_raw_cstr(s_str.as_ptr().cast()).unwrap();
+///     assert!(nstd_core_str_get_byte(&str, 0) == NSTDOptional::Some(0xF0));
+///     assert!(nstd_core_str_get_byte(&str, 4) == NSTDOptional::Some(b'!'));
+///     assert!(nstd_core_str_get_byte(&str, 5) == NSTDOptional::None);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const unsafe fn nstd_core_str_get_byte(str: &NSTDStr, pos: NSTDUInt) -> NSTDOptionalUInt8 {
+    match pos < str.len {
+        true => NSTDOptional::Some(*str.ptr.add(pos)),
+        false => NSTDOptional::None,
+    }
+}
+
 /// Creates a substring of an existing string slice.
 ///
 /// # Parameters:
@@ -565,6 +723,88 @@ pub const unsafe fn nstd_core_str_substr(str: &NSTDStr, range: NSTDURange) -> NS
     nstd_core_str_from_bytes(&bytes)
 }
 
+/// Determines whether or not two string slices are equal, ignoring ASCII case differences.
+///
+/// Non-ASCII characters are compared exactly.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The first string slice.
+///
+/// - `const NSTDStr *other` - The second string slice.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - `NSTD_TRUE` if the two string slices match when ignoring ASCII case.
+///
+/// # Safety
+///
+/// Both `str` and `other`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::str::{nstd_core_str_eq_ignore_ascii_case, nstd_core_str_from_raw_cstr},
+///     NSTD_FALSE, NSTD_TRUE,
+/// };
+///
+/// unsafe {
+///     let a = nstd_core_str_from_raw_cstr("Content-Type\0".as_ptr().cast()).unwrap();
+///     let b = nstd_core_str_from_raw_cstr("content-type\0".as_ptr().cast()).unwrap();
+///     let c = nstd_core_str_from_raw_cstr("Content-Length\0".as_ptr().cast()).unwrap();
+///     assert!(nstd_core_str_eq_ignore_ascii_case(&a, &b) == NSTD_TRUE);
+///     assert!(nstd_core_str_eq_ignore_ascii_case(&a, &c) == NSTD_FALSE);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn)]
+pub unsafe fn nstd_core_str_eq_ignore_ascii_case(str: &NSTDStr, other: &NSTDStr) -> NSTDBool {
+    str.as_str().eq_ignore_ascii_case(other.as_str())
+}
+
+/// Computes the hash of a string slice's UTF-8 encoded bytes.
+///
+/// The result is the same as calling `nstd_core_hash_bytes` on `nstd_core_str_as_bytes(str)`.
+///
+/// # Note
+///
+/// The hash is only guaranteed to be stable within a single run of a program, it may change
+/// between versions of `nstd` and should not be persisted.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *str` - The string slice to hash.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The string slice's hash value.
+///
+/// # Safety
+///
+/// `str`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{
+///     hash::nstd_core_hash_bytes,
+///     str::{nstd_core_str_as_bytes, nstd_core_str_from_raw_cstr, nstd_core_str_hash},
+/// };
+///
+/// unsafe {
+///     let str = nstd_core_str_from_raw_cstr("Hello, world!\0".as_ptr().cast()).unwrap();
+///     let bytes = nstd_core_str_as_bytes(&str);
+///     assert!(nstd_core_str_hash(&str) == nstd_core_hash_bytes(&bytes));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const unsafe fn nstd_core_str_hash(str: &NSTDStr) -> NSTDUInt64 {
+    nstd_core_hash_bytes(&nstd_core_str_as_bytes(str))
+}
+
 gen_to_primitive!(
     /// # Example
     ///
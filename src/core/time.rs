@@ -1,9 +1,9 @@
 //! Low level time utilities.
 use crate::{
     core::optional::{gen_optional, NSTDOptional},
-    NSTDFloat64, NSTDInt64, NSTDUInt32,
+    NSTDFloat64, NSTDInt32, NSTDInt64, NSTDUInt32,
 };
-use core::time::Duration;
+use core::{cmp::Ordering, time::Duration};
 use nstdapi::nstdapi;
 
 /// Represents a span of time.
@@ -134,3 +134,39 @@ pub fn nstd_core_time_duration_add(lhs: NSTDDuration, rhs: NSTDDuration) -> NSTD
 pub fn nstd_core_time_duration_sub(lhs: NSTDDuration, rhs: NSTDDuration) -> NSTDDuration {
     nstd_core_time_duration_new(lhs.seconds - rhs.seconds)
 }
+
+/// Compares two time spans.
+///
+/// # Parameters:
+///
+/// - `NSTDDuration lhs` - The left-hand side operand.
+///
+/// - `NSTDDuration rhs` - The right-hand side operand.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `lhs` is shorter than `rhs`, a positive value if
+/// `lhs` is longer than `rhs`, or 0 if they are equal or either of them is NaN.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::time::{nstd_core_time_duration_compare, nstd_core_time_duration_new};
+///
+/// # unsafe {
+/// let short = nstd_core_time_duration_new(1.5);
+/// let long = nstd_core_time_duration_new(2.25);
+/// assert!(nstd_core_time_duration_compare(short, long) < 0);
+/// assert!(nstd_core_time_duration_compare(long, short) > 0);
+/// assert!(nstd_core_time_duration_compare(short, short) == 0);
+/// # }
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_core_time_duration_compare(lhs: NSTDDuration, rhs: NSTDDuration) -> NSTDInt32 {
+    match lhs.seconds.partial_cmp(&rhs.seconds) {
+        Some(Ordering::Less) => -1,
+        Some(Ordering::Greater) => 1,
+        _ => 0,
+    }
+}
@@ -4,8 +4,11 @@
 //! is done so that an `NSTDUnichar` can be created once and used a number of times without
 //! worrying about Unicode validity.
 use crate::{
-    core::optional::{gen_optional, NSTDOptional},
-    NSTDBool, NSTDChar32, NSTDUInt32,
+    core::{
+        optional::{gen_optional, NSTDOptional, NSTDOptionalUInt32},
+        slice::NSTDSliceMut,
+    },
+    NSTDBool, NSTDChar32, NSTDUInt, NSTDUInt32,
 };
 use nstdapi::nstdapi;
 
@@ -303,10 +306,93 @@ gen_deterministic!(
 ///     assert!(nstd_core_unichar_is_digit('E'.into(), 16) != NSTD_FALSE);
 ///     assert!(nstd_core_unichar_is_digit('F'.into(), 10) == NSTD_FALSE);
 ///     assert!(nstd_core_unichar_is_digit('0'.into(), 37) == NSTD_FALSE);
+///     assert!(nstd_core_unichar_is_digit('0'.into(), 1) == NSTD_FALSE);
 /// }
 /// ```
 #[inline]
 #[nstdapi]
 pub fn nstd_core_unichar_is_digit(chr: NSTDUnichar, radix: NSTDUInt32) -> NSTDBool {
-    radix <= 36 && char::from(chr).is_digit(radix)
+    (2..=36).contains(&radix) && char::from(chr).is_digit(radix)
+}
+
+/// Converts `chr` into a digit, depending on `radix`.
+///
+/// # Parameters:
+///
+/// - `NSTDUnichar chr` - The character to convert.
+///
+/// - `NSTDUInt32 radix` - The base, which must be within the range [2, 36].
+///
+/// # Returns
+///
+/// `NSTDOptionalUInt32 digit` - The digit's numerical value on success, or an uninitialized "none"
+/// variant if `chr` is not a digit in base `radix` or `radix` is out of range.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{optional::NSTDOptional, unichar::nstd_core_unichar_to_digit};
+///
+/// unsafe {
+///     assert!(nstd_core_unichar_to_digit('7'.into(), 10) == NSTDOptional::Some(7));
+///     assert!(nstd_core_unichar_to_digit('f'.into(), 16) == NSTDOptional::Some(15));
+///     assert!(nstd_core_unichar_to_digit('F'.into(), 10) == NSTDOptional::None);
+///     assert!(nstd_core_unichar_to_digit('0'.into(), 37) == NSTDOptional::None);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_core_unichar_to_digit(chr: NSTDUnichar, radix: NSTDUInt32) -> NSTDOptionalUInt32 {
+    match (2..=36).contains(&radix) {
+        true => char::from(chr)
+            .to_digit(radix)
+            .map_or(NSTDOptional::None, NSTDOptional::Some),
+        false => NSTDOptional::None,
+    }
+}
+
+/// Encodes `chr` as UTF-8 into the start of `buffer`.
+///
+/// # Parameters:
+///
+/// - `NSTDUnichar chr` - The character to encode.
+///
+/// - `NSTDSliceMut *buffer` - The byte buffer to write the encoded character to.
+///
+/// # Returns
+///
+/// `NSTDUInt len` - The number of bytes written to `buffer`, this will be 0 if `buffer`'s stride
+/// is not 1 or it is too small to hold the encoded character.
+///
+/// # Safety
+///
+/// `buffer`'s data must be valid for writes.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::core::{slice::nstd_core_slice_mut_new, unichar::nstd_core_unichar_encode_utf8};
+///
+/// let mut bytes = [0u8; 4];
+/// unsafe {
+///     let mut buffer = nstd_core_slice_mut_new(bytes.as_mut_ptr().cast(), 1, 1, 4).unwrap();
+///     assert!(nstd_core_unichar_encode_utf8('é'.into(), &mut buffer) == 2);
+///     assert!(bytes[..2] == *"é".as_bytes());
+///
+///     let mut buffer = nstd_core_slice_mut_new(bytes.as_mut_ptr().cast(), 1, 1, 1).unwrap();
+///     assert!(nstd_core_unichar_encode_utf8('é'.into(), &mut buffer) == 0);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_core_unichar_encode_utf8(
+    chr: NSTDUnichar,
+    buffer: &mut NSTDSliceMut,
+) -> NSTDUInt {
+    let chr = char::from(chr);
+    if let Some(buffer) = buffer.as_slice_mut::<u8>() {
+        if let Some(buffer) = buffer.get_mut(..chr.len_utf8()) {
+            return chr.encode_utf8(buffer).len();
+        }
+    }
+    0
 }
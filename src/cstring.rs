@@ -11,6 +11,7 @@ use crate::{
         },
         optional::NSTDOptional,
         slice::NSTDSlice,
+        str::{nstd_core_str_from_cstr, NSTDOptionalStr},
     },
     vec::{
         nstd_vec_allocator, nstd_vec_as_ptr, nstd_vec_as_slice, nstd_vec_cap, nstd_vec_clear,
@@ -250,6 +251,44 @@ pub const fn nstd_cstring_as_cstr(cstring: &NSTDCString<'_>) -> NSTDCStr {
     unsafe { nstd_core_cstr_new_unchecked(ptr.cast(), len) }
 }
 
+/// Creates a string slice containing the contents of `cstring`, excluding the null byte.
+///
+/// # Parameters:
+///
+/// - `const NSTDCString *cstring` - The C string.
+///
+/// # Returns
+///
+/// `NSTDOptionalStr str` - The new string slice on success, or an uninitialized "none" variant if
+/// the C string is not valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{cstr::nstd_core_cstr_from_raw, optional::NSTDOptional, str::nstd_core_str_byte_len},
+///     cstring::{nstd_cstring_as_str, nstd_cstring_from_cstr},
+/// };
+///
+/// unsafe {
+///     let cstr = nstd_core_cstr_from_raw("Hello, world!\0".as_ptr().cast());
+///     let cstring = nstd_cstring_from_cstr(&NSTD_ALLOCATOR, &cstr).unwrap();
+///     let NSTDOptional::Some(str) = nstd_cstring_as_str(&cstring) else {
+///         panic!("C string should be valid UTF-8");
+///     };
+///     assert!(nstd_core_str_byte_len(&str) == 13);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_cstring_as_str(cstring: &NSTDCString<'_>) -> NSTDOptionalStr {
+    let ptr = nstd_vec_as_ptr(&cstring.bytes);
+    let len = nstd_cstring_len(cstring);
+    // SAFETY: `ptr` is never null, owned C strings can never be longer than `NSTDInt`'s max value.
+    unsafe { nstd_core_str_from_cstr(&nstd_core_cstr_new_unchecked(ptr.cast(), len)) }
+}
+
 /// Returns an immutable byte slice of the C string's active data, including the null byte.
 ///
 /// # Parameters:
@@ -8,6 +8,7 @@ use crate::{
     io::{NSTDIOError, NSTDIOStringResult},
     string::{NSTDOptionalString, NSTDString},
     vec::{nstd_vec_new, nstd_vec_push, NSTDVec},
+    NSTDBool,
 };
 use core::ptr::addr_of;
 use nstdapi::nstdapi;
@@ -111,6 +112,41 @@ pub unsafe fn nstd_env_var(key: &NSTDStr) -> NSTDIOStringResult<'_> {
     }
 }
 
+/// Checks if a variable is set in the process environment.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *key` - The variable's key.
+///
+/// # Returns
+///
+/// `NSTDBool exists` - `NSTD_TRUE` if the variable is set, even if its value isn't valid Unicode.
+///
+/// # Safety
+///
+/// The user of this function must ensure that `key` is valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::str::nstd_core_str_from_raw_cstr, env::nstd_env_var_exists, NSTD_FALSE, NSTD_TRUE,
+/// };
+///
+/// std::env::set_var("NSTD_ENV_VAR_EXISTS_TEST", "1");
+/// unsafe {
+///     let key = nstd_core_str_from_raw_cstr("NSTD_ENV_VAR_EXISTS_TEST\0".as_ptr().cast()).unwrap();
+///     assert!(nstd_env_var_exists(&key) == NSTD_TRUE);
+///     let key = nstd_core_str_from_raw_cstr("NSTD_ENV_VAR_MISSING_TEST\0".as_ptr().cast()).unwrap();
+///     assert!(nstd_env_var_exists(&key) == NSTD_FALSE);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_env_var_exists(key: &NSTDStr) -> NSTDBool {
+    std::env::var_os(key.as_str()).is_some()
+}
+
 /// Sets an environment variable for the current process.
 ///
 /// # Parameters:
@@ -165,16 +201,16 @@ pub unsafe fn nstd_env_remove_var(key: &NSTDStr) {
 ///
 /// `NSTDVec args` - The `NSTDString` arguments that the program was started with.
 ///
-/// # Panics
+/// # Note
 ///
-/// This operation will panic if any program arguments contain invalid Unicode.
+/// Invalid Unicode sequences in program arguments are replaced with `U+FFFD REPLACEMENT CHARACTER`.
 #[nstdapi]
 pub fn nstd_env_args() -> NSTDVec<'static> {
     let size = core::mem::size_of::<NSTDString<'_>>();
     let align = core::mem::align_of::<NSTDString<'_>>();
     let mut args = nstd_vec_new(&NSTD_ALLOCATOR, size, align);
-    for arg in std::env::args() {
-        let arg = NSTDString::from_string(arg);
+    for arg in std::env::args_os() {
+        let arg = NSTDString::from_string(arg.to_string_lossy().into_owned());
         // SAFETY: `arg` is stored on the stack.
         let errc = unsafe { nstd_vec_push(&mut args, addr_of!(arg).cast()) };
         if errc == NSTD_ALLOC_ERROR_NONE {
@@ -191,15 +227,18 @@ pub fn nstd_env_args() -> NSTDVec<'static> {
 ///
 /// `NSTDVec vars` - A list of the process environment variables.
 ///
-/// # Panics
+/// # Note
 ///
-/// This operation will panic if any environment variables contain invalid Unicode.
+/// Environment variables whose key or value contain invalid Unicode are skipped.
 #[nstdapi]
 pub fn nstd_env_vars() -> NSTDVec<'static> {
     let size = core::mem::size_of::<[NSTDString<'_>; 2]>();
     let align = core::mem::align_of::<[NSTDString<'_>; 2]>();
     let mut vars = nstd_vec_new(&NSTD_ALLOCATOR, size, align);
-    for (k, v) in std::env::vars() {
+    for (k, v) in std::env::vars_os() {
+        let (Ok(k), Ok(v)) = (k.into_string(), v.into_string()) else {
+            continue;
+        };
         let var = [NSTDString::from_string(k), NSTDString::from_string(v)];
         // SAFETY: `var` is stored on the stack.
         let errc = unsafe { nstd_vec_push(&mut vars, addr_of!(var).cast()) };
@@ -6,10 +6,14 @@ use crate::{
     string::NSTDString,
     time::{NSTDOptionalTime, NSTDTime},
     vec::NSTDVec,
-    NSTDUInt64, NSTDUInt8,
+    NSTDAnyMut, NSTDBool, NSTDUInt64, NSTDUInt8,
 };
 use nstdapi::nstdapi;
-use std::fs::File;
+use std::{
+    fs::{File, FileType},
+    io::Write,
+    path::Path,
+};
 
 /// A bit flag describing a file with read access.
 pub const NSTD_FILE_PERMISSION_READ: NSTDUInt8 = 1;
@@ -28,6 +32,20 @@ pub enum NSTDFileType {
     /// A symbolic link.
     NSTD_FILE_TYPE_SYMLINK,
 }
+impl From<FileType> for NSTDFileType {
+    /// Converts a [`FileType`] into an [`NSTDFileType`].
+    fn from(value: FileType) -> Self {
+        if value.is_file() {
+            Self::NSTD_FILE_TYPE_REGULAR
+        } else if value.is_dir() {
+            Self::NSTD_FILE_TYPE_DIRECTORY
+        } else if value.is_symlink() {
+            Self::NSTD_FILE_TYPE_SYMLINK
+        } else {
+            Self::NSTD_FILE_TYPE_UNKNOWN
+        }
+    }
+}
 
 /// Represents file metadata.
 #[nstdapi]
@@ -226,6 +244,14 @@ pub unsafe fn nstd_fs_read_to_string(path: &NSTDStr) -> NSTDIOStringResult<'_> {
 
 /// Overwrites the contents of a file.
 ///
+/// The file is created if it does not already exist, and truncated if it does. All of `content` is
+/// written, an error is returned if it can't be.
+///
+/// # Note
+///
+/// This operation is not atomic. If it fails or is interrupted part way through, the file may be
+/// left empty or only partially written.
+///
 /// # Parameters:
 ///
 /// - `const NSTDStr *path` - A path to the file to write to.
@@ -250,6 +276,37 @@ pub unsafe fn nstd_fs_write(path: &NSTDStr, content: &NSTDSlice) -> NSTDIOError
     )
 }
 
+/// Appends data to the end of a file, creating the file if it does not already exist.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file to append to.
+///
+/// - `const NSTDSlice *content` - The content to append to the file.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if either `path` or `content`'s data is invalid.
+#[nstdapi]
+pub unsafe fn nstd_fs_append(path: &NSTDStr, content: &NSTDSlice) -> NSTDIOError {
+    content.as_slice().map_or(
+        NSTDIOError::NSTD_IO_ERROR_INVALID_INPUT,
+        |bytes| match File::options()
+            .create(true)
+            .append(true)
+            .open(path.as_str())
+            .and_then(|mut f| f.write_all(bytes))
+        {
+            Ok(()) => NSTDIOError::NSTD_IO_ERROR_NONE,
+            Err(err) => NSTDIOError::from_err(err.kind()),
+        },
+    )
+}
+
 /// Renames a file or directory, replacing the destination if it already exists.
 ///
 /// # Parameters:
@@ -298,6 +355,54 @@ pub unsafe fn nstd_fs_copy(from: &NSTDStr, to: &NSTDStr) -> NSTDIOError {
     NSTDIOError::NSTD_IO_ERROR_NONE
 }
 
+/// Creates a new symbolic link on the file system.
+///
+/// # Note
+///
+/// On Windows, creating a symbolic link may require elevated privileges or Developer Mode to be
+/// enabled. Windows also distinguishes file symbolic links from directory symbolic links, a
+/// directory link is created if `original` (resolved relative to `link`'s parent directory) is an
+/// existing directory, otherwise a file link is created.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *original` - The path that the link will point to.
+///
+/// - `const NSTDStr *link` - The path of the new symbolic link.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code. This will be `NSTD_IO_ERROR_UNSUPPORTED` on
+/// platforms other than Unix and Windows.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if either `original` or `link`'s data is invalid.
+#[nstdapi]
+pub unsafe fn nstd_fs_symlink(original: &NSTDStr, link: &NSTDStr) -> NSTDIOError {
+    #[cfg(unix)]
+    let res = std::os::unix::fs::symlink(original.as_str(), link.as_str());
+    #[cfg(windows)]
+    let res = {
+        let original = Path::new(original.as_str());
+        let link = Path::new(link.as_str());
+        let target = link
+            .parent()
+            .map_or(original.into(), |parent| parent.join(original));
+        match target.is_dir() {
+            true => std::os::windows::fs::symlink_dir(original, link),
+            false => std::os::windows::fs::symlink_file(original, link),
+        }
+    };
+    #[cfg(not(any(unix, windows)))]
+    return NSTDIOError::NSTD_IO_ERROR_UNSUPPORTED;
+    #[cfg(any(unix, windows))]
+    match res {
+        Ok(()) => NSTDIOError::NSTD_IO_ERROR_NONE,
+        Err(err) => NSTDIOError::from_err(err.kind()),
+    }
+}
+
 /// Returns the absolute path of a file system item.
 ///
 /// # Parameters:
@@ -323,6 +428,31 @@ pub unsafe fn nstd_fs_absolute(path: &NSTDStr) -> NSTDIOStringResult<'_> {
     }
 }
 
+/// Returns the path that a symbolic link points to.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the symbolic link.
+///
+/// # Returns
+///
+/// `NSTDIOStringResult target` - The path that the link points to, or the I/O operation error code
+/// on failure.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+#[nstdapi]
+pub unsafe fn nstd_fs_read_link(path: &NSTDStr) -> NSTDIOStringResult<'_> {
+    match std::fs::read_link(path.as_str()) {
+        Ok(path) => path.into_os_string().into_string().map_or(
+            NSTDResult::Err(NSTDIOError::NSTD_IO_ERROR_INVALID_DATA),
+            |path| NSTDResult::Ok(NSTDString::from_string(path)),
+        ),
+        Err(err) => NSTDResult::Err(NSTDIOError::from_err(err.kind())),
+    }
+}
+
 /// Retrieves metadata about a file pointed to by `path`.
 ///
 /// # Parameters:
@@ -350,19 +480,200 @@ pub unsafe fn nstd_fs_metadata(path: &NSTDStr) -> NSTDFileMetadataResult {
             modified: metadata.modified().map_or(NSTDOptional::None, |t| {
                 NSTDOptional::Some(NSTDTime::from(t))
             }),
-            file_type: {
-                if metadata.is_file() {
-                    NSTDFileType::NSTD_FILE_TYPE_REGULAR
-                } else if metadata.is_dir() {
-                    NSTDFileType::NSTD_FILE_TYPE_DIRECTORY
-                } else if metadata.is_symlink() {
-                    NSTDFileType::NSTD_FILE_TYPE_SYMLINK
-                } else {
-                    NSTDFileType::NSTD_FILE_TYPE_UNKNOWN
-                }
-            },
+            file_type: metadata.file_type().into(),
             permissions: metadata.permissions().readonly().into(),
         }),
         Err(err) => NSTDResult::Err(NSTDIOError::from_err(err.kind())),
     }
 }
+
+/// Sets whether or not a file is read-only.
+///
+/// # Note
+///
+/// On Unix systems, making a file writable this way makes it writable by all users. Use
+/// `nstd_os_unix_fs_set_mode` for finer grained control.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file.
+///
+/// - `NSTDBool readonly` - `NSTD_TRUE` to make the file read-only, `NSTD_FALSE` to make it
+/// writable.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+#[nstdapi]
+pub unsafe fn nstd_fs_set_readonly(path: &NSTDStr, readonly: NSTDBool) -> NSTDIOError {
+    let path = path.as_str();
+    let mut permissions = match std::fs::metadata(path) {
+        Ok(metadata) => metadata.permissions(),
+        Err(err) => return NSTDIOError::from_err(err.kind()),
+    };
+    permissions.set_readonly(readonly);
+    if let Err(err) = std::fs::set_permissions(path, permissions) {
+        return NSTDIOError::from_err(err.kind());
+    }
+    NSTDIOError::NSTD_IO_ERROR_NONE
+}
+
+/// Invokes `callback` with the name of each entry within a directory.
+///
+/// # Note
+///
+/// The order of the entries is unspecified. Entries whose names contain invalid Unicode are
+/// skipped.
+///
+/// Symbolic links are not followed, so `callback` receives `NSTD_FALSE` for a symbolic link even
+/// if it points to a directory.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the directory to read.
+///
+/// - `void (*callback)(const NSTDStr *, NSTDBool, NSTDAnyMut)` - The function to call with each
+/// entry's name, whether or not the entry is a directory, and `data`.
+///
+/// - `NSTDAnyMut data` - User data to pass to `callback`.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// - This operation makes a direct call on a C function pointer (`callback`).
+///
+/// - This operation can cause undefined behavior if `path`'s data is invalid.
+///
+/// - The entry name passed to `callback` is only valid for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::str::{
+///         nstd_core_str_as_ptr, nstd_core_str_byte_len, nstd_core_str_from_raw_cstr, NSTDStr,
+///     },
+///     fs::nstd_fs_read_dir,
+///     io::NSTDIOError,
+///     NSTDAnyMut, NSTDBool,
+/// };
+///
+/// unsafe extern "C" fn count(name: &NSTDStr, is_dir: NSTDBool, data: NSTDAnyMut) {
+///     let len = nstd_core_str_byte_len(name);
+///     if core::slice::from_raw_parts(nstd_core_str_as_ptr(name), len) == b"src" && is_dir {
+///         *data.cast::<u32>() += 1;
+///     }
+/// }
+///
+/// let mut found = 0u32;
+/// unsafe {
+///     let path = nstd_core_str_from_raw_cstr(".\0".as_ptr().cast()).unwrap();
+///     let errc = nstd_fs_read_dir(&path, count, (&mut found as *mut u32).cast());
+///     assert!(errc == NSTDIOError::NSTD_IO_ERROR_NONE);
+/// }
+/// assert!(found == 1);
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_fs_read_dir(
+    path: &NSTDStr,
+    callback: unsafe extern "C" fn(&NSTDStr, NSTDBool, NSTDAnyMut),
+    data: NSTDAnyMut,
+) -> NSTDIOError {
+    let read_dir = match std::fs::read_dir(path.as_str()) {
+        Ok(read_dir) => read_dir,
+        Err(err) => return NSTDIOError::from_err(err.kind()),
+    };
+    for entry in read_dir {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => return NSTDIOError::from_err(err.kind()),
+        };
+        let is_dir = match entry.file_type() {
+            Ok(file_type) => file_type.is_dir(),
+            Err(err) => return NSTDIOError::from_err(err.kind()),
+        };
+        if let Ok(name) = entry.file_name().into_string() {
+            callback(&NSTDStr::from_str(&name), is_dir, data);
+        }
+    }
+    NSTDIOError::NSTD_IO_ERROR_NONE
+}
+
+/// Checks if a file system item exists.
+///
+/// # Note
+///
+/// Symbolic links are followed, and `NSTD_FALSE` is returned if the item's metadata cannot be
+/// retrieved for any reason, including insufficient permissions.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file system item.
+///
+/// # Returns
+///
+/// `NSTDBool exists` - `NSTD_TRUE` if `path` points to an existing file system item.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_fs_exists(path: &NSTDStr) -> NSTDBool {
+    Path::new(path.as_str()).exists()
+}
+
+/// Checks if `path` points to a regular file.
+///
+/// # Note
+///
+/// Symbolic links are followed, and `NSTD_FALSE` is returned if the item's metadata cannot be
+/// retrieved for any reason, including insufficient permissions.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file system item.
+///
+/// # Returns
+///
+/// `NSTDBool is_file` - `NSTD_TRUE` if `path` points to a regular file.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_fs_is_file(path: &NSTDStr) -> NSTDBool {
+    Path::new(path.as_str()).is_file()
+}
+
+/// Checks if `path` points to a directory.
+///
+/// # Note
+///
+/// Symbolic links are followed, and `NSTD_FALSE` is returned if the item's metadata cannot be
+/// retrieved for any reason, including insufficient permissions.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the file system item.
+///
+/// # Returns
+///
+/// `NSTDBool is_dir` - `NSTD_TRUE` if `path` points to a directory.
+///
+/// # Safety
+///
+/// This operation can cause undefined behavior if `path`'s data is invalid.
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_fs_is_dir(path: &NSTDStr) -> NSTDBool {
+    Path::new(path.as_str()).is_dir()
+}
@@ -9,12 +9,15 @@ use crate::{
     io::{NSTDIOError, NSTDIOResult},
     string::NSTDString,
     vec::NSTDVec,
-    NSTDUInt8,
+    NSTDInt64, NSTDUInt64, NSTDUInt8,
 };
 use nstdapi::nstdapi;
-use std::fs::File;
 #[cfg(unix)]
 use std::os::unix::io::AsRawFd;
+use std::{
+    fs::File,
+    io::{Seek, SeekFrom},
+};
 
 /// Creates the file upon opening if it does not already exist.
 ///
@@ -44,6 +47,22 @@ pub struct NSTDFile {
 /// A result type yielding an `NSTDFile` on success.
 pub type NSTDFileResult = NSTDResult<NSTDFile, NSTDIOError>;
 
+/// Describes where a file seek operation's offset is relative to.
+#[nstdapi]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum NSTDSeekFrom {
+    /// The offset is relative to the start of the file, it must not be negative.
+    NSTD_SEEK_FROM_START,
+    /// The offset is relative to the current position in the file.
+    NSTD_SEEK_FROM_CURRENT,
+    /// The offset is relative to the end of the file.
+    NSTD_SEEK_FROM_END,
+}
+
+/// A result type yielding a file's new cursor position on success.
+pub type NSTDFileSeekResult = NSTDResult<NSTDUInt64, NSTDIOError>;
+
 /// Opens file on the filesystem and returns a handle to it.
 ///
 /// # Parameters:
@@ -258,6 +277,63 @@ pub unsafe fn nstd_fs_file_read_exact(
     return crate::os::unix::io::stdio::read_exact(file.f.as_raw_fd(), buffer).into();
 }
 
+/// Moves a file's cursor to a new position.
+///
+/// # Parameters:
+///
+/// - `NSTDFile *file` - A handle to the file.
+///
+/// - `NSTDInt64 offset` - The number of bytes to move the cursor by.
+///
+/// - `NSTDSeekFrom whence` - The position that `offset` is relative to.
+///
+/// # Returns
+///
+/// `NSTDFileSeekResult pos` - The new cursor position from the start of the file on success, or
+/// the I/O operation error code on failure.
+#[nstdapi]
+pub fn nstd_fs_file_seek(
+    file: &mut NSTDFile,
+    offset: NSTDInt64,
+    whence: NSTDSeekFrom,
+) -> NSTDFileSeekResult {
+    let pos = match whence {
+        NSTDSeekFrom::NSTD_SEEK_FROM_START => match NSTDUInt64::try_from(offset) {
+            Ok(offset) => SeekFrom::Start(offset),
+            _ => return NSTDResult::Err(NSTDIOError::NSTD_IO_ERROR_INVALID_INPUT),
+        },
+        NSTDSeekFrom::NSTD_SEEK_FROM_CURRENT => SeekFrom::Current(offset),
+        NSTDSeekFrom::NSTD_SEEK_FROM_END => SeekFrom::End(offset),
+    };
+    match file.f.seek(pos) {
+        Ok(pos) => NSTDResult::Ok(pos),
+        Err(err) => NSTDResult::Err(NSTDIOError::from_err(err.kind())),
+    }
+}
+
+/// Truncates or extends a file to `len` bytes.
+///
+/// If the file is extended, the new bytes are filled with zeros. The file's cursor position is
+/// left unchanged.
+///
+/// # Parameters:
+///
+/// - `NSTDFile *file` - A handle to the file, it must be opened for writing.
+///
+/// - `NSTDUInt64 len` - The file's new length in bytes.
+///
+/// # Returns
+///
+/// `NSTDIOError errc` - The I/O operation error code.
+#[inline]
+#[nstdapi]
+pub fn nstd_fs_file_set_len(file: &mut NSTDFile, len: NSTDUInt64) -> NSTDIOError {
+    if let Err(err) = file.f.set_len(len) {
+        return NSTDIOError::from_err(err.kind());
+    }
+    NSTDIOError::NSTD_IO_ERROR_NONE
+}
+
 /// Closes a file handle.
 ///
 /// # Parameters:
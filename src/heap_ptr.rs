@@ -183,6 +183,31 @@ pub unsafe fn nstd_heap_ptr_new_zeroed(
 ///
 /// `NSTDOptionalHeapPtr cloned` - A new clone of the original heap object, or an uninitialized
 /// "none" variant if allocating fails.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::alloc::nstd_core_alloc_layout_new,
+///     heap_ptr::{
+///         nstd_heap_ptr_clone, nstd_heap_ptr_get, nstd_heap_ptr_get_mut, nstd_heap_ptr_new,
+///     },
+/// };
+///
+/// unsafe {
+///     let v = [4u16, 8, 15, 16, 23, 42];
+///     let size = core::mem::size_of::<[u16; 6]>();
+///     let align = core::mem::align_of::<[u16; 6]>();
+///     let layout = nstd_core_alloc_layout_new(size, align).unwrap();
+///     let mut hptr = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let cloned = nstd_heap_ptr_clone(&hptr).unwrap();
+///     assert!(nstd_heap_ptr_get(&cloned) != nstd_heap_ptr_get(&hptr));
+///     *nstd_heap_ptr_get_mut(&mut hptr).cast::<[u16; 6]>() = [0; 6];
+///     assert!(*nstd_heap_ptr_get(&cloned).cast::<[u16; 6]>() == v);
+/// }
+/// ```
 #[nstdapi]
 pub fn nstd_heap_ptr_clone<'a>(hptr: &NSTDHeapPtr<'a>) -> NSTDOptionalHeapPtr<'a> {
     let size = nstd_heap_ptr_size(hptr);
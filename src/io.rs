@@ -57,7 +57,7 @@ pub enum NSTDIOError {
     NSTD_IO_ERROR_BLOCKING,
     /// Some input parameter was incorrect.
     NSTD_IO_ERROR_INVALID_INPUT,
-    /// Some input data was incorrect.
+    /// Some input data was incorrect, such as invalid UTF-8.
     NSTD_IO_ERROR_INVALID_DATA,
     /// The I/O operation's timeout expired, causing it to be canceled.
     NSTD_IO_ERROR_TIMED_OUT,
@@ -71,6 +71,8 @@ pub enum NSTDIOError {
     NSTD_IO_ERROR_UNEXPECTED_EOF,
     /// An operation could not be completed, because it failed to allocate enough memory.
     NSTD_IO_ERROR_OUT_OF_MEMORY,
+    /// A reader has reached the end of its stream.
+    NSTD_IO_ERROR_EOF,
 }
 impl NSTDIOError {
     /// Creates a new instance of [`NSTDIOError`] from a Rust [`ErrorKind`].
@@ -145,6 +147,10 @@ pub type NSTDIOStringResult<'a> = NSTDResult<NSTDString<'a>, NSTDIOError>;
 
 /// Writes a string slice to stdout.
 ///
+/// # Note
+///
+/// Stdout is flushed once the string has been written, so there is no need to flush it manually.
+///
 /// # Parameters:
 ///
 /// - `const NSTDStr *output` - The string slice to write to stdout.
@@ -170,6 +176,10 @@ pub unsafe fn nstd_io_print(output: &NSTDStr) -> NSTDIOError {
 
 /// Writes a string slice to stdout followed by a new line.
 ///
+/// # Note
+///
+/// Stdout is flushed once the string has been written, so there is no need to flush it manually.
+///
 /// # Parameters:
 ///
 /// - `const NSTDStr *output` - The string slice to write to stdout.
@@ -2,14 +2,11 @@
 use crate::{
     alloc::CBox,
     core::{
-        alloc::NSTDAllocError,
         optional::{gen_optional, NSTDOptional},
-        result::NSTDResult,
-        slice::{NSTDSlice, NSTDSliceMut},
-        str::nstd_core_str_from_bytes_unchecked,
+        slice::NSTDSliceMut,
     },
     io::{NSTDIOError, NSTDIOResult},
-    string::{nstd_string_push_str, NSTDString},
+    string::NSTDString,
     vec::NSTDVec,
 };
 use nstdapi::nstdapi;
@@ -138,7 +135,8 @@ pub fn nstd_io_stdin_read_to_string(
 /// # Note
 ///
 /// This function will return an error code of `NSTD_IO_ERROR_INVALID_INPUT` if the buffer's
-/// element size is not 1.
+/// element size is not 1, and `NSTD_IO_ERROR_UNEXPECTED_EOF` if the stream ends before `buffer` is
+/// filled.
 ///
 /// # Parameters:
 ///
@@ -167,6 +165,11 @@ pub unsafe fn nstd_io_stdin_read_exact(
 
 /// Reads a line from stdin and appends it to `buffer`.
 ///
+/// # Note
+///
+/// The line's trailing newline is kept. Once the end of the stream has been reached, an error code
+/// of `NSTD_IO_ERROR_EOF` is returned.
+///
 /// # Parameters:
 ///
 /// - `NSTDStdin *handle` - A handle to stdin.
@@ -182,21 +185,7 @@ pub fn nstd_io_stdin_read_line(
     handle: &mut NSTDStdin,
     buffer: &mut NSTDString<'_>,
 ) -> NSTDIOResult {
-    let mut buf = String::new();
-    match handle.r#in.read_line(&mut buf) {
-        Ok(r) => {
-            let bytes = NSTDSlice::from_slice(buf.as_bytes());
-            // SAFETY: `bytes` refers to `buf`'s data, which is still valid UTF-8 here.
-            unsafe {
-                let str = nstd_core_str_from_bytes_unchecked(&bytes);
-                match nstd_string_push_str(buffer, &str) {
-                    NSTDAllocError::NSTD_ALLOC_ERROR_NONE => NSTDResult::Ok(r),
-                    _ => NSTDResult::Err(NSTDIOError::NSTD_IO_ERROR_OUT_OF_MEMORY),
-                }
-            }
-        }
-        Err(err) => NSTDResult::Err(NSTDIOError::from_err(err.kind())),
-    }
+    crate::io::stdio::read_line(&mut handle.r#in.lock(), buffer)
 }
 
 /// Frees an instance of `NSTDStdin`.
@@ -337,7 +326,8 @@ pub fn nstd_io_stdin_lock_read_to_string(
 /// # Note
 ///
 /// This function will return an error code of `NSTD_IO_ERROR_INVALID_INPUT` if the buffer's
-/// element size is not 1.
+/// element size is not 1, and `NSTD_IO_ERROR_UNEXPECTED_EOF` if the stream ends before `buffer` is
+/// filled.
 ///
 /// # Parameters:
 ///
@@ -364,6 +354,32 @@ pub unsafe fn nstd_io_stdin_lock_read_exact(
     return crate::os::unix::io::stdio::read_exact(handle.r#in.as_raw_fd(), buffer).into();
 }
 
+/// Reads a line from stdin and appends it to `buffer`.
+///
+/// # Note
+///
+/// The line's trailing newline is kept. Once the end of the stream has been reached, an error code
+/// of `NSTD_IO_ERROR_EOF` is returned.
+///
+/// # Parameters:
+///
+/// - `NSTDStdinLock *handle` - A locked handle to the standard input stream.
+///
+/// - `NSTDString *buffer` - The string buffer to extend with a line from stdin.
+///
+/// # Returns
+///
+/// `NSTDIOResult read` - The number of bytes read from `handle` on success, or the I/O operation
+/// error code on failure.
+#[inline]
+#[nstdapi]
+pub fn nstd_io_stdin_lock_read_line(
+    handle: &mut NSTDStdinLock,
+    buffer: &mut NSTDString<'_>,
+) -> NSTDIOResult {
+    crate::io::stdio::read_line(&mut *handle.r#in, buffer)
+}
+
 /// Frees and unlocks an instance of `NSTDStdinLock`.
 ///
 /// # Parameters:
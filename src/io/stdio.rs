@@ -11,7 +11,7 @@ use crate::{
     string::{nstd_string_push_str, NSTDString},
     vec::{nstd_vec_extend, nstd_vec_stride, NSTDVec},
 };
-use std::io::{Read, Write};
+use std::io::{BufRead, Read, Write};
 
 /// Writes some `nstd` bytes to a [Write] stream.
 ///
@@ -134,3 +134,31 @@ pub(crate) unsafe fn read_exact<R: Read>(stream: &mut R, buffer: &mut NSTDSliceM
         },
     )
 }
+
+/// Extends an [`NSTDString`] with a line of UTF-8 data from a [`BufRead`] stream.
+///
+/// # Note
+///
+/// If extending the buffer fails, an error code of `NSTD_IO_ERROR_OUT_OF_MEMORY` will be returned.
+/// This does not mean there were no bytes read from `stream` in this case.
+///
+/// An error code of `NSTD_IO_ERROR_EOF` is returned once `stream` has reached its end.
+pub(crate) fn read_line<R: BufRead>(stream: &mut R, buffer: &mut NSTDString<'_>) -> NSTDIOResult {
+    // Attempt to read a line into `buffer`.
+    let mut buf = String::new();
+    match stream.read_line(&mut buf) {
+        Ok(0) => NSTDResult::Err(NSTDIOError::NSTD_IO_ERROR_EOF),
+        Ok(r) => {
+            let bytes = NSTDSlice::from_slice(buf.as_bytes());
+            // SAFETY: `bytes` refers to `buf`'s data, which is still valid UTF-8 here.
+            unsafe {
+                let str = nstd_core_str_from_bytes_unchecked(&bytes);
+                match nstd_string_push_str(buffer, &str) {
+                    NSTDAllocError::NSTD_ALLOC_ERROR_NONE => NSTDResult::Ok(r),
+                    _ => NSTDResult::Err(NSTDIOError::NSTD_IO_ERROR_OUT_OF_MEMORY),
+                }
+            }
+        }
+        Err(err) => NSTDResult::Err(NSTDIOError::from_err(err.kind())),
+    }
+}
@@ -60,6 +60,9 @@
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub mod alloc;
+#[cfg(feature = "condvar")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "condvar")))]
+pub mod condvar;
 #[cfg(feature = "core")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "core")))]
 pub mod core;
@@ -78,6 +81,9 @@ pub mod heap_ptr;
 #[cfg(feature = "io")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "io")))]
 pub mod io;
+#[cfg(feature = "map")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "map")))]
+pub mod map;
 #[cfg(feature = "math")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "math")))]
 pub mod math;
@@ -90,6 +96,9 @@ pub mod os;
 #[cfg(feature = "proc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "proc")))]
 pub mod proc;
+#[cfg(feature = "rwlock")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rwlock")))]
+pub mod rwlock;
 #[cfg(feature = "shared_lib")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "shared_lib")))]
 pub mod shared_lib;
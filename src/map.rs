@@ -0,0 +1,486 @@
+//! An unordered hash map with fixed-size keys and values.
+use crate::{
+    core::{
+        alloc::{
+            NSTDAllocError::{self, NSTD_ALLOC_ERROR_NONE},
+            NSTDAllocator,
+        },
+        def::NSTDByte,
+        hash::nstd_core_hash_bytes,
+        mem::{nstd_core_mem_compare, nstd_core_mem_copy},
+        optional::NSTDOptional,
+        slice::{nstd_core_slice_align, nstd_core_slice_new_unchecked},
+    },
+    vec::{
+        nstd_vec_allocator, nstd_vec_as_slice, nstd_vec_get, nstd_vec_get_mut, nstd_vec_len,
+        nstd_vec_new, nstd_vec_new_zeroed, nstd_vec_stride, NSTDVec,
+    },
+    NSTDAny, NSTDAnyMut, NSTDBool, NSTDUInt, NSTDUInt8, NSTD_NULL,
+};
+use nstdapi::nstdapi;
+
+/// The state of a bucket that has never held an entry.
+const EMPTY: NSTDUInt8 = 0;
+
+/// The state of a bucket that holds an entry.
+const OCCUPIED: NSTDUInt8 = 1;
+
+/// The state of a bucket whose entry has been removed.
+const REMOVED: NSTDUInt8 = 2;
+
+/// The minimum number of buckets a hash map allocates.
+const MIN_BUCKETS: NSTDUInt = 8;
+
+/// An unordered hash map with fixed-size keys and values.
+///
+/// Keys are compared and hashed by their raw bytes. Collisions are resolved with open addressing
+/// (linear probing).
+#[nstdapi]
+pub struct NSTDMap<'a> {
+    /// The state of each bucket.
+    states: NSTDVec<'a>,
+    /// Each bucket's key.
+    keys: NSTDVec<'a>,
+    /// Each bucket's value.
+    values: NSTDVec<'a>,
+    /// The number of entries in the map.
+    len: NSTDUInt,
+    /// The number of buckets whose entries have been removed.
+    removed: NSTDUInt,
+}
+impl NSTDMap<'_> {
+    /// Returns the number of buckets in the map.
+    #[inline]
+    const fn buckets(&self) -> NSTDUInt {
+        nstd_vec_len(&self.states)
+    }
+
+    /// Returns the state of the bucket at `index`.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than the number of buckets in the map.
+    #[inline]
+    const unsafe fn state(&self, index: NSTDUInt) -> NSTDUInt8 {
+        *nstd_vec_get(&self.states, index).cast()
+    }
+
+    /// Searches the map for `key`.
+    ///
+    /// Returns [Ok] with the index of the bucket containing `key` if it is found, otherwise [Err]
+    /// with the index of the bucket that `key` should be inserted into.
+    ///
+    /// # Safety
+    ///
+    /// - The map must have at least one empty bucket.
+    ///
+    /// - `key` must be valid for reads of the map's key stride.
+    unsafe fn probe(&self, key: NSTDAny) -> Result<NSTDUInt, NSTDUInt> {
+        let key_stride = nstd_vec_stride(&self.keys);
+        let bytes = nstd_core_slice_new_unchecked(key, 1, 1, key_stride);
+        #[allow(clippy::cast_possible_truncation)]
+        let hash = nstd_core_hash_bytes(&bytes) as NSTDUInt;
+        // The number of buckets is always a power of two.
+        #[allow(clippy::arithmetic_side_effects)]
+        let mask = self.buckets() - 1;
+        let mut index = hash & mask;
+        let mut removed = None;
+        loop {
+            match self.state(index) {
+                EMPTY => return Err(removed.unwrap_or(index)),
+                REMOVED => {
+                    removed.get_or_insert(index);
+                }
+                _ => {
+                    let other = nstd_vec_get(&self.keys, index).cast();
+                    if nstd_core_mem_compare(other, key.cast(), key_stride) {
+                        return Ok(index);
+                    }
+                }
+            }
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                index = (index + 1) & mask;
+            }
+        }
+    }
+
+    /// Fills the bucket at `index` with a copy of `key` and `value`.
+    ///
+    /// # Safety
+    ///
+    /// - `index` must be less than the number of buckets in the map, and the bucket must not be
+    ///   occupied.
+    ///
+    /// - `key` and `value` must be valid for reads of the map's key and value strides.
+    #[allow(clippy::arithmetic_side_effects)]
+    unsafe fn fill(&mut self, index: NSTDUInt, key: NSTDAny, value: NSTDAny) {
+        let key_stride = nstd_vec_stride(&self.keys);
+        let value_stride = nstd_vec_stride(&self.values);
+        let state = nstd_vec_get_mut(&mut self.states, index).cast::<NSTDUInt8>();
+        if *state == REMOVED {
+            self.removed -= 1;
+        }
+        *state = OCCUPIED;
+        let dest = nstd_vec_get_mut(&mut self.keys, index).cast();
+        nstd_core_mem_copy(dest, key.cast(), key_stride);
+        let dest = nstd_vec_get_mut(&mut self.values, index).cast();
+        nstd_core_mem_copy(dest, value.cast(), value_stride);
+        self.len += 1;
+    }
+
+    /// Moves all of the map's entries into a new set of `buckets` buckets.
+    ///
+    /// `buckets` must be a power of two greater than the number of entries in the map.
+    fn rehash(&mut self, buckets: NSTDUInt) -> NSTDAllocError {
+        let allocator = nstd_vec_allocator(&self.states);
+        let key_stride = nstd_vec_stride(&self.keys);
+        let key_align = nstd_core_slice_align(&nstd_vec_as_slice(&self.keys));
+        let value_stride = nstd_vec_stride(&self.values);
+        let value_align = nstd_core_slice_align(&nstd_vec_as_slice(&self.values));
+        let (NSTDOptional::Some(states), NSTDOptional::Some(keys), NSTDOptional::Some(values)) = (
+            nstd_vec_new_zeroed(allocator, 1, 1, buckets),
+            nstd_vec_new_zeroed(allocator, key_stride, key_align, buckets),
+            nstd_vec_new_zeroed(allocator, value_stride, value_align, buckets),
+        ) else {
+            return NSTDAllocError::NSTD_ALLOC_ERROR_OUT_OF_MEMORY;
+        };
+        let mut map = NSTDMap {
+            states,
+            keys,
+            values,
+            len: 0,
+            removed: 0,
+        };
+        for index in 0..self.buckets() {
+            // SAFETY: `index` is a valid bucket index, `map` has more buckets than entries, and
+            // keys are unique.
+            unsafe {
+                if self.state(index) == OCCUPIED {
+                    let key = nstd_vec_get(&self.keys, index);
+                    let value = nstd_vec_get(&self.values, index);
+                    let slot = map.probe(key);
+                    debug_assert!(slot.is_err());
+                    let (Ok(slot) | Err(slot)) = slot;
+                    map.fill(slot, key, value);
+                }
+            }
+        }
+        *self = map;
+        NSTD_ALLOC_ERROR_NONE
+    }
+}
+
+/// Creates a new hash map without allocating any resources.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDUInt key_stride` - The size in bytes of each key in the map.
+///
+/// - `NSTDUInt key_align` - The alignment of each key in the map.
+///
+/// - `NSTDUInt value_stride` - The size in bytes of each value in the map.
+///
+/// - `NSTDUInt value_align` - The alignment of each value in the map.
+///
+/// # Returns
+///
+/// `NSTDMap map` - The new hash map.
+///
+/// # Panics
+///
+/// This operation will panic if either `key_align` or `value_align` is not a power of two, or if
+/// `key_stride` or `value_stride` are not multiples of their respective alignments.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{alloc::NSTD_ALLOCATOR, map::nstd_map_new};
+///
+/// const SIZE: usize = core::mem::size_of::<u32>();
+/// const ALIGN: usize = core::mem::align_of::<u32>();
+///
+/// let map = unsafe { nstd_map_new(&NSTD_ALLOCATOR, SIZE, ALIGN, SIZE, ALIGN) };
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_map_new(
+    allocator: &NSTDAllocator,
+    key_stride: NSTDUInt,
+    key_align: NSTDUInt,
+    value_stride: NSTDUInt,
+    value_align: NSTDUInt,
+) -> NSTDMap<'_> {
+    NSTDMap {
+        states: nstd_vec_new(allocator, 1, 1),
+        keys: nstd_vec_new(allocator, key_stride, key_align),
+        values: nstd_vec_new(allocator, value_stride, value_align),
+        len: 0,
+        removed: 0,
+    }
+}
+
+/// Returns the number of entries in a hash map.
+///
+/// # Parameters:
+///
+/// - `const NSTDMap *map` - The hash map.
+///
+/// # Returns
+///
+/// `NSTDUInt len` - The number of key-value pairs stored in the map.
+#[inline]
+#[nstdapi]
+pub const fn nstd_map_len(map: &NSTDMap<'_>) -> NSTDUInt {
+    map.len
+}
+
+/// Inserts a key-value pair into a hash map.
+///
+/// If the map already contains `key`, its value is overwritten with `value`.
+///
+/// # Parameters:
+///
+/// - `NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to copy into the map.
+///
+/// - `NSTDAny value` - A pointer to the value to copy into the map.
+///
+/// # Returns
+///
+/// `NSTDAllocError errc` - The allocation operation error code.
+///
+/// # Safety
+///
+/// - `key` must be valid for reads of the map's key stride.
+///
+/// - `value` must be valid for reads of the map's value stride, and must not point into the map's
+/// own buffers.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::alloc::NSTDAllocError::NSTD_ALLOC_ERROR_NONE,
+///     map::{nstd_map_get, nstd_map_insert, nstd_map_len, nstd_map_new},
+/// };
+/// use core::ptr::addr_of;
+///
+/// const SIZE: usize = core::mem::size_of::<u64>();
+/// const ALIGN: usize = core::mem::align_of::<u64>();
+///
+/// unsafe {
+///     let mut map = nstd_map_new(&NSTD_ALLOCATOR, SIZE, ALIGN, SIZE, ALIGN);
+///     for i in 0..100u64 {
+///         let square = i * i;
+///         let errc = nstd_map_insert(&mut map, addr_of!(i).cast(), addr_of!(square).cast());
+///         assert!(errc == NSTD_ALLOC_ERROR_NONE);
+///     }
+///     assert!(nstd_map_len(&map) == 100);
+///
+///     let (key, value) = (9u64, 0u64);
+///     nstd_map_insert(&mut map, addr_of!(key).cast(), addr_of!(value).cast());
+///     assert!(nstd_map_len(&map) == 100);
+///     assert!(*nstd_map_get(&map, addr_of!(key).cast()).cast::<u64>() == 0);
+///     let key = 12u64;
+///     assert!(*nstd_map_get(&map, addr_of!(key).cast()).cast::<u64>() == 144);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_map_insert(
+    map: &mut NSTDMap<'_>,
+    key: NSTDAny,
+    value: NSTDAny,
+) -> NSTDAllocError {
+    let mut buckets = map.buckets();
+    if buckets > 0 {
+        if let Ok(index) = map.probe(key) {
+            let dest = nstd_vec_get_mut(&mut map.values, index).cast::<NSTDByte>();
+            nstd_core_mem_copy(dest, value.cast(), nstd_vec_stride(&map.values));
+            return NSTD_ALLOC_ERROR_NONE;
+        }
+    }
+    // Keep at least a quarter of the buckets empty so probing stays short and always terminates.
+    #[allow(clippy::arithmetic_side_effects)]
+    if map.len + map.removed >= buckets - buckets / 4 {
+        // Reuse the current number of buckets if most of the used buckets are removed entries.
+        if map.len >= buckets / 2 {
+            buckets = match buckets.checked_mul(2) {
+                Some(buckets) => buckets.max(MIN_BUCKETS),
+                None => return NSTDAllocError::NSTD_ALLOC_ERROR_INVALID_LAYOUT,
+            };
+        }
+        let errc = map.rehash(buckets);
+        if errc != NSTD_ALLOC_ERROR_NONE {
+            return errc;
+        }
+    }
+    if let Err(index) = map.probe(key) {
+        map.fill(index, key, value);
+    }
+    NSTD_ALLOC_ERROR_NONE
+}
+
+/// Returns an immutable pointer to the value associated with `key`.
+///
+/// # Parameters:
+///
+/// - `const NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDAny value` - A pointer to the value associated with `key`, or null if `map` does not
+/// contain `key`.
+///
+/// # Safety
+///
+/// `key` must be valid for reads of the map's key stride.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     map::{nstd_map_get, nstd_map_insert, nstd_map_new},
+/// };
+/// use core::ptr::addr_of;
+///
+/// unsafe {
+///     let mut map = nstd_map_new(&NSTD_ALLOCATOR, 2, 1, 4, 4);
+///     let (key, value) = (*b"id", 7i32);
+///     nstd_map_insert(&mut map, key.as_ptr().cast(), addr_of!(value).cast());
+///     assert!(*nstd_map_get(&map, b"id".as_ptr().cast()).cast::<i32>() == 7);
+///     assert!(nstd_map_get(&map, b"no".as_ptr().cast()).is_null());
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_map_get(map: &NSTDMap<'_>, key: NSTDAny) -> NSTDAny {
+    if map.buckets() > 0 {
+        if let Ok(index) = map.probe(key) {
+            return nstd_vec_get(&map.values, index);
+        }
+    }
+    NSTD_NULL
+}
+
+/// Returns a pointer to the value associated with `key`.
+///
+/// # Parameters:
+///
+/// - `NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDAnyMut value` - A pointer to the value associated with `key`, or null if `map` does not
+/// contain `key`.
+///
+/// # Safety
+///
+/// `key` must be valid for reads of the map's key stride.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     map::{nstd_map_get, nstd_map_get_mut, nstd_map_insert, nstd_map_new},
+/// };
+/// use core::ptr::addr_of;
+///
+/// const SIZE: usize = core::mem::size_of::<u32>();
+/// const ALIGN: usize = core::mem::align_of::<u32>();
+///
+/// unsafe {
+///     let mut map = nstd_map_new(&NSTD_ALLOCATOR, SIZE, ALIGN, SIZE, ALIGN);
+///     let (key, value) = (1u32, 10u32);
+///     nstd_map_insert(&mut map, addr_of!(key).cast(), addr_of!(value).cast());
+///     *nstd_map_get_mut(&mut map, addr_of!(key).cast()).cast::<u32>() += 5;
+///     assert!(*nstd_map_get(&map, addr_of!(key).cast()).cast::<u32>() == 15);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_map_get_mut(map: &mut NSTDMap<'_>, key: NSTDAny) -> NSTDAnyMut {
+    if map.buckets() > 0 {
+        if let Ok(index) = map.probe(key) {
+            return nstd_vec_get_mut(&mut map.values, index);
+        }
+    }
+    NSTD_NULL
+}
+
+/// Removes a key-value pair from a hash map.
+///
+/// # Parameters:
+///
+/// - `NSTDMap *map` - The hash map.
+///
+/// - `NSTDAny key` - A pointer to the key to remove.
+///
+/// # Returns
+///
+/// `NSTDBool is_removed` - `NSTD_TRUE` if `map` contained `key`.
+///
+/// # Safety
+///
+/// `key` must be valid for reads of the map's key stride.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     map::{nstd_map_get, nstd_map_insert, nstd_map_len, nstd_map_new, nstd_map_remove},
+///     NSTD_FALSE, NSTD_TRUE,
+/// };
+/// use core::ptr::addr_of;
+///
+/// const SIZE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// unsafe {
+///     let mut map = nstd_map_new(&NSTD_ALLOCATOR, SIZE, ALIGN, SIZE, ALIGN);
+///     let (key, value) = (3u16, 4u16);
+///     nstd_map_insert(&mut map, addr_of!(key).cast(), addr_of!(value).cast());
+///     assert!(nstd_map_remove(&mut map, addr_of!(key).cast()) == NSTD_TRUE);
+///     assert!(nstd_map_remove(&mut map, addr_of!(key).cast()) == NSTD_FALSE);
+///     assert!(nstd_map_get(&map, addr_of!(key).cast()).is_null());
+///     assert!(nstd_map_len(&map) == 0);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_map_remove(map: &mut NSTDMap<'_>, key: NSTDAny) -> NSTDBool {
+    if map.buckets() > 0 {
+        if let Ok(index) = map.probe(key) {
+            *nstd_vec_get_mut(&mut map.states, index).cast() = REMOVED;
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                map.len -= 1;
+                map.removed += 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// Frees an instance of `NSTDMap`.
+///
+/// # Parameters:
+///
+/// - `NSTDMap map` - The hash map to free.
+#[inline]
+#[nstdapi]
+#[allow(
+    unused_variables,
+    clippy::missing_const_for_fn,
+    clippy::needless_pass_by_value
+)]
+pub fn nstd_map_free(map: NSTDMap<'_>) {}
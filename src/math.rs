@@ -124,8 +124,45 @@ pub fn nstd_math_pow_f64(x: NSTDFloat64, exp: NSTDInt32) -> NSTDFloat64 {
     x.powi(exp)
 }
 
+/// Raises `x` to a floating point power.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat32 x` - The value.
+///
+/// - `NSTDFloat32 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDFloat32 pow` - `x` raised to the power of `exp`.
+#[inline]
+#[nstdapi]
+pub fn nstd_math_powf_f32(x: NSTDFloat32, exp: NSTDFloat32) -> NSTDFloat32 {
+    x.powf(exp)
+}
+/// Raises `x` to a floating point power.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat64 x` - The value.
+///
+/// - `NSTDFloat64 exp` - The exponent.
+///
+/// # Returns
+///
+/// `NSTDFloat64 pow` - `x` raised to the power of `exp`.
+#[inline]
+#[nstdapi]
+pub fn nstd_math_powf_f64(x: NSTDFloat64, exp: NSTDFloat64) -> NSTDFloat64 {
+    x.powf(exp)
+}
+
 /// Computes the square root of `x`.
 ///
+/// # Note
+///
+/// If `x` is less than -0, NaN is returned.
+///
 /// # Parameters:
 ///
 /// - `NSTDFloat32 x` - The value.
@@ -140,6 +177,10 @@ pub fn nstd_math_sqrt_f32(x: NSTDFloat32) -> NSTDFloat32 {
 }
 /// Computes the square root of `x`.
 ///
+/// # Note
+///
+/// If `x` is less than -0, NaN is returned.
+///
 /// # Parameters:
 ///
 /// - `NSTDFloat64 x` - The value.
@@ -153,7 +194,7 @@ pub fn nstd_math_sqrt_f64(x: NSTDFloat64) -> NSTDFloat64 {
     x.sqrt()
 }
 
-/// Computes the sine of `x`.
+/// Computes the cube root of `x`.
 ///
 /// # Parameters:
 ///
@@ -161,6 +202,35 @@ pub fn nstd_math_sqrt_f64(x: NSTDFloat64) -> NSTDFloat64 {
 ///
 /// # Returns
 ///
+/// `NSTDFloat32 cbrt` - The cube root of `x`.
+#[inline]
+#[nstdapi]
+pub fn nstd_math_cbrt_f32(x: NSTDFloat32) -> NSTDFloat32 {
+    x.cbrt()
+}
+/// Computes the cube root of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat64 x` - The value.
+///
+/// # Returns
+///
+/// `NSTDFloat64 cbrt` - The cube root of `x`.
+#[inline]
+#[nstdapi]
+pub fn nstd_math_cbrt_f64(x: NSTDFloat64) -> NSTDFloat64 {
+    x.cbrt()
+}
+
+/// Computes the sine of `x`.
+///
+/// # Parameters:
+///
+/// - `NSTDFloat32 x` - The angle in radians.
+///
+/// # Returns
+///
 /// `NSTDFloat32 sin` - The sine value of `x`.
 #[inline]
 #[nstdapi]
@@ -171,7 +241,7 @@ pub fn nstd_math_sin_f32(x: NSTDFloat32) -> NSTDFloat32 {
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat64 x` - The value.
+/// - `NSTDFloat64 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -186,7 +256,7 @@ pub fn nstd_math_sin_f64(x: NSTDFloat64) -> NSTDFloat64 {
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat32 x` - The value.
+/// - `NSTDFloat32 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -200,7 +270,7 @@ pub fn nstd_math_cos_f32(x: NSTDFloat32) -> NSTDFloat32 {
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat64 x` - The value.
+/// - `NSTDFloat64 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -215,7 +285,7 @@ pub fn nstd_math_cos_f64(x: NSTDFloat64) -> NSTDFloat64 {
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat32 x` - The value.
+/// - `NSTDFloat32 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -229,7 +299,7 @@ pub fn nstd_math_tan_f32(x: NSTDFloat32) -> NSTDFloat32 {
 ///
 /// # Parameters:
 ///
-/// - `NSTDFloat64 x` - The value.
+/// - `NSTDFloat64 x` - The angle in radians.
 ///
 /// # Returns
 ///
@@ -248,7 +318,7 @@ pub fn nstd_math_tan_f64(x: NSTDFloat64) -> NSTDFloat64 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 asin` - The arcsine value of `x`.
+/// `NSTDFloat32 asin` - The arcsine value of `x` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_asin_f32(x: NSTDFloat32) -> NSTDFloat32 {
@@ -262,7 +332,7 @@ pub fn nstd_math_asin_f32(x: NSTDFloat32) -> NSTDFloat32 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 asin` - The arcsine value of `x`.
+/// `NSTDFloat64 asin` - The arcsine value of `x` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_asin_f64(x: NSTDFloat64) -> NSTDFloat64 {
@@ -277,7 +347,7 @@ pub fn nstd_math_asin_f64(x: NSTDFloat64) -> NSTDFloat64 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 acos` - The arccosine value of `x`.
+/// `NSTDFloat32 acos` - The arccosine value of `x` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_acos_f32(x: NSTDFloat32) -> NSTDFloat32 {
@@ -291,7 +361,7 @@ pub fn nstd_math_acos_f32(x: NSTDFloat32) -> NSTDFloat32 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 acos` - The arccosine value of `x`.
+/// `NSTDFloat64 acos` - The arccosine value of `x` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_acos_f64(x: NSTDFloat64) -> NSTDFloat64 {
@@ -306,7 +376,7 @@ pub fn nstd_math_acos_f64(x: NSTDFloat64) -> NSTDFloat64 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 atan` - The arctangent value of `x`.
+/// `NSTDFloat32 atan` - The arctangent value of `x` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_atan_f32(x: NSTDFloat32) -> NSTDFloat32 {
@@ -320,14 +390,14 @@ pub fn nstd_math_atan_f32(x: NSTDFloat32) -> NSTDFloat32 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 atan` - The arctangent value of `x`.
+/// `NSTDFloat64 atan` - The arctangent value of `x` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_atan_f64(x: NSTDFloat64) -> NSTDFloat64 {
     x.atan()
 }
 
-/// Computes the four quadrant arctangent of `x` & `y`.
+/// Computes the four quadrant arctangent of `x` & `y` in radians.
 ///
 /// # Parameters:
 ///
@@ -337,13 +407,13 @@ pub fn nstd_math_atan_f64(x: NSTDFloat64) -> NSTDFloat64 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat32 atan2` - The four quadrant arctangent of `x` & `y`.
+/// `NSTDFloat32 atan2` - The four quadrant arctangent of `x` & `y` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_atan2_f32(x: NSTDFloat32, y: NSTDFloat32) -> NSTDFloat32 {
     x.atan2(y)
 }
-/// Computes the four quadrant arctangent of `x` & `y`.
+/// Computes the four quadrant arctangent of `x` & `y` in radians.
 ///
 /// # Parameters:
 ///
@@ -353,7 +423,7 @@ pub fn nstd_math_atan2_f32(x: NSTDFloat32, y: NSTDFloat32) -> NSTDFloat32 {
 ///
 /// # Returns
 ///
-/// `NSTDFloat64 atan2` - The four quadrant arctangent of `x` & `y`.
+/// `NSTDFloat64 atan2` - The four quadrant arctangent of `x` & `y` in radians.
 #[inline]
 #[nstdapi]
 pub fn nstd_math_atan2_f64(x: NSTDFloat64, y: NSTDFloat64) -> NSTDFloat64 {
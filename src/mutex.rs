@@ -9,7 +9,7 @@ use crate::{
     NSTDAny, NSTDAnyMut, NSTDBool,
 };
 use nstdapi::nstdapi;
-use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::sync::{LockResult, Mutex, MutexGuard, TryLockError};
 
 /// A mutual exclusion primitive useful for protecting shared data.
 #[nstdapi]
@@ -27,6 +27,14 @@ pub struct NSTDMutexGuard<'m, 'a> {
     /// The Rust [MutexGuard].
     guard: CBox<MutexGuard<'m, NSTDHeapPtr<'a>>>,
 }
+#[cfg(feature = "condvar")]
+impl<'m, 'a> NSTDMutexGuard<'m, 'a> {
+    /// Takes ownership of the underlying Rust [`MutexGuard`].
+    #[inline]
+    pub(crate) fn into_inner(self) -> MutexGuard<'m, NSTDHeapPtr<'a>> {
+        self.guard.into_inner()
+    }
+}
 
 /// A lock result returned from `nstd_mutex_lock` containing the mutex guard whether or not the
 /// data is poisoned.
@@ -38,6 +46,20 @@ pub type NSTDMutexLockResult<'m, 'a> = NSTDResult<NSTDMutexGuard<'m, 'a>, NSTDMu
 /// function would block.
 pub type NSTDOptionalMutexLockResult<'m, 'a> = NSTDOptional<NSTDMutexLockResult<'m, 'a>>;
 
+/// Converts a Rust [`LockResult`] into an `NSTDOptionalMutexLockResult`.
+pub(crate) fn lock_result<'m, 'a>(
+    result: LockResult<MutexGuard<'m, NSTDHeapPtr<'a>>>,
+) -> NSTDOptionalMutexLockResult<'m, 'a> {
+    match result {
+        Ok(guard) => CBox::new(guard).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Ok(NSTDMutexGuard { guard }))
+        }),
+        Err(err) => CBox::new(err.into_inner()).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Err(NSTDMutexGuard { guard }))
+        }),
+    }
+}
+
 /// Creates a new mutual exclusion primitive.
 ///
 /// # Parameters:
@@ -74,6 +96,19 @@ pub fn nstd_mutex_is_poisoned(mutex: &NSTDMutex<'_>) -> NSTDBool {
     mutex.mtx.is_poisoned()
 }
 
+/// Clears a mutex's poisoned state.
+///
+/// This allows a mutex to be marked as recovered after a thread panicked while holding its lock.
+///
+/// # Parameters:
+///
+/// - `const NSTDMutex *mutex` - The mutex.
+#[inline]
+#[nstdapi]
+pub fn nstd_mutex_clear_poison(mutex: &NSTDMutex<'_>) {
+    mutex.mtx.clear_poison();
+}
+
 /// Waits for a mutex lock to become acquired, returning a guard wrapping the protected data.
 ///
 /// Attempting to call this function on a thread that already owns the lock will either result in a
@@ -93,14 +128,7 @@ pub fn nstd_mutex_is_poisoned(mutex: &NSTDMutex<'_>) -> NSTDBool {
 /// This operation may panic if the lock is already held by the current thread.
 #[nstdapi]
 pub fn nstd_mutex_lock<'m, 'a>(mutex: &'m NSTDMutex<'a>) -> NSTDOptionalMutexLockResult<'m, 'a> {
-    match mutex.mtx.lock() {
-        Ok(guard) => CBox::new(guard).map_or(NSTDOptional::None, |guard| {
-            NSTDOptional::Some(NSTDResult::Ok(NSTDMutexGuard { guard }))
-        }),
-        Err(err) => CBox::new(err.into_inner()).map_or(NSTDOptional::None, |guard| {
-            NSTDOptional::Some(NSTDResult::Err(NSTDMutexGuard { guard }))
-        }),
-    }
+    lock_result(mutex.mtx.lock())
 }
 
 /// The non-blocking variant of `nstd_mutex_lock` returning an uninitialized "none" result if the
@@ -2,6 +2,9 @@
 #[cfg(feature = "os_unix_alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "os_unix_alloc")))]
 pub mod alloc;
+#[cfg(feature = "os_unix_fs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "os_unix_fs")))]
+pub mod fs;
 #[cfg(feature = "os_unix_io")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "os_unix_io")))]
 pub mod io;
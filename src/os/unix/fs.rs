@@ -0,0 +1,76 @@
+//! Unix file system utilities.
+use crate::{core::result::NSTDResult, os::unix::io::NSTDUnixIOError, NSTDChar, NSTDUInt32};
+use core::mem::MaybeUninit;
+use libc::{chmod, stat};
+use nstdapi::nstdapi;
+
+/// A result type that yields a Unix file mode on success and a Unix I/O operation error code on
+/// failure.
+pub type NSTDUnixFileModeResult = NSTDResult<NSTDUInt32, NSTDUnixIOError>;
+
+/// Returns the mode (permission bits and file type) of the file at `path`.
+///
+/// Symbolic links are followed.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar *path` - A null terminated path to the file.
+///
+/// # Returns
+///
+/// `NSTDUnixFileModeResult mode` - The file's `st_mode` on success, or the I/O operation error code
+/// on failure.
+///
+/// # Safety
+///
+/// `path` must be a valid null terminated C string.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{core::result::NSTDResult, os::unix::fs::nstd_os_unix_fs_mode};
+///
+/// unsafe {
+///     let NSTDResult::Ok(mode) = nstd_os_unix_fs_mode("/\0".as_ptr().cast()) else {
+///         panic!();
+///     };
+///     // The root directory's file type bits are `S_IFDIR`.
+///     assert!(mode & 0o170000 == 0o040000);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_os_unix_fs_mode(path: *const NSTDChar) -> NSTDUnixFileModeResult {
+    let mut buf = MaybeUninit::<stat>::uninit();
+    // `st_mode`'s type differs between platforms.
+    #[allow(clippy::useless_conversion)]
+    match stat(path, buf.as_mut_ptr()) {
+        0 => NSTDResult::Ok(buf.assume_init().st_mode.into()),
+        _ => NSTDResult::Err(NSTDUnixIOError::last()),
+    }
+}
+
+/// Sets the permission bits of the file at `path`, for example octal 755 to make it executable.
+///
+/// Symbolic links are followed.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar *path` - A null terminated path to the file.
+///
+/// - `NSTDUInt32 mode` - The file's new permission bits.
+///
+/// # Returns
+///
+/// `NSTDUnixIOError errc` - The I/O operation error code.
+///
+/// # Safety
+///
+/// `path` must be a valid null terminated C string.
+#[nstdapi]
+pub unsafe fn nstd_os_unix_fs_set_mode(path: *const NSTDChar, mode: NSTDUInt32) -> NSTDUnixIOError {
+    #[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+    match chmod(path, mode as _) {
+        0 => NSTDUnixIOError::NSTD_UNIX_IO_ERROR_NONE,
+        _ => NSTDUnixIOError::last(),
+    }
+}
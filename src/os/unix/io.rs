@@ -3,8 +3,8 @@ pub(crate) mod stdio;
 use crate::{core::result::NSTDResult, NSTDUInt};
 use core::ffi::c_int;
 use libc::{
-    EACCES, EAGAIN, EBADF, ECONNRESET, EINTR, EINVAL, EISDIR, ENETDOWN, ENETUNREACH, ENOMEM,
-    ENOTCONN, EPIPE, ESPIPE, ETIMEDOUT, EWOULDBLOCK,
+    EACCES, EAGAIN, EBADF, ECONNRESET, EINTR, EINVAL, EISDIR, ENETDOWN, ENETUNREACH, ENOENT,
+    ENOMEM, ENOTCONN, EPIPE, ESPIPE, ETIMEDOUT, EWOULDBLOCK,
 };
 use nstdapi::nstdapi;
 
@@ -50,11 +50,11 @@ pub enum NSTDUnixIOError {
 impl NSTDUnixIOError {
     /// Retrieves the last system error and turns it into an `NSTDUnixIOError`.
     #[allow(unused)]
-    fn last() -> Self {
+    pub(crate) fn last() -> Self {
         #[allow(trivial_numeric_casts, unreachable_patterns)]
         match errno::errno().0 as c_int {
             0 => Self::NSTD_UNIX_IO_ERROR_NONE,
-            EBADF => Self::NSTD_UNIX_IO_ERROR_NOT_FOUND,
+            EBADF | ENOENT => Self::NSTD_UNIX_IO_ERROR_NOT_FOUND,
             EACCES => Self::NSTD_UNIX_IO_ERROR_PERMISSION_DENIED,
             ECONNRESET => Self::NSTD_UNIX_IO_ERROR_CONNECTION_RESET,
             ENETDOWN | ENETUNREACH | ENOTCONN => Self::NSTD_UNIX_IO_ERROR_NO_CONNECTION,
@@ -1,6 +1,13 @@
 //! Provides shared library access for Unix like systems.
-use crate::{core::optional::NSTDOptional, NSTDAny, NSTDAnyMut, NSTDChar, NSTD_NULL};
-use libc::{dlclose, dlopen, dlsym, RTLD_LAZY, RTLD_LOCAL};
+extern crate alloc;
+use crate::{
+    core::{def::NSTDErrorCode, optional::NSTDOptional},
+    string::{NSTDOptionalString, NSTDString},
+    NSTDAny, NSTDAnyMut, NSTDChar, NSTDInt32, NSTD_NULL,
+};
+use alloc::string::String;
+use core::ffi::CStr;
+use libc::{dlclose, dlerror, dlopen, dlsym, RTLD_LAZY, RTLD_LOCAL};
 use nstdapi::nstdapi;
 
 /// Represents an owned handle to a dynamically loaded library.
@@ -41,7 +48,31 @@ pub type NSTDUnixOptionalSharedLib = NSTDOptional<NSTDUnixSharedLib>;
 #[inline]
 #[nstdapi]
 pub unsafe fn nstd_os_unix_shared_lib_load(path: *const NSTDChar) -> NSTDUnixOptionalSharedLib {
-    match dlopen(path, RTLD_LAZY | RTLD_LOCAL) {
+    nstd_os_unix_shared_lib_load_with_flags(path, RTLD_LAZY | RTLD_LOCAL)
+}
+
+/// Loads a dynamically loaded shared library with a custom set of `dlopen` flags.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar *path` - A path to the shared library to load.
+///
+/// - `NSTDInt32 flags` - The flags to pass to `dlopen`, such as `RTLD_NOW | RTLD_LOCAL`.
+///
+/// # Returns
+///
+/// `NSTDUnixOptionalSharedLib lib` - A handle to the loaded library.
+///
+/// # Safety
+///
+/// See <https://man7.org/linux/man-pages/man3/dlopen.3.html>.
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_os_unix_shared_lib_load_with_flags(
+    path: *const NSTDChar,
+    flags: NSTDInt32,
+) -> NSTDUnixOptionalSharedLib {
+    match dlopen(path, flags) {
         NSTD_NULL => NSTDOptional::None,
         handle => NSTDOptional::Some(NSTDUnixSharedLib { handle }),
     }
@@ -110,6 +141,51 @@ pub unsafe fn nstd_os_unix_shared_lib_get_mut(
     dlsym(lib.handle, symbol)
 }
 
+/// Returns a description of the most recent error that occurred while loading a shared library or
+/// retrieving one of its symbols.
+///
+/// Calling this function clears the error, so subsequent calls will return "none" until another
+/// error occurs.
+///
+/// # Returns
+///
+/// `NSTDOptionalString error` - A human readable description of the last error, or an uninitialized
+/// "none" variant if no error has occurred since the last call.
+///
+/// # Safety
+///
+/// See <https://man7.org/linux/man-pages/man3/dlerror.3.html>.
+#[nstdapi]
+pub unsafe fn nstd_os_unix_shared_lib_last_error() -> NSTDOptionalString<'static> {
+    let err = dlerror();
+    if err.is_null() {
+        return NSTDOptional::None;
+    }
+    let err = String::from_utf8_lossy(CStr::from_ptr(err).to_bytes()).into_owned();
+    NSTDOptional::Some(NSTDString::from_string(err))
+}
+
+/// Closes a loaded shared library, returning the status code reported by `dlclose`.
+///
+/// # Parameters:
+///
+/// - `NSTDUnixSharedLib lib` - A handle to the loaded library to unload.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if unloading the library fails.
+///
+/// # Safety
+///
+/// See <https://man7.org/linux/man-pages/man3/dlclose.3p.html>.
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_os_unix_shared_lib_close(lib: NSTDUnixSharedLib) -> NSTDErrorCode {
+    let handle = lib.handle;
+    core::mem::forget(lib);
+    dlclose(handle)
+}
+
 /// Closes and frees a loaded shared library.
 ///
 /// # Parameters:
@@ -1,10 +1,23 @@
 //! Shared library/module access for Windows.
+extern crate alloc;
 use crate::{
-    core::optional::NSTDOptional, os::windows::NSTDWindowsHandle, NSTDAny, NSTDAnyMut, NSTDChar,
-    NSTDChar16,
+    core::{optional::NSTDOptional, str::NSTDStr},
+    os::windows::{str::nstd_os_windows_str_to_utf16, NSTDWindowsHandle},
+    string::{NSTDOptionalString, NSTDString},
+    vec::nstd_vec_as_ptr,
+    NSTDAny, NSTDAnyMut, NSTDChar, NSTDChar16, NSTDUInt32,
 };
+use alloc::string::String;
 use nstdapi::nstdapi;
-use windows_sys::Win32::System::LibraryLoader::{FreeLibrary, GetProcAddress, LoadLibraryW};
+use windows_sys::Win32::{
+    Foundation::GetLastError,
+    System::{
+        Diagnostics::Debug::{
+            FormatMessageW, FORMAT_MESSAGE_FROM_SYSTEM, FORMAT_MESSAGE_IGNORE_INSERTS,
+        },
+        LibraryLoader::{FreeLibrary, GetProcAddress, LoadLibraryExW, LoadLibraryW},
+    },
+};
 
 /// A handle to a loaded library.
 #[nstdapi]
@@ -30,6 +43,10 @@ pub type NSTDWindowsOptionalSharedLib = NSTDOptional<NSTDWindowsSharedLib>;
 
 /// Loads a shared library/module by name.
 ///
+/// This function uses the standard DLL search order, which may include directories such as the
+/// current working directory. Use `nstd_os_windows_shared_lib_load_ex` with a flag such as
+/// `LOAD_LIBRARY_SEARCH_SYSTEM32` to constrain where the library is searched for.
+///
 /// # Parameters:
 ///
 /// - `const NSTDChar16 *name` - The name of the module to load.
@@ -53,6 +70,62 @@ pub unsafe fn nstd_os_windows_shared_lib_load(
     }
 }
 
+/// Loads a shared library/module by name with a custom set of `LoadLibraryExW` flags.
+///
+/// # Parameters:
+///
+/// - `const NSTDChar16 *name` - The name of the module to load.
+///
+/// - `NSTDUInt32 flags` - The flags to pass to `LoadLibraryExW`, such as
+/// `LOAD_LIBRARY_SEARCH_SYSTEM32`.
+///
+/// # Returns
+///
+/// `NSTDWindowsOptionalSharedLib lib` - A handle to the shared library.
+///
+/// # Safety
+///
+/// See
+/// <https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryexw>.
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_os_windows_shared_lib_load_ex(
+    name: *const NSTDChar16,
+    flags: NSTDUInt32,
+) -> NSTDWindowsOptionalSharedLib {
+    match LoadLibraryExW(name, 0, flags) {
+        0 => NSTDOptional::None,
+        handle => NSTDOptional::Some(NSTDWindowsSharedLib { handle }),
+    }
+}
+
+/// Loads a shared library/module by a UTF-8 encoded name.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *name` - The name of the module to load.
+///
+/// # Returns
+///
+/// `NSTDWindowsOptionalSharedLib lib` - A handle to the shared library, or an uninitialized "none"
+/// variant if converting `name` to UTF-16 or loading the library fails.
+///
+/// # Safety
+///
+/// - `name`'s data must be valid for reads.
+///
+/// - See
+/// <https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryw>.
+#[nstdapi]
+pub unsafe fn nstd_os_windows_shared_lib_load_utf8(name: &NSTDStr) -> NSTDWindowsOptionalSharedLib {
+    match nstd_os_windows_str_to_utf16(name) {
+        NSTDOptional::Some(utf16) => {
+            nstd_os_windows_shared_lib_load(nstd_vec_as_ptr(&utf16).cast())
+        }
+        NSTDOptional::None => NSTDOptional::None,
+    }
+}
+
 /// Returns a raw handle to a dynamically loaded library.
 ///
 /// # Parameters:
@@ -117,6 +190,45 @@ pub unsafe fn nstd_os_windows_shared_lib_get_mut(
     core::mem::transmute(GetProcAddress(lib.handle, symbol.cast()))
 }
 
+/// Returns a description of the calling thread's last error code, as set by a failed attempt to
+/// load a shared library or retrieve one of its symbols.
+///
+/// # Returns
+///
+/// `NSTDOptionalString error` - A human readable description of the last error, or an uninitialized
+/// "none" variant if the thread's last error code is not set.
+///
+/// # Safety
+///
+/// See
+/// <https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew>.
+#[nstdapi]
+pub unsafe fn nstd_os_windows_shared_lib_last_error() -> NSTDOptionalString<'static> {
+    /// The maximum number of UTF-16 code units that a description may contain.
+    const BUF_LEN: u32 = 512;
+    let errc = GetLastError();
+    if errc == 0 {
+        return NSTDOptional::None;
+    }
+    let mut buf = [0; BUF_LEN as _];
+    let len = FormatMessageW(
+        FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS,
+        core::ptr::null(),
+        errc,
+        0,
+        buf.as_mut_ptr(),
+        BUF_LEN,
+        core::ptr::null(),
+    );
+    match len {
+        0 => NSTDOptional::None,
+        len => {
+            let err = String::from_utf16_lossy(&buf[..len as _]);
+            NSTDOptional::Some(NSTDString::from_string(err.trim_end().into()))
+        }
+    }
+}
+
 /// Unloads and frees a dynamically loaded shared library.
 ///
 /// # Parameters:
@@ -2,11 +2,12 @@
 use crate::{
     alloc::CBox,
     core::{
-        optional::{gen_optional, NSTDOptional},
+        optional::{gen_optional, NSTDOptional, NSTDOptionalInt32},
         slice::NSTDSlice,
         str::NSTDStr,
     },
     io::NSTDIOError,
+    vec::NSTDVec,
     NSTDInt32, NSTDUInt32,
 };
 use nstdapi::nstdapi;
@@ -20,6 +21,43 @@ pub struct NSTDChildProcess {
 }
 gen_optional!(NSTDOptionalChildProcess, NSTDChildProcess);
 
+/// The output of a child process that has run to completion.
+#[nstdapi]
+pub struct NSTDProcessOutput {
+    /// The child process's exit code, or an uninitialized "none" variant if the process was
+    /// terminated by a signal.
+    pub exit_code: NSTDOptionalInt32,
+    /// The data that the child process wrote to its standard output stream.
+    pub stdout_bytes: NSTDVec<'static>,
+    /// The data that the child process wrote to its standard error stream.
+    pub stderr_bytes: NSTDVec<'static>,
+}
+gen_optional!(NSTDOptionalProcessOutput, NSTDProcessOutput);
+
+/// Creates a process command builder from a program path, arguments, and environment variables.
+///
+/// Returns [None] if either `args` or `vars` have an incorrect stride.
+///
+/// # Safety
+///
+/// All of `program`, `args`, and `vars` and their data must be valid for reads.
+unsafe fn command(program: &NSTDStr, args: &NSTDSlice, vars: &NSTDSlice) -> Option<Command> {
+    // Create the process command builder.
+    let mut cmd = Command::new(program.as_str());
+    let args = args.as_slice::<NSTDStr>()?;
+    let vars = vars.as_slice::<[NSTDStr; 2]>()?;
+    // Add the arguments.
+    cmd.args(args.iter().map(|arg| arg.as_str()));
+    // Add the environment variables.
+    cmd.envs(vars.iter().map(|vars| {
+        (
+            vars.get_unchecked(0).as_str(),
+            vars.get_unchecked(1).as_str(),
+        )
+    }));
+    Some(cmd)
+}
+
 /// Spawns a new child process with the name `program` and returns a handle to it.
 ///
 /// # Parameters:
@@ -34,7 +72,8 @@ gen_optional!(NSTDOptionalChildProcess, NSTDChildProcess);
 /// # Returns
 ///
 /// `NSTDOptionalChildProcess child` - A handle to the new child process on success, or an
-/// uninitialized "none" variant if spawning the child process fails.
+/// uninitialized "none" variant if spawning the child process fails or either `args` or `vars`
+/// have an incorrect stride.
 ///
 /// # Safety
 ///
@@ -46,30 +85,61 @@ pub unsafe fn nstd_proc_spawn(
     args: &NSTDSlice,
     vars: &NSTDSlice,
 ) -> NSTDOptionalChildProcess {
-    // Create the process command builder.
-    let mut cmd = Command::new(program.as_str());
-    if let Some(args) = args.as_slice::<NSTDStr>() {
-        if let Some(vars) = vars.as_slice::<[NSTDStr; 2]>() {
-            // Add the arguments.
-            cmd.args(args.iter().map(|arg| arg.as_str()));
-            // Add the environment variables.
-            cmd.envs(vars.iter().map(|vars| {
-                (
-                    vars.get_unchecked(0).as_str(),
-                    vars.get_unchecked(1).as_str(),
-                )
-            }));
-            // Spawn the process.
-            if let Ok(proc) = cmd.spawn() {
-                if let Some(proc) = CBox::new(proc) {
-                    return NSTDOptional::Some(NSTDChildProcess { proc });
-                }
+    if let Some(mut cmd) = command(program, args, vars) {
+        // Spawn the process.
+        if let Ok(proc) = cmd.spawn() {
+            if let Some(proc) = CBox::new(proc) {
+                return NSTDOptional::Some(NSTDChildProcess { proc });
             }
         }
     }
     NSTDOptional::None
 }
 
+/// Runs a child process to completion, capturing its standard output and error streams.
+///
+/// The child process's standard input stream is not inherited from the current process.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *program` - A path to the program to run as a child process.
+///
+/// - `const NSTDSlice *args` - A slice of `NSTDStr` arguments to pass to the program.
+///
+/// - `const NSTDSlice *vars` - A slice of `NSTDStr[2]` key/value environment variables to
+/// give to the program.
+///
+/// # Returns
+///
+/// `NSTDOptionalProcessOutput output` - The child process's output on success, or an
+/// uninitialized "none" variant if running the child process fails or either `args` or `vars`
+/// have an incorrect stride.
+///
+/// # Safety
+///
+/// The user must ensure that all of `program`, `args`, and `vars` and their data remain valid for
+/// reads while this function is executing.
+#[nstdapi]
+pub unsafe fn nstd_proc_output(
+    program: &NSTDStr,
+    args: &NSTDSlice,
+    vars: &NSTDSlice,
+) -> NSTDOptionalProcessOutput {
+    if let Some(mut cmd) = command(program, args, vars) {
+        if let Ok(output) = cmd.output() {
+            return NSTDOptional::Some(NSTDProcessOutput {
+                exit_code: output
+                    .status
+                    .code()
+                    .map_or(NSTDOptional::None, NSTDOptional::Some),
+                stdout_bytes: NSTDVec::from_vec(output.stdout),
+                stderr_bytes: NSTDVec::from_vec(output.stderr),
+            });
+        }
+    }
+    NSTDOptional::None
+}
+
 /// Returns the OS-assigned ID of a child process.
 ///
 /// # Parameters:
@@ -0,0 +1,542 @@
+//! A reader-writer lock useful for protecting shared data that is read far more often than it is
+//! written to.
+use crate::{
+    alloc::CBox,
+    core::{optional::NSTDOptional, result::NSTDResult},
+    heap_ptr::{
+        nstd_heap_ptr_drop, nstd_heap_ptr_get, nstd_heap_ptr_get_mut, NSTDHeapPtr,
+        NSTDOptionalHeapPtr,
+    },
+    NSTDAny, NSTDAnyMut, NSTDBool,
+};
+use nstdapi::nstdapi;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
+
+/// A reader-writer lock useful for protecting shared data.
+///
+/// This type allows any number of readers or at most one writer to access the protected data at
+/// any point in time.
+#[nstdapi]
+pub struct NSTDRwLock<'a> {
+    /// The Rust [`RwLock`].
+    lock: CBox<RwLock<NSTDHeapPtr<'a>>>,
+}
+
+/// Represents an optional value of type `NSTDRwLock`.
+pub type NSTDOptionalRwLock<'a> = NSTDOptional<NSTDRwLock<'a>>;
+
+/// A guard providing shared read access to a reader-writer lock's protected data.
+#[nstdapi]
+pub struct NSTDRwLockReadGuard<'l, 'a> {
+    /// The Rust [`RwLockReadGuard`].
+    guard: CBox<RwLockReadGuard<'l, NSTDHeapPtr<'a>>>,
+}
+
+/// A lock result returned from `nstd_rwlock_read` containing the read guard whether or not the
+/// data is poisoned.
+pub type NSTDRwLockReadResult<'l, 'a> =
+    NSTDResult<NSTDRwLockReadGuard<'l, 'a>, NSTDRwLockReadGuard<'l, 'a>>;
+
+/// An optional value of type `NSTDRwLockReadResult`.
+///
+/// This type is returned from `nstd_rwlock_try_read` where the uninitialized variant means that
+/// the function would block.
+pub type NSTDOptionalRwLockReadResult<'l, 'a> = NSTDOptional<NSTDRwLockReadResult<'l, 'a>>;
+
+/// A guard providing exclusive write access to a reader-writer lock's protected data.
+#[nstdapi]
+pub struct NSTDRwLockWriteGuard<'l, 'a> {
+    /// The Rust [`RwLockWriteGuard`].
+    guard: CBox<RwLockWriteGuard<'l, NSTDHeapPtr<'a>>>,
+}
+
+/// A lock result returned from `nstd_rwlock_write` containing the write guard whether or not the
+/// data is poisoned.
+pub type NSTDRwLockWriteResult<'l, 'a> =
+    NSTDResult<NSTDRwLockWriteGuard<'l, 'a>, NSTDRwLockWriteGuard<'l, 'a>>;
+
+/// An optional value of type `NSTDRwLockWriteResult`.
+///
+/// This type is returned from `nstd_rwlock_try_write` where the uninitialized variant means that
+/// the function would block.
+pub type NSTDOptionalRwLockWriteResult<'l, 'a> = NSTDOptional<NSTDRwLockWriteResult<'l, 'a>>;
+
+/// Creates a new reader-writer lock.
+///
+/// # Parameters:
+///
+/// - `NSTDHeapPtr data` - The data to protect.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLock lock` - The new reader-writer lock protecting `data` on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional, result::NSTDResult},
+///     heap_ptr::nstd_heap_ptr_new,
+///     rwlock::{nstd_rwlock_new, nstd_rwlock_read, nstd_rwlock_read_get},
+/// };
+///
+/// unsafe {
+///     let v = 10i32;
+///     let layout = nstd_core_alloc_layout_new(4, 4).unwrap();
+///     let data = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let lock = nstd_rwlock_new(data).unwrap();
+///     let NSTDOptional::Some(NSTDResult::Ok(guard)) = nstd_rwlock_read(&lock) else {
+///         panic!();
+///     };
+///     assert!(*nstd_rwlock_read_get(&guard).cast::<i32>() == 10);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_rwlock_new(data: NSTDHeapPtr<'_>) -> NSTDOptionalRwLock<'_> {
+    CBox::new(RwLock::new(data)).map_or(NSTDOptional::None, |lock| {
+        NSTDOptional::Some(NSTDRwLock { lock })
+    })
+}
+
+/// Determines whether or not a reader-writer lock's data is poisoned.
+///
+/// Reader-writer locks are poisoned when a thread that owns a write guard panics. This function is
+/// useful for those that configure `nstd` to unwind the stack instead of aborting on panic.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDBool is_poisoned` - A boolean value indicating whether or not `lock` is poisoned.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional, result::NSTDResult},
+///     heap_ptr::nstd_heap_ptr_new,
+///     rwlock::{nstd_rwlock_clear_poison, nstd_rwlock_is_poisoned, nstd_rwlock_new},
+/// };
+///
+/// unsafe {
+///     let v = 10i32;
+///     let layout = nstd_core_alloc_layout_new(4, 4).unwrap();
+///     let data = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let lock = nstd_rwlock_new(data).unwrap();
+///     assert!(!nstd_rwlock_is_poisoned(&lock));
+///     nstd_rwlock_clear_poison(&lock);
+///     assert!(!nstd_rwlock_is_poisoned(&lock));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_rwlock_is_poisoned(lock: &NSTDRwLock<'_>) -> NSTDBool {
+    lock.lock.is_poisoned()
+}
+
+/// Clears a reader-writer lock's poisoned state.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+#[inline]
+#[nstdapi]
+pub fn nstd_rwlock_clear_poison(lock: &NSTDRwLock<'_>) {
+    lock.lock.clear_poison();
+}
+
+/// Waits for shared read access to a reader-writer lock, returning a guard wrapping the protected
+/// data.
+///
+/// Attempting to call this function on a thread that already owns a write guard will either
+/// result in a panic or a deadlock.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockReadResult guard` - A handle to the lock's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation may panic if a write guard is already held by the current thread.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional, result::NSTDResult},
+///     heap_ptr::nstd_heap_ptr_new,
+///     rwlock::{
+///         nstd_rwlock_new, nstd_rwlock_read, nstd_rwlock_read_get, nstd_rwlock_read_unlock,
+///     },
+/// };
+///
+/// unsafe {
+///     let v = 10i32;
+///     let layout = nstd_core_alloc_layout_new(4, 4).unwrap();
+///     let data = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let lock = nstd_rwlock_new(data).unwrap();
+///     let NSTDOptional::Some(NSTDResult::Ok(first)) = nstd_rwlock_read(&lock) else {
+///         panic!();
+///     };
+///     // Any number of readers may hold the lock at once.
+///     let NSTDOptional::Some(NSTDResult::Ok(second)) = nstd_rwlock_read(&lock) else {
+///         panic!();
+///     };
+///     assert!(nstd_rwlock_read_get(&first) == nstd_rwlock_read_get(&second));
+///     nstd_rwlock_read_unlock(first);
+///     nstd_rwlock_read_unlock(second);
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_rwlock_read<'l, 'a>(lock: &'l NSTDRwLock<'a>) -> NSTDOptionalRwLockReadResult<'l, 'a> {
+    match lock.lock.read() {
+        Ok(guard) => CBox::new(guard).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Ok(NSTDRwLockReadGuard { guard }))
+        }),
+        Err(err) => CBox::new(err.into_inner()).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Err(NSTDRwLockReadGuard { guard }))
+        }),
+    }
+}
+
+/// The non-blocking variant of `nstd_rwlock_read` returning an uninitialized "none" result if the
+/// lock is held by a writer.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockReadResult guard` - A handle to the lock's protected data.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional, result::NSTDResult},
+///     heap_ptr::nstd_heap_ptr_new,
+///     rwlock::{
+///         nstd_rwlock_new, nstd_rwlock_try_read, nstd_rwlock_write, nstd_rwlock_write_unlock,
+///     },
+/// };
+///
+/// unsafe {
+///     let v = 10i32;
+///     let layout = nstd_core_alloc_layout_new(4, 4).unwrap();
+///     let data = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let lock = nstd_rwlock_new(data).unwrap();
+///     let NSTDOptional::Some(NSTDResult::Ok(guard)) = nstd_rwlock_write(&lock) else {
+///         panic!();
+///     };
+///     assert!(matches!(nstd_rwlock_try_read(&lock), NSTDOptional::None));
+///     nstd_rwlock_write_unlock(guard);
+///     assert!(matches!(
+///         nstd_rwlock_try_read(&lock),
+///         NSTDOptional::Some(NSTDResult::Ok(_))
+///     ));
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_rwlock_try_read<'l, 'a>(
+    lock: &'l NSTDRwLock<'a>,
+) -> NSTDOptionalRwLockReadResult<'l, 'a> {
+    match lock.lock.try_read() {
+        Ok(guard) => CBox::new(guard).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Ok(NSTDRwLockReadGuard { guard }))
+        }),
+        Err(err) => match err {
+            TryLockError::WouldBlock => NSTDOptional::None,
+            TryLockError::Poisoned(err) => CBox::new(err.into_inner())
+                .map_or(NSTDOptional::None, |guard| {
+                    NSTDOptional::Some(NSTDResult::Err(NSTDRwLockReadGuard { guard }))
+                }),
+        },
+    }
+}
+
+/// Waits for exclusive write access to a reader-writer lock, returning a guard wrapping the
+/// protected data.
+///
+/// Attempting to call this function on a thread that already owns a read or write guard will
+/// either result in a panic or a deadlock.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockWriteResult guard` - A handle to the lock's protected data on success, or an
+/// uninitialized "none" variant on error.
+///
+/// # Panics
+///
+/// This operation may panic if a guard is already held by the current thread.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional, result::NSTDResult},
+///     heap_ptr::nstd_heap_ptr_new,
+///     rwlock::{
+///         nstd_rwlock_new, nstd_rwlock_read, nstd_rwlock_read_get, nstd_rwlock_write,
+///         nstd_rwlock_write_get_mut, nstd_rwlock_write_unlock,
+///     },
+/// };
+///
+/// unsafe {
+///     let v = 10i32;
+///     let layout = nstd_core_alloc_layout_new(4, 4).unwrap();
+///     let data = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let lock = nstd_rwlock_new(data).unwrap();
+///     let NSTDOptional::Some(NSTDResult::Ok(mut guard)) = nstd_rwlock_write(&lock) else {
+///         panic!();
+///     };
+///     *nstd_rwlock_write_get_mut(&mut guard).cast::<i32>() = 20;
+///     nstd_rwlock_write_unlock(guard);
+///     let NSTDOptional::Some(NSTDResult::Ok(guard)) = nstd_rwlock_read(&lock) else {
+///         panic!();
+///     };
+///     assert!(*nstd_rwlock_read_get(&guard).cast::<i32>() == 20);
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_rwlock_write<'l, 'a>(
+    lock: &'l NSTDRwLock<'a>,
+) -> NSTDOptionalRwLockWriteResult<'l, 'a> {
+    match lock.lock.write() {
+        Ok(guard) => CBox::new(guard).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Ok(NSTDRwLockWriteGuard { guard }))
+        }),
+        Err(err) => CBox::new(err.into_inner()).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Err(NSTDRwLockWriteGuard { guard }))
+        }),
+    }
+}
+
+/// The non-blocking variant of `nstd_rwlock_write` returning an uninitialized "none" result if the
+/// lock is held by a reader or writer.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLock *lock` - The reader-writer lock.
+///
+/// # Returns
+///
+/// `NSTDOptionalRwLockWriteResult guard` - A handle to the lock's protected data.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional, result::NSTDResult},
+///     heap_ptr::nstd_heap_ptr_new,
+///     rwlock::{nstd_rwlock_new, nstd_rwlock_read, nstd_rwlock_read_unlock, nstd_rwlock_try_write},
+/// };
+///
+/// unsafe {
+///     let v = 10i32;
+///     let layout = nstd_core_alloc_layout_new(4, 4).unwrap();
+///     let data = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let lock = nstd_rwlock_new(data).unwrap();
+///     let NSTDOptional::Some(NSTDResult::Ok(guard)) = nstd_rwlock_read(&lock) else {
+///         panic!();
+///     };
+///     assert!(matches!(nstd_rwlock_try_write(&lock), NSTDOptional::None));
+///     nstd_rwlock_read_unlock(guard);
+///     assert!(matches!(
+///         nstd_rwlock_try_write(&lock),
+///         NSTDOptional::Some(NSTDResult::Ok(_))
+///     ));
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_rwlock_try_write<'l, 'a>(
+    lock: &'l NSTDRwLock<'a>,
+) -> NSTDOptionalRwLockWriteResult<'l, 'a> {
+    match lock.lock.try_write() {
+        Ok(guard) => CBox::new(guard).map_or(NSTDOptional::None, |guard| {
+            NSTDOptional::Some(NSTDResult::Ok(NSTDRwLockWriteGuard { guard }))
+        }),
+        Err(err) => match err {
+            TryLockError::WouldBlock => NSTDOptional::None,
+            TryLockError::Poisoned(err) => CBox::new(err.into_inner())
+                .map_or(NSTDOptional::None, |guard| {
+                    NSTDOptional::Some(NSTDResult::Err(NSTDRwLockWriteGuard { guard }))
+                }),
+        },
+    }
+}
+
+/// Returns a pointer to a reader-writer lock's raw data through a read guard.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLockReadGuard *guard` - A handle to the lock's protected data.
+///
+/// # Returns
+///
+/// `NSTDAny data` - A pointer to the lock's data.
+#[inline]
+#[nstdapi]
+pub fn nstd_rwlock_read_get(guard: &NSTDRwLockReadGuard<'_, '_>) -> NSTDAny {
+    nstd_heap_ptr_get(&guard.guard)
+}
+
+/// Returns a pointer to a reader-writer lock's raw data through a write guard.
+///
+/// # Parameters:
+///
+/// - `const NSTDRwLockWriteGuard *guard` - A handle to the lock's protected data.
+///
+/// # Returns
+///
+/// `NSTDAny data` - A pointer to the lock's data.
+#[inline]
+#[nstdapi]
+pub fn nstd_rwlock_write_get(guard: &NSTDRwLockWriteGuard<'_, '_>) -> NSTDAny {
+    nstd_heap_ptr_get(&guard.guard)
+}
+
+/// Returns a mutable pointer to a reader-writer lock's raw data through a write guard.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLockWriteGuard *guard` - A handle to the lock's protected data.
+///
+/// # Returns
+///
+/// `NSTDAnyMut data` - A mutable pointer to the lock's data.
+#[inline]
+#[nstdapi]
+pub fn nstd_rwlock_write_get_mut(guard: &mut NSTDRwLockWriteGuard<'_, '_>) -> NSTDAnyMut {
+    nstd_heap_ptr_get_mut(&mut guard.guard)
+}
+
+/// Consumes a reader-writer lock and returns the data it was protecting.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLock lock` - The reader-writer lock to take ownership of.
+///
+/// # Returns
+///
+/// `NSTDOptionalHeapPtr data` - Ownership of the lock's data, or an uninitialized "none" variant
+/// if the lock was poisoned.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional, result::NSTDResult},
+///     heap_ptr::{nstd_heap_ptr_get, nstd_heap_ptr_new},
+///     rwlock::{nstd_rwlock_into_inner, nstd_rwlock_new},
+/// };
+///
+/// unsafe {
+///     let v = 10i32;
+///     let layout = nstd_core_alloc_layout_new(4, 4).unwrap();
+///     let data = nstd_heap_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let lock = nstd_rwlock_new(data).unwrap();
+///     let NSTDOptional::Some(data) = nstd_rwlock_into_inner(lock) else {
+///         panic!();
+///     };
+///     assert!(*nstd_heap_ptr_get(&data).cast::<i32>() == 10);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_rwlock_into_inner(lock: NSTDRwLock<'_>) -> NSTDOptionalHeapPtr<'_> {
+    lock.lock
+        .into_inner()
+        .into_inner()
+        .map_or(NSTDOptional::None, NSTDOptional::Some)
+}
+
+/// Releases shared read access to a reader-writer lock by consuming a read guard.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLockReadGuard guard` - The read guard.
+#[inline]
+#[nstdapi]
+#[allow(
+    unused_variables,
+    clippy::missing_const_for_fn,
+    clippy::needless_pass_by_value
+)]
+pub fn nstd_rwlock_read_unlock(guard: NSTDRwLockReadGuard<'_, '_>) {}
+
+/// Releases exclusive write access to a reader-writer lock by consuming a write guard.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLockWriteGuard guard` - The write guard.
+#[inline]
+#[nstdapi]
+#[allow(
+    unused_variables,
+    clippy::missing_const_for_fn,
+    clippy::needless_pass_by_value
+)]
+pub fn nstd_rwlock_write_unlock(guard: NSTDRwLockWriteGuard<'_, '_>) {}
+
+/// Frees an instance of `NSTDRwLock`.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLock lock` - The reader-writer lock to free.
+#[inline]
+#[nstdapi]
+#[allow(
+    unused_variables,
+    clippy::missing_const_for_fn,
+    clippy::needless_pass_by_value
+)]
+pub fn nstd_rwlock_free(lock: NSTDRwLock<'_>) {}
+
+/// Frees an instance of `NSTDRwLock` after invoking `callback` with the lock's data.
+///
+/// `callback` will not be called if the lock is poisoned.
+///
+/// # Parameters:
+///
+/// - `NSTDRwLock lock` - The reader-writer lock to free.
+///
+/// - `void (*callback)(NSTDAnyMut)` - The lock data's destructor.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`callback`).
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_rwlock_drop(lock: NSTDRwLock<'_>, callback: unsafe extern "C" fn(NSTDAnyMut)) {
+    if let Ok(data) = lock.lock.into_inner().into_inner() {
+        nstd_heap_ptr_drop(data, callback);
+    }
+}
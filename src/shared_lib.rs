@@ -6,7 +6,8 @@
 #![cfg(any(unix, windows))]
 use crate::{
     core::{optional::NSTDOptional, str::NSTDStr},
-    NSTDAny, NSTDAnyMut, NSTDChar,
+    string::NSTDOptionalString,
+    NSTDAny, NSTDAnyMut, NSTDBool, NSTDChar, NSTDUInt8, NSTD_FALSE, NSTD_TRUE,
 };
 use cfg_if::cfg_if;
 use nstdapi::nstdapi;
@@ -20,8 +21,9 @@ cfg_if! {
                 str::nstd_core_str_as_cstr,
             },
             cstring::{nstd_cstring_as_ptr, nstd_cstring_from_cstr_unchecked},
+            os::unix::shared_lib::nstd_os_unix_shared_lib_last_error,
         };
-        use libc::{dlclose, dlopen, dlsym, RTLD_LAZY, RTLD_LOCAL};
+        use libc::{dlclose, dlerror, dlopen, dlsym, RTLD_LAZY, RTLD_LOCAL, RTLD_NOW};
 
         /// A handle to a dynamically loaded library.
         #[nstdapi]
@@ -42,15 +44,10 @@ cfg_if! {
         // SAFETY: `NSTDSharedLib` does not undergo interior mutability.
         unsafe impl Sync for NSTDSharedLib {}
     } else if #[cfg(windows)] {
-        use crate::{
-            os::windows::{
-                shared_lib::{
-                    nstd_os_windows_shared_lib_get, nstd_os_windows_shared_lib_get_mut,
-                    nstd_os_windows_shared_lib_load, NSTDWindowsSharedLib,
-                },
-                str::nstd_os_windows_str_to_utf16,
-            },
-            vec::nstd_vec_as_ptr,
+        use crate::os::windows::shared_lib::{
+            nstd_os_windows_shared_lib_get, nstd_os_windows_shared_lib_get_mut,
+            nstd_os_windows_shared_lib_handle, nstd_os_windows_shared_lib_last_error,
+            nstd_os_windows_shared_lib_load_utf8, NSTDWindowsSharedLib,
         };
 
         /// A handle to a dynamically loaded library.
@@ -58,6 +55,11 @@ cfg_if! {
     }
 }
 
+/// Resolves all of the library's undefined symbols upon loading instead of lazily.
+///
+/// This is the default (and only) behavior on Windows, where this flag has no effect.
+pub const NSTD_SHARED_LIB_NOW: NSTDUInt8 = 1;
+
 /// An optional handle to a shared library.
 ///
 /// This type is returned from `nstd_shared_lib_load`.
@@ -78,10 +80,44 @@ pub type NSTDOptionalSharedLib = NSTDOptional<NSTDSharedLib>;
 /// - `path`'s data must be valid for reads.
 ///
 /// - The loaded library may have platform-specific initialization routines ran when it is loaded.
+#[inline]
 #[nstdapi]
 pub unsafe fn nstd_shared_lib_load(path: &NSTDStr) -> NSTDOptionalSharedLib {
+    nstd_shared_lib_load_with_flags(path, 0)
+}
+
+/// Dynamically loads a shared library at runtime with a set of load flags.
+///
+/// On Windows `flags` is ignored, libraries are always loaded with `LoadLibraryW`'s default
+/// behavior.
+///
+/// # Parameters:
+///
+/// - `const NSTDStr *path` - A path to the shared library.
+///
+/// - `NSTDUInt8 flags` - A bit mask of `NSTD_SHARED_LIB_*` flags.
+///
+/// # Returns
+///
+/// `NSTDOptionalSharedLib lib` - A handle to the dynamically loaded library, or none on error.
+///
+/// # Safety
+///
+/// - `path`'s data must be valid for reads.
+///
+/// - The loaded library may have platform-specific initialization routines ran when it is loaded.
+#[nstdapi]
+#[cfg_attr(windows, allow(unused_variables))]
+pub unsafe fn nstd_shared_lib_load_with_flags(
+    path: &NSTDStr,
+    flags: NSTDUInt8,
+) -> NSTDOptionalSharedLib {
     #[cfg(unix)]
     {
+        let mode = match (flags & NSTD_SHARED_LIB_NOW) != 0 {
+            true => RTLD_NOW | RTLD_LOCAL,
+            false => RTLD_LAZY | RTLD_LOCAL,
+        };
         // Check if `path` is already null terminated.
         let path = nstd_core_str_as_cstr(path);
         if nstd_core_cstr_get_null(&path).is_null() {
@@ -89,7 +125,7 @@ pub unsafe fn nstd_shared_lib_load(path: &NSTDStr) -> NSTDOptionalSharedLib {
             if let NSTDOptional::Some(path) =
                 nstd_cstring_from_cstr_unchecked(&NSTD_ALLOCATOR, &path)
             {
-                let handle = dlopen(nstd_cstring_as_ptr(&path), RTLD_LAZY | RTLD_LOCAL);
+                let handle = dlopen(nstd_cstring_as_ptr(&path), mode);
                 if !handle.is_null() {
                     return NSTDOptional::Some(NSTDSharedLib { handle });
                 }
@@ -97,7 +133,7 @@ pub unsafe fn nstd_shared_lib_load(path: &NSTDStr) -> NSTDOptionalSharedLib {
             NSTDOptional::None
         } else {
             // Use the already null terminated `path`.
-            let handle = dlopen(nstd_core_cstr_as_ptr(&path), RTLD_LAZY | RTLD_LOCAL);
+            let handle = dlopen(nstd_core_cstr_as_ptr(&path), mode);
             match !handle.is_null() {
                 true => NSTDOptional::Some(NSTDSharedLib { handle }),
                 false => NSTDOptional::None,
@@ -105,12 +141,29 @@ pub unsafe fn nstd_shared_lib_load(path: &NSTDStr) -> NSTDOptionalSharedLib {
         }
     }
     #[cfg(windows)]
-    {
-        let NSTDOptional::Some(utf16) = nstd_os_windows_str_to_utf16(path) else {
-            return NSTDOptional::None;
-        };
-        nstd_os_windows_shared_lib_load(nstd_vec_as_ptr(&utf16).cast())
-    }
+    return nstd_os_windows_shared_lib_load_utf8(path);
+}
+
+/// Returns a raw native handle to a dynamically loaded library.
+///
+/// On Unix systems this is the handle returned from `dlopen`, and on Windows this is the module's
+/// `HMODULE`.
+///
+/// # Parameters:
+///
+/// - `const NSTDSharedLib *lib` - The loaded library.
+///
+/// # Returns
+///
+/// `NSTDAnyMut handle` - A raw handle to the dynamically loaded library.
+#[inline]
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn)]
+pub fn nstd_shared_lib_handle(lib: &NSTDSharedLib) -> NSTDAnyMut {
+    #[cfg(unix)]
+    return lib.handle;
+    #[cfg(windows)]
+    return nstd_os_windows_shared_lib_handle(lib) as _;
 }
 
 /// Gets a pointer to a function or static variable in a dynamically loaded library by symbol name.
@@ -165,6 +218,104 @@ pub unsafe fn nstd_shared_lib_get_mut(
     return nstd_os_windows_shared_lib_get_mut(lib, symbol);
 }
 
+/// Gets a pointer to a function or static variable in a dynamically loaded library by symbol name,
+/// reporting whether or not the symbol was found.
+///
+/// Unlike `nstd_shared_lib_get`, this function can distinguish a missing symbol from a symbol
+/// whose address is null.
+///
+/// # Parameters
+///
+/// - `const NSTDSharedLib *lib` - The loaded library.
+///
+/// - `const NSTDChar *symbol` - The name of the function or variable to get a pointer to.
+///
+/// - `NSTDAny *ptr` - Receives the pointer to the function or variable on success.
+///
+/// # Returns
+///
+/// `NSTDBool found` - `NSTD_TRUE` if the symbol was found, or `NSTD_FALSE` if it wasn't, in which
+/// case `ptr` is left unchanged.
+///
+/// # Safety
+///
+/// - Undefined behavior may occur if `symbol`'s data is invalid.
+///
+/// - On Unix systems this function may not be thread safe, see
+/// <https://man7.org/linux/man-pages/man3/dlerror.3.html>.
+#[nstdapi]
+pub unsafe fn nstd_shared_lib_get_checked(
+    lib: &NSTDSharedLib,
+    symbol: *const NSTDChar,
+    ptr: &mut NSTDAny,
+) -> NSTDBool {
+    #[cfg(unix)]
+    {
+        // Clear any previous error so that a null symbol can be told apart from a missing one.
+        dlerror();
+        let sym = dlsym(lib.handle, symbol);
+        if sym.is_null() && !dlerror().is_null() {
+            return NSTD_FALSE;
+        }
+        *ptr = sym;
+        NSTD_TRUE
+    }
+    #[cfg(windows)]
+    {
+        // `GetProcAddress` only returns null on failure.
+        let sym = nstd_os_windows_shared_lib_get(lib, symbol);
+        if sym.is_null() {
+            return NSTD_FALSE;
+        }
+        *ptr = sym;
+        NSTD_TRUE
+    }
+}
+
+/// Returns a description of the most recent error that occurred while loading a shared library or
+/// retrieving one of its symbols.
+///
+/// On Unix systems calling this function clears the stored error, so subsequent calls will return
+/// "none" until another error occurs.
+///
+/// Only errors reported by the platform's library loader are described. If `nstd_shared_lib_load`
+/// fails before the loader is called, such as when allocating a null terminated copy of its path
+/// fails, the returned description may be stale or "none".
+///
+/// # Returns
+///
+/// `NSTDOptionalString error` - A human readable description of the last error, or an uninitialized
+/// "none" variant if there is no error to report.
+///
+/// # Safety
+///
+/// On Unix systems this function may not be thread safe, see
+/// <https://man7.org/linux/man-pages/man3/dlerror.3.html>.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::{optional::NSTDOptional, str::nstd_core_str_from_raw_cstr},
+///     shared_lib::{nstd_shared_lib_last_error, nstd_shared_lib_load},
+/// };
+///
+/// unsafe {
+///     let path = nstd_core_str_from_raw_cstr("not_a_library\0".as_ptr().cast()).unwrap();
+///     assert!(matches!(nstd_shared_lib_load(&path), NSTDOptional::None));
+///     assert!(matches!(nstd_shared_lib_last_error(), NSTDOptional::Some(_)));
+///     #[cfg(unix)]
+///     assert!(matches!(nstd_shared_lib_last_error(), NSTDOptional::None));
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_shared_lib_last_error() -> NSTDOptionalString<'static> {
+    #[cfg(unix)]
+    return nstd_os_unix_shared_lib_last_error();
+    #[cfg(windows)]
+    return nstd_os_windows_shared_lib_last_error();
+}
+
 /// Unloads and frees the resources of a dynamically loaded library.
 ///
 /// # Parameters:
@@ -10,11 +10,53 @@ use crate::{
     },
     NSTDAny, NSTDAnyMut, NSTDUInt,
 };
+use core::sync::atomic::{fence, AtomicUsize, Ordering};
 use nstdapi::nstdapi;
 
 /// The size (in bytes) of [usize].
 const USIZE_SIZE: usize = core::mem::size_of::<usize>();
 
+/// The size (in bytes) of a shared object's control block.
+///
+/// The control block is stored directly after the shared object and is made up of the number of
+/// shared (strong) pointers followed by the number of weak pointers.
+#[allow(clippy::arithmetic_side_effects)]
+const CONTROL_SIZE: usize = USIZE_SIZE * 2;
+
+/// Returns a pointer to the number of shared pointers in a shared object's control block.
+///
+/// # Note
+///
+/// The returned pointer may be unaligned, so reading/writing must be done with
+/// [`core::ptr::read_unaligned`] and [`core::ptr::write_unaligned`].
+///
+/// # Safety
+///
+/// `ptr` and `layout` must describe a live shared object's memory buffer.
+#[inline]
+#[allow(clippy::arithmetic_side_effects)]
+const unsafe fn strong_ptrs(ptr: NSTDAnyMut, layout: NSTDAllocLayout) -> *mut usize {
+    ptr.add(nstd_core_alloc_layout_size(layout) - CONTROL_SIZE)
+        .cast()
+}
+
+/// Returns a pointer to the number of weak pointers in a shared object's control block.
+///
+/// # Note
+///
+/// The returned pointer may be unaligned, so reading/writing must be done with
+/// [`core::ptr::read_unaligned`] and [`core::ptr::write_unaligned`].
+///
+/// # Safety
+///
+/// `ptr` and `layout` must describe a live shared object's memory buffer.
+#[inline]
+#[allow(clippy::arithmetic_side_effects)]
+const unsafe fn weak_ptrs(ptr: NSTDAnyMut, layout: NSTDAllocLayout) -> *mut usize {
+    ptr.add(nstd_core_alloc_layout_size(layout) - USIZE_SIZE)
+        .cast()
+}
+
 /// A reference counting smart pointer.
 #[nstdapi]
 pub struct NSTDSharedPtr<'a> {
@@ -30,10 +72,8 @@ impl NSTDSharedPtr<'_> {
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
     fn ptrs(&self) -> usize {
-        // SAFETY:
-        // - Shared pointers are always non-null.
-        // - Shared pointers never allocate more than `isize::MAX` bytes for their value.
-        unsafe { core::ptr::read_unaligned(self.ptr.add(nstd_shared_ptr_size(self)).cast()) }
+        // SAFETY: Shared pointers are always non-null.
+        unsafe { core::ptr::read_unaligned(self.ptrs_mut()) }
     }
 
     /// Returns a mutable pointer to the number of pointers sharing the object.
@@ -45,10 +85,8 @@ impl NSTDSharedPtr<'_> {
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
     fn ptrs_mut(&self) -> *mut usize {
-        // SAFETY:
-        // - Shared pointers are always non-null.
-        // - Shared pointers never allocate more than `isize::MAX` bytes for their value.
-        unsafe { self.ptr.add(nstd_shared_ptr_size(self)).cast() }
+        // SAFETY: Shared pointers are always non-null.
+        unsafe { strong_ptrs(self.ptr, self.layout) }
     }
 }
 impl Drop for NSTDSharedPtr<'_> {
@@ -61,8 +99,39 @@ impl Drop for NSTDSharedPtr<'_> {
             #[allow(clippy::arithmetic_side_effects)]
             let new_size = self.ptrs() - 1;
             core::ptr::write_unaligned(ptrs, new_size);
-            // If the pointer count is zero, free the data.
-            if new_size == 0 {
+            // If there are no shared or weak pointers left, free the data.
+            if new_size == 0 && core::ptr::read_unaligned(weak_ptrs(self.ptr, self.layout)) == 0 {
+                (self.allocator.deallocate)(self.allocator.state, self.ptr, self.layout);
+            }
+        }
+    }
+}
+
+/// A non-owning reference to a shared object.
+///
+/// Weak pointers do not keep the shared object alive, they must be upgraded into an
+/// `NSTDSharedPtr` before the object can be accessed.
+#[nstdapi]
+pub struct NSTDWeakPtr<'a> {
+    /// The memory allocator.
+    allocator: &'a NSTDAllocator,
+    /// A raw pointer to private data about the shared object.
+    ptr: NSTDAnyMut,
+    /// The shared object's memory layout.
+    layout: NSTDAllocLayout,
+}
+impl Drop for NSTDWeakPtr<'_> {
+    /// [`NSTDWeakPtr`]'s destructor.
+    fn drop(&mut self) {
+        // SAFETY: The shared object's memory buffer lives for as long as any weak pointers do.
+        unsafe {
+            // Update the weak pointer count.
+            let weak = weak_ptrs(self.ptr, self.layout);
+            #[allow(clippy::arithmetic_side_effects)]
+            let new_size = core::ptr::read_unaligned(weak) - 1;
+            core::ptr::write_unaligned(weak, new_size);
+            // If there are no shared or weak pointers left, free the data.
+            if new_size == 0 && core::ptr::read_unaligned(strong_ptrs(self.ptr, self.layout)) == 0 {
                 (self.allocator.deallocate)(self.allocator.state, self.ptr, self.layout);
             }
         }
@@ -116,18 +185,18 @@ pub unsafe fn nstd_shared_ptr_new(
     layout: NSTDAllocLayout,
     init: NSTDAny,
 ) -> NSTDOptionalSharedPtr<'_> {
-    // Allocate a region of memory for the object and the pointer count.
+    // Allocate a region of memory for the object and the control block.
     let size = nstd_core_alloc_layout_size(layout);
-    if let Some(buffer_size) = size.checked_add(USIZE_SIZE) {
+    if let Some(buffer_size) = size.checked_add(CONTROL_SIZE) {
         let align = nstd_core_alloc_layout_align(layout);
         if let NSTDOptional::Some(layout) = nstd_core_alloc_layout_new(buffer_size, align) {
             let ptr = (allocator.allocate)(allocator.state, layout);
             if !ptr.is_null() {
                 // Initialize the shared object.
                 nstd_core_mem_copy(ptr.cast(), init.cast(), size);
-                // Set the pointer count to one.
-                let ptrs = ptr.add(size).cast::<usize>();
-                core::ptr::write_unaligned(ptrs, 1);
+                // Set the pointer count to one and the weak pointer count to zero.
+                core::ptr::write_unaligned(strong_ptrs(ptr, layout), 1);
+                core::ptr::write_unaligned(weak_ptrs(ptr, layout), 0);
                 // Construct the pointer.
                 return NSTDOptional::Some(NSTDSharedPtr {
                     allocator,
@@ -180,16 +249,15 @@ pub unsafe fn nstd_shared_ptr_new_zeroed(
     allocator: &NSTDAllocator,
     layout: NSTDAllocLayout,
 ) -> NSTDOptionalSharedPtr<'_> {
-    // Allocate a region of memory for the object and the pointer count.
+    // Allocate a region of memory for the object and the control block.
     let size = nstd_core_alloc_layout_size(layout);
-    if let Some(buffer_size) = size.checked_add(USIZE_SIZE) {
+    if let Some(buffer_size) = size.checked_add(CONTROL_SIZE) {
         let align = nstd_core_alloc_layout_align(layout);
         if let NSTDOptional::Some(layout) = nstd_core_alloc_layout_new(buffer_size, align) {
             let ptr = (allocator.allocate_zeroed)(allocator.state, layout);
             if !ptr.is_null() {
-                // Set the pointer count to one.
-                let ptrs = ptr.add(size).cast::<usize>();
-                core::ptr::write_unaligned(ptrs, 1);
+                // Set the pointer count to one, the weak pointer count is already zero.
+                core::ptr::write_unaligned(strong_ptrs(ptr, layout), 1);
                 // Construct the pointer.
                 return NSTDOptional::Some(NSTDSharedPtr {
                     allocator,
@@ -244,7 +312,7 @@ pub fn nstd_shared_ptr_share<'a>(shared_ptr: &NSTDSharedPtr<'a>) -> NSTDSharedPt
         // Update the pointer count.
         let ptrs = shared_ptr.ptrs_mut();
         #[allow(clippy::arithmetic_side_effects)]
-        core::ptr::write_unaligned(ptrs, *ptrs + 1);
+        core::ptr::write_unaligned(ptrs, shared_ptr.ptrs() + 1);
         // Construct the new shared pointer instance.
         NSTDSharedPtr {
             allocator: shared_ptr.allocator,
@@ -351,7 +419,7 @@ pub fn nstd_shared_ptr_owners(shared_ptr: &NSTDSharedPtr<'_>) -> NSTDUInt {
 #[nstdapi]
 #[allow(clippy::arithmetic_side_effects)]
 pub const fn nstd_shared_ptr_size(shared_ptr: &NSTDSharedPtr<'_>) -> NSTDUInt {
-    nstd_core_alloc_layout_size(shared_ptr.layout) - USIZE_SIZE
+    nstd_core_alloc_layout_size(shared_ptr.layout) - CONTROL_SIZE
 }
 
 /// Returns an immutable raw pointer to the shared object.
@@ -389,6 +457,110 @@ pub const fn nstd_shared_ptr_get(shared_ptr: &NSTDSharedPtr<'_>) -> NSTDAny {
     shared_ptr.ptr
 }
 
+/// Creates a new weak pointer to `shared_ptr`'s data.
+///
+/// # Parameters:
+///
+/// - `const NSTDSharedPtr *shared_ptr` - The shared object to create a weak pointer to.
+///
+/// # Returns
+///
+/// `NSTDWeakPtr weak` - A new weak pointer to the shared data.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::nstd_core_alloc_layout_new, optional::NSTDOptional},
+///     shared_ptr::{
+///         nstd_shared_ptr_downgrade, nstd_shared_ptr_get, nstd_shared_ptr_new,
+///         nstd_shared_ptr_owners, nstd_shared_ptr_weak_upgrade,
+///     },
+/// };
+///
+/// unsafe {
+///     let v = 9u32;
+///     let size = core::mem::size_of::<u32>();
+///     let align = core::mem::align_of::<u32>();
+///     let layout = nstd_core_alloc_layout_new(size, align).unwrap();
+///     let shared_ptr = nstd_shared_ptr_new(&NSTD_ALLOCATOR, layout, addr_of!(v).cast()).unwrap();
+///     let weak = nstd_shared_ptr_downgrade(&shared_ptr);
+///     assert!(nstd_shared_ptr_owners(&shared_ptr) == 1);
+///     {
+///         let upgraded = nstd_shared_ptr_weak_upgrade(&weak).unwrap();
+///         assert!(nstd_shared_ptr_owners(&upgraded) == 2);
+///         assert!(*nstd_shared_ptr_get(&upgraded).cast::<u32>() == v);
+///     }
+///     drop(shared_ptr);
+///     assert!(matches!(nstd_shared_ptr_weak_upgrade(&weak), NSTDOptional::None));
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn)]
+pub fn nstd_shared_ptr_downgrade<'a>(shared_ptr: &NSTDSharedPtr<'a>) -> NSTDWeakPtr<'a> {
+    // SAFETY: Shared pointers are always non-null.
+    unsafe {
+        // Update the weak pointer count.
+        let weak = weak_ptrs(shared_ptr.ptr, shared_ptr.layout);
+        #[allow(clippy::arithmetic_side_effects)]
+        core::ptr::write_unaligned(weak, core::ptr::read_unaligned(weak) + 1);
+    }
+    NSTDWeakPtr {
+        allocator: shared_ptr.allocator,
+        ptr: shared_ptr.ptr,
+        layout: shared_ptr.layout,
+    }
+}
+
+/// Attempts to create a new shared pointer from a weak pointer.
+///
+/// # Parameters:
+///
+/// - `const NSTDWeakPtr *weak` - The weak pointer.
+///
+/// # Returns
+///
+/// `NSTDOptionalSharedPtr shared_ptr` - A new pointer to the shared data, or an uninitialized
+/// "none" variant if the shared object has already been freed.
+#[inline]
+#[nstdapi]
+#[allow(clippy::missing_const_for_fn)]
+pub fn nstd_shared_ptr_weak_upgrade<'a>(weak: &NSTDWeakPtr<'a>) -> NSTDOptionalSharedPtr<'a> {
+    // SAFETY: The shared object's memory buffer lives for as long as any weak pointers do.
+    unsafe {
+        let ptrs = strong_ptrs(weak.ptr, weak.layout);
+        match core::ptr::read_unaligned(ptrs) {
+            0 => NSTDOptional::None,
+            #[allow(clippy::arithmetic_side_effects)]
+            count => {
+                core::ptr::write_unaligned(ptrs, count + 1);
+                NSTDOptional::Some(NSTDSharedPtr {
+                    allocator: weak.allocator,
+                    ptr: weak.ptr,
+                    layout: weak.layout,
+                })
+            }
+        }
+    }
+}
+
+/// Frees an instance of `NSTDWeakPtr`.
+///
+/// # Parameters:
+///
+/// - `NSTDWeakPtr weak` - The weak pointer to free.
+#[inline]
+#[nstdapi]
+#[allow(
+    unused_variables,
+    clippy::missing_const_for_fn,
+    clippy::needless_pass_by_value
+)]
+pub fn nstd_shared_ptr_weak_free(weak: NSTDWeakPtr<'_>) {}
+
 /// Frees an instance of `NSTDSharedPtr`.
 ///
 /// # Parameters:
@@ -423,3 +595,330 @@ pub unsafe fn nstd_shared_ptr_drop(
 ) {
     callback(shared_ptr.ptr);
 }
+
+/// The alignment (in bytes) of [`AtomicUsize`].
+const ATOMIC_USIZE_ALIGN: usize = core::mem::align_of::<AtomicUsize>();
+
+/// Returns the offset of an atomic shared object's pointer count along with the memory layout of
+/// the buffer holding both the object and the pointer count.
+///
+/// Returns [`None`] if the buffer's size would overflow.
+fn atomic_buffer_layout(layout: NSTDAllocLayout) -> Option<(NSTDUInt, NSTDAllocLayout)> {
+    let size = nstd_core_alloc_layout_size(layout);
+    let offset = size.checked_next_multiple_of(ATOMIC_USIZE_ALIGN)?;
+    let buffer_size = offset.checked_add(USIZE_SIZE)?;
+    let align = nstd_core_alloc_layout_align(layout).max(ATOMIC_USIZE_ALIGN);
+    match nstd_core_alloc_layout_new(buffer_size, align) {
+        NSTDOptional::Some(buffer_layout) => Some((offset, buffer_layout)),
+        NSTDOptional::None => None,
+    }
+}
+
+/// A thread-safe reference counting smart pointer.
+///
+/// Unlike `NSTDSharedPtr`, this type's reference count is updated atomically, allowing it to be
+/// shared between threads.
+#[nstdapi]
+pub struct NSTDAtomicSharedPtr<'a> {
+    /// The memory allocator.
+    allocator: &'a NSTDAllocator,
+    /// A raw pointer to private data about the shared object.
+    ptr: NSTDAnyMut,
+    /// The shared object's memory layout.
+    layout: NSTDAllocLayout,
+}
+impl NSTDAtomicSharedPtr<'_> {
+    /// Returns a reference to the number of pointers sharing the object.
+    #[inline]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn ptrs(&self) -> &AtomicUsize {
+        let size = nstd_core_alloc_layout_size(self.layout);
+        let offset = size.next_multiple_of(ATOMIC_USIZE_ALIGN);
+        // SAFETY: Atomic shared pointers are always non-null, the reference count is always
+        // properly aligned.
+        unsafe { &*self.ptr.add(offset).cast() }
+    }
+}
+impl Drop for NSTDAtomicSharedPtr<'_> {
+    /// [`NSTDAtomicSharedPtr`]'s destructor.
+    fn drop(&mut self) {
+        // Update the pointer count, if there are no pointers left, free the data.
+        if self.ptrs().fetch_sub(1, Ordering::Release) == 1 {
+            fence(Ordering::Acquire);
+            if let Some((_, layout)) = atomic_buffer_layout(self.layout) {
+                // SAFETY: This is the last pointer to the shared object.
+                unsafe { (self.allocator.deallocate)(self.allocator.state, self.ptr, layout) };
+            }
+        }
+    }
+}
+/// # Safety
+///
+/// The data that the shared pointer holds must be able to be safely sent between threads.
+// SAFETY: The user guarantees that the data is thread-safe.
+unsafe impl Send for NSTDAtomicSharedPtr<'_> {}
+/// # Safety
+///
+/// The data that the shared pointer holds must be able to be safely shared between threads.
+// SAFETY: The user guarantees that the data is thread-safe.
+unsafe impl Sync for NSTDAtomicSharedPtr<'_> {}
+
+/// Represents an optional value of type `NSTDAtomicSharedPtr`.
+pub type NSTDOptionalAtomicSharedPtr<'a> = NSTDOptional<NSTDAtomicSharedPtr<'a>>;
+
+/// Creates a new initialized instance of a thread-safe shared pointer.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDAllocLayout layout` - The shared object's memory layout.
+///
+/// - `NSTDAny init` - A pointer to the object to initialize the shared pointer with.
+///
+/// # Returns
+///
+/// `NSTDOptionalAtomicSharedPtr shared_ptr` - The new shared pointer, or an uninitialized "none"
+/// variant if allocating fails.
+///
+/// # Safety
+///
+/// `init` must be a pointer to a value that is valid for reads based on `layout`.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::alloc::nstd_core_alloc_layout_new,
+///     shared_ptr::{nstd_shared_ptr_atomic_get, nstd_shared_ptr_new_atomic},
+/// };
+///
+/// unsafe {
+///     let v = u8::MAX;
+///     let size = core::mem::size_of::<u8>();
+///     let align = core::mem::align_of::<u8>();
+///     let layout = nstd_core_alloc_layout_new(size, align).unwrap();
+///     let addr = addr_of!(v).cast();
+///     let shared_ptr = nstd_shared_ptr_new_atomic(&NSTD_ALLOCATOR, layout, addr).unwrap();
+///     assert!(*nstd_shared_ptr_atomic_get(&shared_ptr).cast::<u8>() == v);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_shared_ptr_new_atomic(
+    allocator: &NSTDAllocator,
+    layout: NSTDAllocLayout,
+    init: NSTDAny,
+) -> NSTDOptionalAtomicSharedPtr<'_> {
+    // Allocate a region of memory for the object and a properly aligned pointer count.
+    if let Some((offset, buffer_layout)) = atomic_buffer_layout(layout) {
+        let ptr = (allocator.allocate)(allocator.state, buffer_layout);
+        if !ptr.is_null() {
+            // Initialize the shared object.
+            nstd_core_mem_copy(ptr.cast(), init.cast(), nstd_core_alloc_layout_size(layout));
+            // Set the pointer count to one.
+            ptr.add(offset)
+                .cast::<AtomicUsize>()
+                .write(AtomicUsize::new(1));
+            // Construct the pointer.
+            return NSTDOptional::Some(NSTDAtomicSharedPtr {
+                allocator,
+                ptr,
+                layout,
+            });
+        }
+    }
+    NSTDOptional::None
+}
+
+/// Creates a new zero-initialized instance of a thread-safe shared pointer.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDAllocLayout layout` - The shared object's memory layout.
+///
+/// # Returns
+///
+/// `NSTDOptionalAtomicSharedPtr shared_ptr` - The yet to be shared pointer, or an uninitialized
+/// "none" variant if allocating fails.
+///
+/// # Safety
+///
+/// The data to be stored in the shared pointer must be safely representable by an all-zero byte
+/// pattern.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::alloc::nstd_core_alloc_layout_new,
+///     shared_ptr::{nstd_shared_ptr_atomic_get, nstd_shared_ptr_new_atomic_zeroed},
+/// };
+///
+/// unsafe {
+///     let size = core::mem::size_of::<u128>();
+///     let align = core::mem::align_of::<u128>();
+///     let layout = nstd_core_alloc_layout_new(size, align).unwrap();
+///     let shared_ptr = nstd_shared_ptr_new_atomic_zeroed(&NSTD_ALLOCATOR, layout).unwrap();
+///     assert!(*nstd_shared_ptr_atomic_get(&shared_ptr).cast::<u128>() == 0);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_shared_ptr_new_atomic_zeroed(
+    allocator: &NSTDAllocator,
+    layout: NSTDAllocLayout,
+) -> NSTDOptionalAtomicSharedPtr<'_> {
+    // Allocate a region of memory for the object and a properly aligned pointer count.
+    if let Some((offset, buffer_layout)) = atomic_buffer_layout(layout) {
+        let ptr = (allocator.allocate_zeroed)(allocator.state, buffer_layout);
+        if !ptr.is_null() {
+            // Set the pointer count to one.
+            ptr.add(offset)
+                .cast::<AtomicUsize>()
+                .write(AtomicUsize::new(1));
+            // Construct the pointer.
+            return NSTDOptional::Some(NSTDAtomicSharedPtr {
+                allocator,
+                ptr,
+                layout,
+            });
+        }
+    }
+    NSTDOptional::None
+}
+
+/// Shares `shared_ptr`.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - The shared object to share.
+///
+/// # Returns
+///
+/// `NSTDAtomicSharedPtr shared` - A new pointer pointing to the shared data.
+///
+/// # Example
+///
+/// ```
+/// use core::ptr::addr_of;
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::alloc::nstd_core_alloc_layout_new,
+///     shared_ptr::{
+///         nstd_shared_ptr_atomic_get, nstd_shared_ptr_atomic_owners,
+///         nstd_shared_ptr_atomic_share, nstd_shared_ptr_new_atomic,
+///     },
+/// };
+///
+/// unsafe {
+///     let v = 1024u64;
+///     let size = core::mem::size_of::<u64>();
+///     let align = core::mem::align_of::<u64>();
+///     let layout = nstd_core_alloc_layout_new(size, align).unwrap();
+///     let addr = addr_of!(v).cast();
+///     let shared_ptr = nstd_shared_ptr_new_atomic(&NSTD_ALLOCATOR, layout, addr).unwrap();
+///     let threads: Vec<_> = (0..4)
+///         .map(|_| {
+///             let share = nstd_shared_ptr_atomic_share(&shared_ptr);
+///             std::thread::spawn(move || *nstd_shared_ptr_atomic_get(&share).cast::<u64>())
+///         })
+///         .collect();
+///     for thread in threads {
+///         assert!(thread.join().unwrap() == v);
+///     }
+///     assert!(nstd_shared_ptr_atomic_owners(&shared_ptr) == 1);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_shared_ptr_atomic_share<'a>(
+    shared_ptr: &NSTDAtomicSharedPtr<'a>,
+) -> NSTDAtomicSharedPtr<'a> {
+    // Update the pointer count.
+    shared_ptr.ptrs().fetch_add(1, Ordering::Relaxed);
+    // Construct the new shared pointer instance.
+    NSTDAtomicSharedPtr {
+        allocator: shared_ptr.allocator,
+        ptr: shared_ptr.ptr,
+        layout: shared_ptr.layout,
+    }
+}
+
+/// Returns the number of pointers that share `shared_ptr`'s data.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - An instance of a shared pointer.
+///
+/// # Returns
+///
+/// `NSTDUInt owners` - The number of pointers that share `shared_ptr`'s data.
+#[inline]
+#[nstdapi]
+pub fn nstd_shared_ptr_atomic_owners(shared_ptr: &NSTDAtomicSharedPtr<'_>) -> NSTDUInt {
+    shared_ptr.ptrs().load(Ordering::Acquire)
+}
+
+/// Returns the size of the shared object.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - The shared pointer.
+///
+/// # Returns
+///
+/// `NSTDUInt size` - The size of the shared object.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::alloc::nstd_core_alloc_layout_new,
+///     shared_ptr::{nstd_shared_ptr_atomic_size, nstd_shared_ptr_new_atomic_zeroed},
+/// };
+///
+/// unsafe {
+///     let layout = nstd_core_alloc_layout_new(3, 1).unwrap();
+///     let shared_ptr = nstd_shared_ptr_new_atomic_zeroed(&NSTD_ALLOCATOR, layout).unwrap();
+///     assert!(nstd_shared_ptr_atomic_size(&shared_ptr) == 3);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_shared_ptr_atomic_size(shared_ptr: &NSTDAtomicSharedPtr<'_>) -> NSTDUInt {
+    nstd_core_alloc_layout_size(shared_ptr.layout)
+}
+
+/// Returns an immutable raw pointer to the shared object.
+///
+/// # Parameters:
+///
+/// - `const NSTDAtomicSharedPtr *shared_ptr` - The shared pointer.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A raw pointer to the shared object.
+#[inline]
+#[nstdapi]
+pub const fn nstd_shared_ptr_atomic_get(shared_ptr: &NSTDAtomicSharedPtr<'_>) -> NSTDAny {
+    shared_ptr.ptr
+}
+
+/// Frees an instance of `NSTDAtomicSharedPtr`.
+///
+/// # Parameters:
+///
+/// - `NSTDAtomicSharedPtr shared_ptr` - The shared object to free.
+#[inline]
+#[nstdapi]
+#[allow(
+    unused_variables,
+    clippy::missing_const_for_fn,
+    clippy::needless_pass_by_value
+)]
+pub fn nstd_shared_ptr_atomic_free(shared_ptr: NSTDAtomicSharedPtr<'_>) {}
@@ -2,20 +2,23 @@
 extern crate alloc;
 use crate::{
     core::{
-        alloc::{NSTDAllocError, NSTDAllocator},
+        alloc::{NSTDAllocError, NSTDAllocator, NSTDOptionalAllocError},
+        cstr::NSTDCStr,
         def::NSTDByte,
+        mem::nstd_core_mem_copy_overlapping,
         optional::NSTDOptional,
         slice::{nstd_core_slice_new_unchecked, NSTDSlice},
         str::{
-            nstd_core_str_as_bytes, nstd_core_str_from_bytes_unchecked, nstd_core_str_len,
-            nstd_core_str_mut_from_bytes_unchecked, NSTDStr, NSTDStrMut,
+            nstd_core_str_as_bytes, nstd_core_str_from_bytes, nstd_core_str_from_bytes_unchecked,
+            nstd_core_str_len, nstd_core_str_mut_from_bytes_unchecked, NSTDStr, NSTDStrMut,
         },
         unichar::{NSTDOptionalUnichar, NSTDUnichar},
     },
     vec::{
-        nstd_vec_allocator, nstd_vec_as_ptr, nstd_vec_as_slice, nstd_vec_as_slice_mut,
-        nstd_vec_cap, nstd_vec_clear, nstd_vec_clone, nstd_vec_extend, nstd_vec_from_slice,
-        nstd_vec_len, nstd_vec_new, nstd_vec_new_with_cap, nstd_vec_truncate, NSTDVec,
+        nstd_vec_allocator, nstd_vec_as_ptr, nstd_vec_as_ptr_mut, nstd_vec_as_slice,
+        nstd_vec_as_slice_mut, nstd_vec_cap, nstd_vec_clear, nstd_vec_clone, nstd_vec_extend,
+        nstd_vec_from_slice, nstd_vec_len, nstd_vec_new, nstd_vec_new_with_cap, nstd_vec_set_len,
+        nstd_vec_truncate, NSTDVec,
     },
     NSTDFloat32, NSTDFloat64, NSTDInt, NSTDInt16, NSTDInt32, NSTDInt64, NSTDInt8, NSTDUInt,
     NSTDUInt16, NSTDUInt32, NSTDUInt64, NSTDUInt8,
@@ -191,6 +194,81 @@ pub fn nstd_string_from_bytes(bytes: NSTDVec<'_>) -> NSTDOptionalString<'_> {
     }
 }
 
+/// Creates a new string from a C string slice, replacing any invalid UTF-8 sequences with the
+/// replacement character (U+FFFD).
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `const NSTDCStr *cstr` - The C string slice to convert.
+///
+/// # Returns
+///
+/// `NSTDOptionalString string` - The new string on success, or an uninitialized "none" variant if
+/// allocating fails.
+///
+/// # Safety
+///
+/// The caller of this function must ensure that `cstr`'s data is valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::cstr::nstd_core_cstr_new,
+///     string::{nstd_string_as_ptr, nstd_string_byte_len, nstd_string_from_cstr_lossy},
+/// };
+///
+/// unsafe {
+///     let bytes = b"Hello, \xF0\x90\x80world!";
+///     let cstr = nstd_core_cstr_new(bytes.as_ptr().cast(), bytes.len()).unwrap();
+///     let string = nstd_string_from_cstr_lossy(&NSTD_ALLOCATOR, &cstr).unwrap();
+///     let len = nstd_string_byte_len(&string);
+///     let expected = "Hello, \u{FFFD}world!".as_bytes();
+///     assert!(core::slice::from_raw_parts(nstd_string_as_ptr(&string), len) == expected);
+/// }
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_string_from_cstr_lossy<'a>(
+    allocator: &'a NSTDAllocator,
+    cstr: &NSTDCStr,
+) -> NSTDOptionalString<'a> {
+    let mut bytes = cstr.as_bytes();
+    let NSTDOptional::Some(mut string) = nstd_string_new_with_cap(allocator, bytes.len()) else {
+        return NSTDOptional::None;
+    };
+    loop {
+        let (valid, invalid) = match core::str::from_utf8(bytes) {
+            Ok(valid) => (valid, None),
+            Err(err) => {
+                let (valid, rest) = bytes.split_at(err.valid_up_to());
+                // `valid` has been validated as UTF-8.
+                let valid = core::str::from_utf8_unchecked(valid);
+                (
+                    valid,
+                    Some(err.error_len().map_or(&[][..], |len| &rest[len..])),
+                )
+            }
+        };
+        let errc = nstd_string_push_str(&mut string, &NSTDStr::from_str(valid));
+        if errc != NSTDAllocError::NSTD_ALLOC_ERROR_NONE {
+            return NSTDOptional::None;
+        }
+        match invalid {
+            Some(rest) => {
+                let errc = nstd_string_push(&mut string, char::REPLACEMENT_CHARACTER.into());
+                if errc != NSTDAllocError::NSTD_ALLOC_ERROR_NONE {
+                    return NSTDOptional::None;
+                }
+                bytes = rest;
+            }
+            None => return NSTDOptional::Some(string),
+        }
+    }
+}
+
 /// Creates a deep copy of a string.
 ///
 /// # Parameters:
@@ -431,6 +509,64 @@ pub unsafe fn nstd_string_push_str(string: &mut NSTDString<'_>, str: &NSTDStr) -
     nstd_vec_extend(&mut string.bytes, &str_bytes)
 }
 
+/// Appends a slice of UTF-8 encoded bytes to the end of a string.
+///
+/// The bytes are validated before they're appended, `string` is left unchanged if they are not
+/// valid UTF-8.
+///
+/// # Parameters:
+///
+/// - `NSTDString *string` - The string.
+///
+/// - `const NSTDSlice *bytes` - The UTF-8 encoded bytes to append to the end of `string`.
+///
+/// # Returns
+///
+/// `NSTDOptionalAllocError errc` - An uninitialized "none" variant if `bytes` is not valid UTF-8,
+/// otherwise the allocation operation error code. The bytes were only appended if this is
+/// `NSTD_ALLOC_ERROR_NONE`, any other error code means that allocating failed and `string` is left
+/// unchanged.
+///
+/// # Safety
+///
+/// `bytes`'s data must be valid for reads.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{
+///         alloc::NSTDAllocError::NSTD_ALLOC_ERROR_NONE, optional::NSTDOptional,
+///         slice::nstd_core_slice_new,
+///     },
+///     string::{nstd_string_byte_len, nstd_string_new, nstd_string_push_bytes},
+/// };
+///
+/// unsafe {
+///     let mut string = nstd_string_new(&NSTD_ALLOCATOR);
+///     let valid = "Hello, 🌎!".as_bytes();
+///     let bytes = nstd_core_slice_new(valid.as_ptr().cast(), 1, 1, valid.len()).unwrap();
+///     let errc = nstd_string_push_bytes(&mut string, &bytes);
+///     assert!(errc == NSTDOptional::Some(NSTD_ALLOC_ERROR_NONE));
+///     let invalid = [b'!', 0xFF];
+///     let bytes = nstd_core_slice_new(invalid.as_ptr().cast(), 1, 1, invalid.len()).unwrap();
+///     assert!(nstd_string_push_bytes(&mut string, &bytes) == NSTDOptional::None);
+///     assert!(nstd_string_byte_len(&string) == valid.len());
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub unsafe fn nstd_string_push_bytes(
+    string: &mut NSTDString<'_>,
+    bytes: &NSTDSlice,
+) -> NSTDOptionalAllocError {
+    match nstd_core_str_from_bytes(bytes) {
+        NSTDOptional::Some(str) => NSTDOptional::Some(nstd_string_push_str(string, &str)),
+        NSTDOptional::None => NSTDOptional::None,
+    }
+}
+
 /// Removes the last character from a string and returns it.
 ///
 /// # Parameters:
@@ -469,6 +605,104 @@ pub fn nstd_string_pop(string: &mut NSTDString<'_>) -> NSTDOptionalUnichar {
     NSTDOptional::None
 }
 
+/// Removes the character starting at byte `index` from a string and returns it.
+///
+/// All bytes after the removed character are shifted to the left.
+///
+/// # Parameters:
+///
+/// - `NSTDString *string` - The string.
+///
+/// - `NSTDUInt index` - The byte index of the character to remove.
+///
+/// # Returns
+///
+/// `NSTDOptionalUnichar chr` - The removed character on success, or an uninitialized "none"
+/// variant if `index` is equal to the string's byte length.
+///
+/// # Panics
+///
+/// This operation will panic if `index` does not lie on a character boundary or is greater than
+/// the string's byte length.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{optional::NSTDOptional, str::nstd_core_str_from_raw_cstr},
+///     string::{nstd_string_byte_len, nstd_string_from_str, nstd_string_remove},
+/// };
+///
+/// unsafe {
+///     let str = nstd_core_str_from_raw_cstr("Hello, 🌎!\0".as_ptr().cast()).unwrap();
+///     let mut string = nstd_string_from_str(&NSTD_ALLOCATOR, &str).unwrap();
+///     assert!(nstd_string_remove(&mut string, 7) == NSTDOptional::Some('🌎'.into()));
+///     assert!(nstd_string_remove(&mut string, 7) == NSTDOptional::Some('!'.into()));
+///     assert!(nstd_string_remove(&mut string, 7) == NSTDOptional::None);
+///     assert!(nstd_string_byte_len(&string) == 7);
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_string_remove(string: &mut NSTDString<'_>, index: NSTDUInt) -> NSTDOptionalUnichar {
+    // SAFETY: `NSTDString` is always UTF-8 encoded.
+    let str = unsafe { core::str::from_utf8_unchecked(string.bytes.as_slice()) };
+    assert!(str.is_char_boundary(index));
+    if let Some(chr) = str[index..].chars().next() {
+        let byte_len = nstd_vec_len(&string.bytes);
+        let chr_len = chr.len_utf8();
+        // SAFETY: `index` lies on a character boundary, and the character that starts at `index`
+        // occupies `chr_len` bytes.
+        #[allow(clippy::arithmetic_side_effects)]
+        unsafe {
+            let ptr = nstd_vec_as_ptr_mut(&mut string.bytes)
+                .cast::<NSTDByte>()
+                .add(index);
+            let src = ptr.add(chr_len);
+            nstd_core_mem_copy_overlapping(ptr, src, byte_len - index - chr_len);
+            nstd_vec_set_len(&mut string.bytes, byte_len - chr_len);
+        }
+        return NSTDOptional::Some(chr.into());
+    }
+    NSTDOptional::None
+}
+
+/// Shortens a string, keeping the first `len` Unicode characters.
+///
+/// This operation does nothing if `len` is greater than or equal to the string's current length.
+///
+/// # Parameters:
+///
+/// - `NSTDString *string` - The string to truncate.
+///
+/// - `NSTDUInt len` - The number of characters to keep.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::str::nstd_core_str_from_raw_cstr,
+///     string::{nstd_string_byte_len, nstd_string_from_str, nstd_string_len, nstd_string_truncate},
+/// };
+///
+/// unsafe {
+///     let str = nstd_core_str_from_raw_cstr("🦀🚀🌎!\0".as_ptr().cast()).unwrap();
+///     let mut string = nstd_string_from_str(&NSTD_ALLOCATOR, &str).unwrap();
+///     nstd_string_truncate(&mut string, 2);
+///     assert!(nstd_string_len(&string) == 2);
+///     assert!(nstd_string_byte_len(&string) == 8);
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_string_truncate(string: &mut NSTDString<'_>, len: NSTDUInt) {
+    // SAFETY: `NSTDString` is always UTF-8 encoded.
+    let str = unsafe { core::str::from_utf8_unchecked(string.bytes.as_slice()) };
+    if let Some((byte_len, _)) = str.char_indices().nth(len) {
+        nstd_vec_truncate(&mut string.bytes, byte_len);
+    }
+}
+
 /// Sets a string's length to zero.
 ///
 /// # Parameters:
@@ -637,6 +871,141 @@ gen_from_primitive!(
     NSTDUInt64
 );
 
+/// Creates a new `NSTDString` from an `NSTDFloat64` with a fixed number of fractional digits.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDFloat64 v` - The 64-bit floating-point value.
+///
+/// - `NSTDUInt precision` - The number of digits to write after the decimal point.
+///
+/// # Returns
+///
+/// `NSTDOptionalString string` - The 64-bit floating-point value as a string on success, or an
+/// uninitialized "none" variant if `precision` is greater than 65535 or allocating fails.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::optional::NSTDOptional,
+///     string::{nstd_string_as_ptr, nstd_string_byte_len, nstd_string_from_f64_precision},
+/// };
+///
+/// unsafe {
+///     let string = nstd_string_from_f64_precision(&NSTD_ALLOCATOR, 3.14159, 2).unwrap();
+///     let len = nstd_string_byte_len(&string);
+///     assert!(core::slice::from_raw_parts(nstd_string_as_ptr(&string), len) == b"3.14");
+///     assert!(matches!(
+///         nstd_string_from_f64_precision(&NSTD_ALLOCATOR, 3.14159, 65536),
+///         NSTDOptional::None
+///     ));
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_string_from_f64_precision(
+    allocator: &NSTDAllocator,
+    v: NSTDFloat64,
+    precision: NSTDUInt,
+) -> NSTDOptionalString<'_> {
+    /// Writes formatted output into an `NSTDString`.
+    struct Writer<'s, 'a>(&'s mut NSTDString<'a>);
+    impl core::fmt::Write for Writer<'_, '_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            // SAFETY: `s` is valid for the duration of this call.
+            match unsafe { nstd_string_push_str(self.0, &NSTDStr::from_str(s)) } {
+                NSTDAllocError::NSTD_ALLOC_ERROR_NONE => Ok(()),
+                _ => Err(core::fmt::Error),
+            }
+        }
+    }
+    // Rust's formatting machinery panics on precisions that don't fit in a `u16`.
+    if precision > NSTDUInt::from(u16::MAX) {
+        return NSTDOptional::None;
+    }
+    let mut string = nstd_string_new(allocator);
+    match core::fmt::Write::write_fmt(&mut Writer(&mut string), format_args!("{v:.precision$}")) {
+        Ok(()) => NSTDOptional::Some(string),
+        Err(_) => NSTDOptional::None,
+    }
+}
+
+/// Creates a new `NSTDString` from an `NSTDInt` using the given `radix`.
+///
+/// Digits greater than 9 are written as lowercase letters, and no prefix (such as "0x") is
+/// written. Negative values are prefixed with a '-' sign.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDInt v` - The arch-bit signed integer value.
+///
+/// - `NSTDUInt32 radix` - The base to write `v` in, in the range [2, 36].
+///
+/// # Returns
+///
+/// `NSTDOptionalString string` - The arch-bit signed integer value as a string on success, or an
+/// uninitialized "none" variant if `radix` is invalid or allocating fails.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::optional::NSTDOptional,
+///     string::{nstd_string_as_ptr, nstd_string_byte_len, nstd_string_from_int_radix},
+/// };
+///
+/// unsafe {
+///     let string = nstd_string_from_int_radix(&NSTD_ALLOCATOR, -255, 16).unwrap();
+///     let len = nstd_string_byte_len(&string);
+///     assert!(core::slice::from_raw_parts(nstd_string_as_ptr(&string), len) == b"-ff");
+///     assert!(matches!(
+///         nstd_string_from_int_radix(&NSTD_ALLOCATOR, 5, 37),
+///         NSTDOptional::None
+///     ));
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_string_from_int_radix(
+    allocator: &NSTDAllocator,
+    v: NSTDInt,
+    radix: NSTDUInt32,
+) -> NSTDOptionalString<'_> {
+    if !(2..=36).contains(&radix) {
+        return NSTDOptional::None;
+    }
+    // A base-2 `NSTDInt` with a sign never requires more than `NSTDInt::BITS + 1` bytes.
+    let mut buf = [0; NSTDInt::BITS as usize + 1];
+    let mut i = buf.len();
+    let mut n = v.unsigned_abs();
+    #[allow(clippy::arithmetic_side_effects, clippy::cast_possible_truncation)]
+    loop {
+        i -= 1;
+        // `radix` is in the range [2, 36], so the digit is always valid.
+        let digit = (n % radix as NSTDUInt) as u32;
+        buf[i] = char::from_digit(digit, radix).map_or(b'0', |chr| chr as u8);
+        n /= radix as NSTDUInt;
+        if n == 0 {
+            break;
+        }
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    if v < 0 {
+        i -= 1;
+        buf[i] = b'-';
+    }
+    // SAFETY: `buf[i..]` contains only ASCII characters.
+    unsafe {
+        let str = core::str::from_utf8_unchecked(&buf[i..]);
+        nstd_string_from_str(allocator, &NSTDStr::from_str(str))
+    }
+}
+
 /// Frees an instance of `NSTDString`.
 ///
 /// # Parameters:
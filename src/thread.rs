@@ -6,14 +6,16 @@ use crate::{
         optional::{gen_optional, NSTDOptional},
         result::NSTDResult,
         str::{nstd_core_str_as_cstr, NSTDOptionalStr, NSTDStr},
-        time::NSTDDuration,
+        time::{nstd_core_time_duration_get, NSTDDuration},
     },
     heap_ptr::NSTDOptionalHeapPtr,
     io::NSTDIOError,
-    NSTDBool, NSTDUInt,
+    time::{nstd_time_instant_elapsed, NSTDInstant},
+    NSTDAnyMut, NSTDBool, NSTDUInt,
 };
+use core::time::Duration;
 use nstdapi::nstdapi;
-use std::thread::{Builder, JoinHandle, Thread, ThreadId};
+use std::thread::{Builder, JoinHandle, Scope, Thread, ThreadId};
 
 /// Represents a running thread.
 #[nstdapi]
@@ -39,7 +41,7 @@ pub struct NSTDThreadID {
 
 /// Describes the creation of a new thread.
 ///
-/// This type is passed to the `nstd_thread_spawn_with_desc` function.
+/// This type is passed to the `nstd_thread_spawn` function.
 #[nstdapi]
 #[derive(Clone, Copy)]
 pub struct NSTDThreadDescriptor {
@@ -53,6 +55,31 @@ pub struct NSTDThreadDescriptor {
     pub stack_size: NSTDUInt,
 }
 
+/// A handle to a thread scope, used to spawn threads that may borrow non-`'static` data.
+///
+/// This type is passed to the callback given to `nstd_thread_scope`.
+#[nstdapi]
+#[derive(Clone, Copy)]
+pub struct NSTDThreadScope<'s, 'e> {
+    /// The Rust [Scope].
+    scope: &'s Scope<'s, 'e>,
+}
+
+/// Raw data sent to a scoped thread.
+struct ScopedData(NSTDAnyMut);
+impl ScopedData {
+    /// Returns the raw data.
+    ///
+    /// Taking `self` by value ensures the closure in `nstd_thread_scope_spawn` captures the whole
+    /// [Send] wrapper rather than the raw pointer field.
+    #[inline]
+    const fn get(self) -> NSTDAnyMut {
+        self.0
+    }
+}
+// SAFETY: The user guarantees that the data is thread-safe.
+unsafe impl Send for ScopedData {}
+
 /// A thread function's return value.
 pub type NSTDThreadResult = NSTDOptionalHeapPtr<'static>;
 
@@ -63,6 +90,33 @@ pub type NSTDOptionalThreadResult = NSTDOptional<NSTDThreadResult>;
 /// success.
 pub type NSTDThreadCountResult = NSTDResult<NSTDUInt, NSTDIOError>;
 
+/// Creates a thread builder from an optional thread descriptor.
+///
+/// Returns [None] if the descriptor's name contains a null byte.
+///
+/// # Safety
+///
+/// `desc.name`'s data must be valid for reads.
+unsafe fn builder(desc: Option<&NSTDThreadDescriptor>) -> Option<Builder> {
+    let mut builder = Builder::new();
+    if let Some(desc) = desc {
+        // Set the thread name.
+        if let NSTDOptional::Some(name) = &desc.name {
+            // Make sure `name` doesn't contain any null bytes.
+            let c_name = nstd_core_str_as_cstr(name);
+            if !nstd_core_cstr_get_null(&c_name).is_null() {
+                return None;
+            }
+            builder = builder.name(name.as_str().to_string());
+        }
+        // Set the thread stack size.
+        if desc.stack_size != 0 {
+            builder = builder.stack_size(desc.stack_size);
+        }
+    }
+    Some(builder)
+}
+
 /// Spawns a new thread executing the function `thread_fn` and returns a handle to the new thread.
 ///
 /// # Parameters:
@@ -118,22 +172,9 @@ pub unsafe fn nstd_thread_spawn(
     desc: Option<&NSTDThreadDescriptor>,
 ) -> NSTDOptionalThread {
     // Create the thread builder.
-    let mut builder = Builder::new();
-    if let Some(desc) = desc {
-        // Set the thread name.
-        if let NSTDOptional::Some(name) = &desc.name {
-            // Make sure `name` doesn't contain any null bytes.
-            let c_name = nstd_core_str_as_cstr(name);
-            if !nstd_core_cstr_get_null(&c_name).is_null() {
-                return NSTDOptional::None;
-            }
-            builder = builder.name(name.as_str().to_string());
-        }
-        // Set the thread stack size.
-        if desc.stack_size != 0 {
-            builder = builder.stack_size(desc.stack_size);
-        }
-    }
+    let Some(builder) = builder(desc) else {
+        return NSTDOptional::None;
+    };
     // Spawn the new thread.
     if let Ok(thread) = builder.spawn(move || thread_fn(data)) {
         if let Some(thread) = CBox::new(thread) {
@@ -143,6 +184,101 @@ pub unsafe fn nstd_thread_spawn(
     NSTDOptional::None
 }
 
+/// Creates a thread scope and invokes `callback` with a handle to it.
+///
+/// Threads spawned within the scope with `nstd_thread_scope_spawn` may borrow data that outlives
+/// this function call, as all of them are joined before this function returns.
+///
+/// # Parameters:
+///
+/// - `void (*callback)(const NSTDThreadScope *, NSTDAnyMut)` - The function to invoke with the
+/// thread scope.
+///
+/// - `NSTDAnyMut data` - Data to pass to `callback`.
+///
+/// # Panics
+///
+/// Panics if any of the scope's threads panic.
+///
+/// # Safety
+///
+/// This operation makes a direct call on a C function pointer (`callback`).
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     thread::{nstd_thread_scope, nstd_thread_scope_spawn, NSTDThreadScope},
+///     NSTDAnyMut,
+/// };
+///
+/// unsafe extern "C" fn double(data: NSTDAnyMut) {
+///     *data.cast::<u32>() *= 2;
+/// }
+///
+/// unsafe extern "C" fn fan_out(scope: &NSTDThreadScope, data: NSTDAnyMut) {
+///     let values = &mut *data.cast::<[u32; 4]>();
+///     for value in values {
+///         let value: *mut u32 = value;
+///         assert!(nstd_thread_scope_spawn(scope, double, value.cast(), None));
+///     }
+/// }
+///
+/// let mut values = [1u32, 2, 3, 4];
+/// unsafe { nstd_thread_scope(fan_out, values.as_mut_ptr().cast()) };
+/// assert!(values == [2, 4, 6, 8]);
+/// ```
+#[nstdapi]
+pub unsafe fn nstd_thread_scope(
+    callback: unsafe extern "C" fn(&NSTDThreadScope<'_, '_>, NSTDAnyMut),
+    data: NSTDAnyMut,
+) {
+    std::thread::scope(|scope| callback(&NSTDThreadScope { scope }, data));
+}
+
+/// Spawns a new thread within a thread scope executing the function `thread_fn`.
+///
+/// The thread is joined automatically when the scope created by `nstd_thread_scope` ends.
+///
+/// # Parameters:
+///
+/// - `const NSTDThreadScope *scope` - The thread scope.
+///
+/// - `void (*thread_fn)(NSTDAnyMut)` - The thread function.
+///
+/// - `NSTDAnyMut data` - Data to send to the thread.
+///
+/// - `const NSTDThreadDescriptor *desc` - The thread descriptor. This value may be null.
+///
+/// # Returns
+///
+/// `NSTDBool is_spawned` - True if the thread was spawned successfully.
+///
+/// # Safety
+///
+/// - The caller of this function must guarantee that `thread_fn` is a valid function pointer.
+///
+/// - This operation can cause undefined behavior if `desc.name`'s data is invalid.
+///
+/// - The data that `data` points to must be able to be safely sent between threads, and must
+/// remain valid until the scope ends.
+#[nstdapi]
+pub unsafe fn nstd_thread_scope_spawn(
+    scope: &NSTDThreadScope<'_, '_>,
+    thread_fn: unsafe extern "C" fn(NSTDAnyMut),
+    data: NSTDAnyMut,
+    desc: Option<&NSTDThreadDescriptor>,
+) -> NSTDBool {
+    let data = ScopedData(data);
+    builder(desc)
+        .and_then(|builder| {
+            builder
+                .spawn_scoped(scope.scope, move || thread_fn(data.get()))
+                .ok()
+        })
+        .is_some()
+}
+
 /// Returns a handle to the calling thread.
 ///
 /// # Returns
@@ -302,6 +438,49 @@ pub fn nstd_thread_sleep(duration: NSTDDuration) {
     std::thread::sleep(duration.into_duration());
 }
 
+/// Puts the current thread to sleep until a monotonic `deadline` is reached.
+///
+/// This function returns immediately if `deadline` has already passed.
+///
+/// # Parameters:
+///
+/// - `NSTDInstant deadline` - The instant to sleep until.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::time::{nstd_core_time_duration_get, nstd_core_time_duration_new},
+///     thread::nstd_thread_sleep_until,
+///     time::{nstd_time_instant_add, nstd_time_instant_elapsed, nstd_time_now_monotonic},
+/// };
+///
+/// let start = nstd_time_now_monotonic();
+/// let deadline = nstd_time_instant_add(start, nstd_core_time_duration_new(0.01));
+/// nstd_thread_sleep_until(deadline);
+/// assert!(nstd_core_time_duration_get(nstd_time_instant_elapsed(start)) >= 0.01);
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_thread_sleep_until(deadline: NSTDInstant) {
+    let remaining = -nstd_core_time_duration_get(nstd_time_instant_elapsed(deadline));
+    if remaining > 0.0 {
+        std::thread::sleep(Duration::from_secs_f64(remaining));
+    }
+}
+
+/// Yields the current thread's time slice, allowing other threads to run.
+///
+/// # Note
+///
+/// This is only a hint to the OS's scheduler, it may return immediately without the thread being
+/// descheduled.
+#[inline]
+#[nstdapi]
+pub fn nstd_thread_yield() {
+    std::thread::yield_now();
+}
+
 /// Returns the number of recommended threads that a program should use.
 ///
 /// # Returns
@@ -1,11 +1,22 @@
 //! Time utilities.
 use crate::{
-    core::time::{nstd_core_time_duration_new, NSTDDuration},
+    core::{
+        alloc::NSTDAllocator,
+        str::NSTDStr,
+        time::{
+            nstd_core_time_duration_add, nstd_core_time_duration_new, nstd_core_time_duration_sub,
+            NSTDDuration,
+        },
+    },
+    string::{nstd_string_from_str, NSTDOptionalString},
     NSTDFloat64, NSTDInt64, NSTDUInt32,
 };
 use cfg_if::cfg_if;
 use nstdapi::nstdapi;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    sync::OnceLock,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
 
 cfg_if! {
     if #[cfg(unix)] {
@@ -78,6 +89,43 @@ cfg_if! {
     }
 }
 
+/// The point in time that every [`NSTDInstant`] is measured from.
+static INSTANT_EPOCH: OnceLock<Instant> = OnceLock::new();
+
+/// A measurement of a monotonically nondecreasing clock.
+///
+/// Unlike `NSTDTime`, instants are unaffected by changes to the system clock, making them suitable
+/// for measuring elapsed time.
+#[nstdapi]
+#[derive(Clone, Copy, PartialEq)]
+pub struct NSTDInstant {
+    /// The time span since an unspecified point in time.
+    duration: NSTDDuration,
+}
+impl NSTDInstant {
+    /// Returns the current [`NSTDInstant`].
+    fn now() -> Self {
+        let epoch = INSTANT_EPOCH.get_or_init(Instant::now);
+        #[allow(unused_unsafe)]
+        // SAFETY: This operation is safe.
+        unsafe {
+            Self {
+                duration: nstd_core_time_duration_new(epoch.elapsed().as_secs_f64()),
+            }
+        }
+    }
+}
+
+/// A stopwatch that measures elapsed time with a monotonic clock.
+#[nstdapi]
+#[derive(Clone, Copy, PartialEq)]
+pub struct NSTDStopwatch {
+    /// The instant that the stopwatch was started.
+    start: NSTDInstant,
+    /// The instant of the stopwatch's most recent lap.
+    lap: NSTDInstant,
+}
+
 /// Returns the current system time as an `NSTDTime` object.
 ///
 /// # Returns
@@ -93,6 +141,46 @@ pub fn nstd_time_now() -> NSTDOptionalTime {
     return NSTDOptional::Some(NSTDTime::from(SystemTime::now()));
 }
 
+/// Creates an `NSTDTime` object from a point in time relative to the Unix epoch.
+///
+/// # Note
+///
+/// `NSTDTime` stores time as a 64-bit floating-point number of seconds, so some nanosecond
+/// precision may be lost for times far from the epoch.
+///
+/// # Parameters:
+///
+/// - `NSTDInt64 seconds` - The number of seconds since January 1st 1970, may be negative.
+///
+/// - `NSTDUInt32 nanoseconds` - The number of nanoseconds to add to `seconds`.
+///
+/// # Returns
+///
+/// `NSTDTime time` - The new time object.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::time::{nstd_time_from_unix, nstd_time_nanoseconds, nstd_time_seconds};
+///
+/// let time = nstd_time_from_unix(1_704_207_845, 500_000_000);
+/// assert!(nstd_time_seconds(time) == 1_704_207_845);
+/// assert!(nstd_time_nanoseconds(time).abs_diff(500_000_000) < 1000);
+/// ```
+#[inline]
+#[nstdapi]
+#[allow(clippy::cast_precision_loss)]
+pub fn nstd_time_from_unix(seconds: NSTDInt64, nanoseconds: NSTDUInt32) -> NSTDTime {
+    /// The number of nanoseconds in a full second.
+    const NANOS_IN_SEC: NSTDFloat64 = 1_000_000_000.0;
+    let seconds = seconds as NSTDFloat64 + NSTDFloat64::from(nanoseconds) / NANOS_IN_SEC;
+    let duration = nstd_core_time_duration_new(seconds);
+    #[cfg(unix)]
+    return NSTDTime::from_duration(duration);
+    #[cfg(not(unix))]
+    return NSTDTime { duration };
+}
+
 /// Returns the number of seconds stored in an `NSTDTime` object as an `NSTDFloat64`.
 ///
 /// # Parameters:
@@ -148,6 +236,38 @@ pub fn nstd_time_nanoseconds(time: NSTDTime) -> NSTDUInt32 {
     return nstd_core_time_duration_nanoseconds(time.duration);
 }
 
+/// Returns the total number of nanoseconds between the Unix epoch and an `NSTDTime` object.
+///
+/// # Parameters:
+///
+/// - `NSTDTime time` - The time object.
+///
+/// # Returns
+///
+/// `NSTDInt64 nanoseconds` - The number of nanoseconds since January 1st 1970, negative for times
+/// before the epoch. This value saturates if it can't fit in an `NSTDInt64`.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::time::{nstd_time_from_unix, nstd_time_unix_nanos};
+///
+/// let time = nstd_time_from_unix(-2, 250_000_000);
+/// assert!(nstd_time_unix_nanos(time).abs_diff(-1_750_000_000) < 1000);
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_time_unix_nanos(time: NSTDTime) -> NSTDInt64 {
+    /// The number of nanoseconds in a full second.
+    const NANOS_IN_SEC: NSTDInt64 = 1_000_000_000;
+    let seconds = nstd_time_seconds(time).saturating_mul(NANOS_IN_SEC);
+    let nanoseconds = NSTDInt64::from(nstd_time_nanoseconds(time));
+    match nstd_time_get(time) < 0.0 {
+        true => seconds.saturating_sub(nanoseconds),
+        false => seconds.saturating_add(nanoseconds),
+    }
+}
+
 /// Computes the addition of an `NSTDTime` object and an `NSTDDuration`.
 ///
 /// # Parameters:
@@ -197,3 +317,229 @@ pub fn nstd_time_sub(time: NSTDTime, duration: NSTDDuration) -> NSTDTime {
         }
     }
 }
+
+/// Formats an `NSTDTime` object as an ISO 8601 timestamp, such as `2024-01-02T15:04:05Z`.
+///
+/// # Note
+///
+/// The timestamp is always expressed in UTC, and any fractional seconds are discarded.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDTime time` - The time object.
+///
+/// # Returns
+///
+/// `NSTDOptionalString timestamp` - The formatted timestamp on success, or an uninitialized "none"
+/// variant if allocating fails.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     string::{nstd_string_as_ptr, nstd_string_byte_len},
+///     time::{nstd_time_format_iso8601, nstd_time_from_unix},
+/// };
+///
+/// unsafe {
+///     let time = nstd_time_from_unix(1_704_207_845, 500_000_000);
+///     let timestamp = nstd_time_format_iso8601(&NSTD_ALLOCATOR, time).unwrap();
+///     let len = nstd_string_byte_len(&timestamp);
+///     let bytes = core::slice::from_raw_parts(nstd_string_as_ptr(&timestamp), len);
+///     assert!(bytes == b"2024-01-02T15:04:05Z");
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_time_format_iso8601(
+    allocator: &NSTDAllocator,
+    time: NSTDTime,
+) -> NSTDOptionalString<'_> {
+    /// The number of seconds in a day.
+    const SECS_IN_DAY: NSTDInt64 = 86_400;
+    #[allow(clippy::cast_possible_truncation)]
+    let secs = nstd_time_get(time).floor() as NSTDInt64;
+    let days = secs.div_euclid(SECS_IN_DAY);
+    let secs = secs.rem_euclid(SECS_IN_DAY);
+    // Convert the number of days since the Unix epoch into a civil date.
+    // See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    #[allow(clippy::arithmetic_side_effects, clippy::integer_division)]
+    let (year, month, day) = {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + NSTDInt64::from(month <= 2);
+        (year, month, day)
+    };
+    #[allow(clippy::arithmetic_side_effects, clippy::integer_division)]
+    let timestamp = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+    // SAFETY: `timestamp` is valid for the duration of this call.
+    unsafe { nstd_string_from_str(allocator, &NSTDStr::from_str(&timestamp)) }
+}
+
+/// Returns the current instant of a monotonic clock.
+///
+/// Instants are measured with Rust's `std::time::Instant` and stored as the time span since the
+/// first instant taken by the current process. Because the span is stored as an `NSTDFloat64`,
+/// instants lose a small amount of precision the longer the process runs.
+///
+/// # Returns
+///
+/// `NSTDInstant now` - The current instant.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::time::nstd_core_time_duration_get,
+///     time::{nstd_time_instant_elapsed, nstd_time_now_monotonic},
+/// };
+///
+/// let start = nstd_time_now_monotonic();
+/// let first = nstd_core_time_duration_get(nstd_time_instant_elapsed(start));
+/// let second = nstd_core_time_duration_get(nstd_time_instant_elapsed(start));
+/// assert!(first >= 0.0 && second >= first);
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_time_now_monotonic() -> NSTDInstant {
+    NSTDInstant::now()
+}
+
+/// Returns the amount of time that has passed since `instant` was measured.
+///
+/// # Parameters:
+///
+/// - `NSTDInstant instant` - The instant to measure from.
+///
+/// # Returns
+///
+/// `NSTDDuration elapsed` - The time span since `instant`.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::time::nstd_core_time_duration_get,
+///     time::{nstd_time_instant_elapsed, nstd_time_now_monotonic},
+/// };
+///
+/// let instant = nstd_time_now_monotonic();
+/// std::thread::sleep(std::time::Duration::from_millis(10));
+/// assert!(nstd_core_time_duration_get(nstd_time_instant_elapsed(instant)) >= 0.01);
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_time_instant_elapsed(instant: NSTDInstant) -> NSTDDuration {
+    let now = NSTDInstant::now();
+    nstd_core_time_duration_sub(now.duration, instant.duration)
+}
+
+/// Computes the addition of an `NSTDInstant` and an `NSTDDuration`.
+///
+/// # Parameters:
+///
+/// - `NSTDInstant instant` - The instant.
+///
+/// - `NSTDDuration duration` - The duration to add.
+///
+/// # Returns
+///
+/// `NSTDInstant instant` - The result of the addition.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::time::{nstd_core_time_duration_get, nstd_core_time_duration_new},
+///     time::{nstd_time_instant_add, nstd_time_instant_elapsed, nstd_time_now_monotonic},
+/// };
+///
+/// let now = nstd_time_now_monotonic();
+/// let later = nstd_time_instant_add(now, nstd_core_time_duration_new(60.0));
+/// assert!(nstd_core_time_duration_get(nstd_time_instant_elapsed(later)) < -59.0);
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_time_instant_add(instant: NSTDInstant, duration: NSTDDuration) -> NSTDInstant {
+    NSTDInstant {
+        duration: nstd_core_time_duration_add(instant.duration, duration),
+    }
+}
+
+/// Starts a new stopwatch.
+///
+/// # Returns
+///
+/// `NSTDStopwatch stopwatch` - A new stopwatch started at the current instant.
+#[inline]
+#[nstdapi]
+pub fn nstd_time_stopwatch_start() -> NSTDStopwatch {
+    let now = NSTDInstant::now();
+    NSTDStopwatch {
+        start: now,
+        lap: now,
+    }
+}
+
+/// Records a lap, returning the amount of time that has passed since the previous lap.
+///
+/// The first lap is measured from when the stopwatch was started.
+///
+/// # Parameters:
+///
+/// - `NSTDStopwatch *stopwatch` - The stopwatch.
+///
+/// # Returns
+///
+/// `NSTDDuration lap` - The time span since the previous lap.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     core::time::nstd_core_time_duration_get,
+///     time::{nstd_time_stopwatch_lap, nstd_time_stopwatch_start, nstd_time_stopwatch_total},
+/// };
+///
+/// let mut stopwatch = nstd_time_stopwatch_start();
+/// std::thread::sleep(std::time::Duration::from_millis(10));
+/// let lap = nstd_core_time_duration_get(nstd_time_stopwatch_lap(&mut stopwatch));
+/// let total = nstd_core_time_duration_get(nstd_time_stopwatch_total(&stopwatch));
+/// assert!(lap >= 0.01 && total >= lap);
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_time_stopwatch_lap(stopwatch: &mut NSTDStopwatch) -> NSTDDuration {
+    let now = NSTDInstant::now();
+    let lap = nstd_core_time_duration_sub(now.duration, stopwatch.lap.duration);
+    stopwatch.lap = now;
+    lap
+}
+
+/// Returns the amount of time that has passed since a stopwatch was started.
+///
+/// # Parameters:
+///
+/// - `const NSTDStopwatch *stopwatch` - The stopwatch.
+///
+/// # Returns
+///
+/// `NSTDDuration total` - The time span since `stopwatch` was started.
+#[inline]
+#[nstdapi]
+pub fn nstd_time_stopwatch_total(stopwatch: &NSTDStopwatch) -> NSTDDuration {
+    nstd_time_instant_elapsed(stopwatch.start)
+}
@@ -11,6 +11,7 @@ use crate::{
         def::{NSTDByte, NSTDErrorCode},
         mem::{nstd_core_mem_copy, nstd_core_mem_copy_overlapping, nstd_core_mem_dangling_mut},
         optional::NSTDOptional,
+        result::NSTDResult,
         slice::{
             nstd_core_slice_align, nstd_core_slice_as_ptr, nstd_core_slice_len,
             nstd_core_slice_mut_new_unchecked, nstd_core_slice_new_unchecked,
@@ -20,7 +21,7 @@ use crate::{
     NSTDAny, NSTDAnyMut, NSTDBool, NSTDUInt, NSTD_NULL,
 };
 use alloc::vec::Vec;
-use core::ptr::addr_of;
+use core::ptr::{addr_of, drop_in_place, slice_from_raw_parts_mut};
 use nstdapi::nstdapi;
 
 /// A dynamically sized contiguous sequence of values.
@@ -141,7 +142,8 @@ impl<A> FromIterator<A> for NSTDVec<'_> {
     ///
     /// # Panics
     ///
-    /// This operation will panic if allocating fails.
+    /// This operation will panic if allocating fails. Any values that were already collected into
+    /// the vector are dropped before panicking.
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
         let size = core::mem::size_of::<A>();
         let align = core::mem::align_of::<A>();
@@ -152,7 +154,15 @@ impl<A> FromIterator<A> for NSTDVec<'_> {
         for v in iter {
             // SAFETY: `v` is stored on the stack.
             errc = unsafe { nstd_vec_push(&mut s, addr_of!(v).cast()) };
-            assert!(errc == NSTD_ALLOC_ERROR_NONE);
+            if errc != NSTD_ALLOC_ERROR_NONE {
+                let values = slice_from_raw_parts_mut(s.ptr.cast::<A>(), s.len);
+                // SAFETY: The vector's active elements are all valid values of type `A`.
+                unsafe {
+                    nstd_vec_set_len(&mut s, 0);
+                    drop_in_place(values);
+                }
+                panic!("failed to collect values into an `NSTDVec`");
+            }
             // Be sure to forget `v` so it doesn't get dropped.
             core::mem::forget(v);
         }
@@ -173,6 +183,10 @@ unsafe impl Sync for NSTDVec<'_> {}
 /// Represents an optional value of type `NSTDVec`.
 pub type NSTDOptionalVec<'a> = NSTDOptional<NSTDVec<'a>>;
 
+/// A result type returned from `nstd_vec_relocate` that yields the relocated vector on success and
+/// the original vector on failure.
+pub type NSTDVecRelocateResult<'a, 'b> = NSTDResult<NSTDVec<'b>, NSTDVec<'a>>;
+
 /// Creates a new vector without allocating any resources.
 ///
 /// # Parameters:
@@ -305,6 +319,81 @@ pub fn nstd_vec_new_with_cap(
     NSTDOptional::None
 }
 
+/// Creates a new vector containing `len` zero-initialized values.
+///
+/// # Parameters:
+///
+/// - `const NSTDAllocator *allocator` - The memory allocator.
+///
+/// - `NSTDUInt stride` - The size in bytes of each value in the vector.
+///
+/// - `NSTDUInt align` - The alignment of each value in the vector.
+///
+/// - `NSTDUInt len` - The number of zeroed values to create the vector with.
+///
+/// # Returns
+///
+/// `NSTDOptionalVec vec` - The new vector on success, or an uninitialized "none" variant if
+/// allocation fails or the memory layout is invalid.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     vec::{nstd_vec_get, nstd_vec_len, nstd_vec_new_zeroed},
+/// };
+///
+/// const SIZE: usize = core::mem::size_of::<u64>();
+/// const ALIGN: usize = core::mem::align_of::<u64>();
+///
+/// unsafe {
+///     let vec = nstd_vec_new_zeroed(&NSTD_ALLOCATOR, SIZE, ALIGN, 16).unwrap();
+///     assert!(nstd_vec_len(&vec) == 16);
+///     for i in 0..nstd_vec_len(&vec) {
+///         assert!(*nstd_vec_get(&vec, i).cast::<u64>() == 0);
+///     }
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_vec_new_zeroed(
+    allocator: &NSTDAllocator,
+    stride: NSTDUInt,
+    align: NSTDUInt,
+    len: NSTDUInt,
+) -> NSTDOptionalVec<'_> {
+    // Check if either `stride` or `len` are zero.
+    if stride == 0 || len == 0 {
+        #[allow(clippy::arithmetic_side_effects)]
+        if crate::core::mem::is_power_of_two(align) && stride % align == 0 {
+            return NSTDOptional::Some(NSTDVec {
+                allocator,
+                ptr: nstd_core_mem_dangling_mut(),
+                stride,
+                align,
+                cap: len,
+                len,
+            });
+        }
+    }
+    // Attempt to allocate the zeroed memory buffer.
+    else if let NSTDOptional::Some(layout) = nstd_core_alloc_layout_array(stride, align, len) {
+        // SAFETY: Both `stride` & `len` are above 0.
+        let ptr = unsafe { (allocator.allocate_zeroed)(allocator.state, layout) };
+        if !ptr.is_null() {
+            return NSTDOptional::Some(NSTDVec {
+                allocator,
+                ptr,
+                stride,
+                align,
+                cap: len,
+                len,
+            });
+        }
+    }
+    NSTDOptional::None
+}
+
 /// Creates a new vector from a slice.
 ///
 /// # Parameters:
@@ -416,6 +505,71 @@ pub const fn nstd_vec_allocator<'a>(vec: &NSTDVec<'a>) -> &'a NSTDAllocator {
     vec.allocator
 }
 
+/// Moves a vector's data into a new buffer allocated with `allocator`, freeing the old buffer with
+/// the vector's previous allocator.
+///
+/// On success, the returned vector uses `allocator` for all future allocations and its capacity is
+/// equal to its length.
+///
+/// # Parameters:
+///
+/// - `NSTDVec vec` - The vector to relocate.
+///
+/// - `const NSTDAllocator *allocator` - The new memory allocator.
+///
+/// # Returns
+///
+/// `NSTDVecRelocateResult relocated` - The relocated vector on success, or the original vector,
+/// left unchanged, if allocating fails.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::{
+///         nstd_alloc_counting_allocator, nstd_alloc_counting_bytes_in_use,
+///         nstd_alloc_counting_new, NSTD_ALLOCATOR,
+///     },
+///     core::{alloc::NSTDAllocError::NSTD_ALLOC_ERROR_NONE, result::NSTDResult},
+///     vec::{nstd_vec_get, nstd_vec_new, nstd_vec_push, nstd_vec_relocate, NSTDVec},
+/// };
+/// use core::ptr::addr_of;
+///
+/// unsafe {
+///     let vec: NSTDVec<'static> = {
+///         let counting = nstd_alloc_counting_new(&NSTD_ALLOCATOR);
+///         let arena = nstd_alloc_counting_allocator(&counting);
+///         let mut vec = nstd_vec_new(&arena, 4, 4);
+///         for i in 0..10u32 {
+///             assert!(nstd_vec_push(&mut vec, addr_of!(i).cast()) == NSTD_ALLOC_ERROR_NONE);
+///         }
+///         assert!(nstd_alloc_counting_bytes_in_use(&counting) > 0);
+///         let NSTDResult::Ok(vec) = nstd_vec_relocate(vec, &NSTD_ALLOCATOR) else {
+///             panic!("failed to relocate the vector");
+///         };
+///         assert!(nstd_alloc_counting_bytes_in_use(&counting) == 0);
+///         vec
+///     };
+///     assert!(*nstd_vec_get(&vec, 9).cast::<u32>() == 9);
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_vec_relocate<'a, 'b>(
+    vec: NSTDVec<'a>,
+    allocator: &'b NSTDAllocator,
+) -> NSTDVecRelocateResult<'a, 'b> {
+    let NSTDOptional::Some(mut relocated) =
+        nstd_vec_new_with_cap(allocator, vec.stride, vec.align, vec.len)
+    else {
+        return NSTDResult::Err(vec);
+    };
+    // SAFETY: Both vectors' buffers are large enough to hold `vec.len` values.
+    unsafe { nstd_core_mem_copy(relocated.ptr.cast(), vec.ptr.cast(), vec.byte_len()) };
+    relocated.len = vec.len;
+    // The old buffer is freed with the old allocator when `vec` is dropped.
+    NSTDResult::Ok(relocated)
+}
+
 /// Returns the length of a vector.
 ///
 /// # Parameters:
@@ -435,6 +589,9 @@ pub const fn nstd_vec_len(vec: &NSTDVec<'_>) -> NSTDUInt {
 ///
 /// This is the max number of values the vector can contain without reallocating.
 ///
+/// Vectors with a stride of 0 never need to allocate, so their capacity is always `NSTDUInt`'s max
+/// value.
+///
 /// # Parameters:
 ///
 /// - `const NSTDVec *vec` - The vector.
@@ -442,10 +599,36 @@ pub const fn nstd_vec_len(vec: &NSTDVec<'_>) -> NSTDUInt {
 /// # Returns
 ///
 /// `NSTDUInt cap` - The vector's capacity.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::alloc::NSTDAllocError::NSTD_ALLOC_ERROR_NONE,
+///     vec::{
+///         nstd_vec_cap, nstd_vec_new, nstd_vec_new_with_cap, nstd_vec_push, nstd_vec_reserved,
+///     },
+/// };
+///
+/// unsafe {
+///     let mut vec = nstd_vec_new(&NSTD_ALLOCATOR, 0, 1);
+///     assert!(nstd_vec_cap(&vec) == usize::MAX);
+///     assert!(nstd_vec_push(&mut vec, [(); 0].as_ptr().cast()) == NSTD_ALLOC_ERROR_NONE);
+///     assert!(nstd_vec_cap(&vec) == usize::MAX);
+///     assert!(nstd_vec_reserved(&vec) == usize::MAX - 1);
+///
+///     let vec = nstd_vec_new_with_cap(&NSTD_ALLOCATOR, 0, 1, 16).unwrap();
+///     assert!(nstd_vec_cap(&vec) == usize::MAX);
+/// }
+/// ```
 #[inline]
 #[nstdapi]
 pub const fn nstd_vec_cap(vec: &NSTDVec<'_>) -> NSTDUInt {
-    vec.cap
+    match vec.stride {
+        0 => NSTDUInt::MAX,
+        _ => vec.cap,
+    }
 }
 
 /// Returns the amount of bytes each value in a vector occupies.
@@ -490,7 +673,7 @@ pub const fn nstd_vec_stride(vec: &NSTDVec<'_>) -> NSTDUInt {
 #[nstdapi]
 #[allow(clippy::arithmetic_side_effects)]
 pub const fn nstd_vec_reserved(vec: &NSTDVec<'_>) -> NSTDUInt {
-    vec.cap - vec.len
+    nstd_vec_cap(vec) - vec.len
 }
 
 /// Returns an immutable slice containing all of a vector's active elements.
@@ -525,6 +708,45 @@ pub fn nstd_vec_as_slice_mut(vec: &mut NSTDVec<'_>) -> NSTDSliceMut {
     unsafe { nstd_core_slice_mut_new_unchecked(vec.ptr, vec.stride, vec.align, vec.len) }
 }
 
+/// Returns an immutable byte slice over all of a vector's active elements, regardless of the
+/// vector's stride.
+///
+/// # Parameters:
+///
+/// - `const NSTDVec *vec` - The vector.
+///
+/// # Returns
+///
+/// `NSTDSlice bytes` - An *immutable* view into the vector's active bytes, with a stride and
+/// alignment of 1.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::slice::{nstd_core_slice_len, nstd_core_slice_stride},
+///     vec::{nstd_vec_as_bytes, nstd_vec_new, nstd_vec_push},
+/// };
+///
+/// unsafe {
+///     let mut vec = nstd_vec_new(&NSTD_ALLOCATOR, 4, 4);
+///     let value = 5u32;
+///     nstd_vec_push(&mut vec, core::ptr::addr_of!(value).cast());
+///     nstd_vec_push(&mut vec, core::ptr::addr_of!(value).cast());
+///     let bytes = nstd_vec_as_bytes(&vec);
+///     assert!(nstd_core_slice_len(&bytes) == 8);
+///     assert!(nstd_core_slice_stride(&bytes) == 1);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub const fn nstd_vec_as_bytes(vec: &NSTDVec<'_>) -> NSTDSlice {
+    // SAFETY: `vec.ptr` is checked, vector byte lengths are never greater than `NSTDInt`'s max
+    // value.
+    unsafe { nstd_core_slice_new_unchecked(vec.ptr, 1, 1, vec.byte_len()) }
+}
+
 /// Returns a pointer to a vector's raw data.
 ///
 /// # Parameters:
@@ -718,6 +940,9 @@ pub fn nstd_vec_get_mut(vec: &mut NSTDVec<'_>, pos: NSTDUInt) -> NSTDAnyMut {
 /// This operation is unsafe because undefined behavior can occur if the size of the value being
 /// pushed onto the vector is not equal to `vec`'s stride.
 ///
+/// `value` must not point into `vec`'s own buffer, as the buffer may be reallocated before the
+/// value is copied. Use `nstd_vec_push_from_within` to push a copy of one of `vec`'s elements.
+///
 /// # Example
 ///
 /// ```
@@ -754,6 +979,53 @@ pub unsafe fn nstd_vec_push(vec: &mut NSTDVec<'_>, value: NSTDAny) -> NSTDAllocE
     errc
 }
 
+/// Pushes a copy of the element at `index` onto the end of a vector.
+///
+/// Unlike passing a pointer to one of `vec`'s elements to `nstd_vec_push`, this is safe to use
+/// when pushing requires the vector to reallocate.
+///
+/// # Parameters:
+///
+/// - `NSTDVec *vec` - The vector.
+///
+/// - `NSTDUInt index` - The index of the element to copy.
+///
+/// # Returns
+///
+/// `NSTDAllocError errc` - The allocation operation error code. This will be
+/// `NSTD_ALLOC_ERROR_INVALID_LAYOUT` if `index` is out of the vector's bounds, in which case the
+/// vector is left unchanged.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{alloc::NSTDAllocError::NSTD_ALLOC_ERROR_NONE, slice::nstd_core_slice_new},
+///     vec::{
+///         nstd_vec_cap, nstd_vec_from_slice, nstd_vec_get, nstd_vec_len, nstd_vec_push_from_within,
+///     },
+/// };
+///
+/// const SIZE: usize = core::mem::size_of::<u64>();
+/// const ALIGN: usize = core::mem::align_of::<u64>();
+///
+/// unsafe {
+///     let values: [u64; 2] = [5, 9];
+///     let slice = nstd_core_slice_new(values.as_ptr().cast(), SIZE, ALIGN, 2).unwrap();
+///     let mut vec = nstd_vec_from_slice(&NSTD_ALLOCATOR, &slice).unwrap();
+///     assert!(nstd_vec_len(&vec) == nstd_vec_cap(&vec));
+///     assert!(nstd_vec_push_from_within(&mut vec, 1) == NSTD_ALLOC_ERROR_NONE);
+///     assert!(nstd_vec_len(&vec) == 3);
+///     assert!(*nstd_vec_get(&vec, 2).cast::<u64>() == 9);
+/// }
+/// ```
+#[inline]
+#[nstdapi]
+pub fn nstd_vec_push_from_within(vec: &mut NSTDVec<'_>, index: NSTDUInt) -> NSTDAllocError {
+    nstd_vec_extend_from_within(vec, index, 1)
+}
+
 /// Removes the last value of a vector and returns a pointer to it.
 ///
 /// # Note
@@ -829,6 +1101,9 @@ pub fn nstd_vec_pop(vec: &mut NSTDVec<'_>) -> NSTDAny {
 /// This operation is unsafe because undefined behavior can occur if the size of the value being
 /// inserted into the vector is not equal to `vec`'s stride.
 ///
+/// `value` must not point into `vec`'s own buffer, as the buffer may be reallocated or shifted
+/// before the value is copied.
+///
 /// # Example
 ///
 /// ```
@@ -1024,6 +1299,93 @@ pub unsafe fn nstd_vec_extend(vec: &mut NSTDVec<'_>, values: &NSTDSlice) -> NSTD
     errc
 }
 
+/// Copies a range of a vector's elements onto the end of the same vector.
+///
+/// # Parameters:
+///
+/// - `NSTDVec *vec` - The vector to extend.
+///
+/// - `NSTDUInt start` - The index of the first element to copy.
+///
+/// - `NSTDUInt len` - The number of elements to copy.
+///
+/// # Returns
+///
+/// `NSTDAllocError errc` - The allocation operation error code. This will be
+/// `NSTD_ALLOC_ERROR_INVALID_LAYOUT` if the range `[start, start + len)` is out of the vector's
+/// bounds or if the vector's new length would overflow, in which case the vector is left
+/// unchanged.
+///
+/// # Example
+///
+/// ```
+/// use nstd_sys::{
+///     alloc::NSTD_ALLOCATOR,
+///     core::{
+///         alloc::NSTDAllocError::{NSTD_ALLOC_ERROR_INVALID_LAYOUT, NSTD_ALLOC_ERROR_NONE},
+///         slice::nstd_core_slice_new,
+///     },
+///     vec::{
+///         nstd_vec_extend_from_within, nstd_vec_from_slice, nstd_vec_get, nstd_vec_len,
+///         nstd_vec_new, nstd_vec_set_len,
+///     },
+/// };
+///
+/// const SIZE: usize = core::mem::size_of::<u16>();
+/// const ALIGN: usize = core::mem::align_of::<u16>();
+///
+/// unsafe {
+///     let values: [u16; 3] = [1, 2, 3];
+///     let slice = nstd_core_slice_new(values.as_ptr().cast(), SIZE, ALIGN, 3).unwrap();
+///     let mut vec = nstd_vec_from_slice(&NSTD_ALLOCATOR, &slice).unwrap();
+///     assert!(nstd_vec_extend_from_within(&mut vec, 1, 2) == NSTD_ALLOC_ERROR_NONE);
+///     assert!(nstd_vec_extend_from_within(&mut vec, 4, 2) == NSTD_ALLOC_ERROR_INVALID_LAYOUT);
+///     assert!(nstd_vec_len(&vec) == 5);
+///     for (i, value) in [1, 2, 3, 2, 3].into_iter().enumerate() {
+///         assert!(*nstd_vec_get(&vec, i).cast::<u16>() == value);
+///     }
+///
+///     let mut zst = nstd_vec_new(&NSTD_ALLOCATOR, 0, 1);
+///     nstd_vec_set_len(&mut zst, usize::MAX / 2 + 1);
+///     let len = nstd_vec_len(&zst);
+///     assert!(nstd_vec_extend_from_within(&mut zst, 0, len) == NSTD_ALLOC_ERROR_INVALID_LAYOUT);
+///     assert!(nstd_vec_len(&zst) == len);
+/// }
+/// ```
+#[nstdapi]
+pub fn nstd_vec_extend_from_within(
+    vec: &mut NSTDVec<'_>,
+    start: NSTDUInt,
+    len: NSTDUInt,
+) -> NSTDAllocError {
+    // Make sure the source range is within the vector's bounds.
+    match start.checked_add(len) {
+        Some(end) if end <= vec.len => (),
+        _ => return NSTDAllocError::NSTD_ALLOC_ERROR_INVALID_LAYOUT,
+    }
+    let Some(new_len) = vec.len.checked_add(len) else {
+        return NSTDAllocError::NSTD_ALLOC_ERROR_INVALID_LAYOUT;
+    };
+    // Making sure there's enough space for the extension.
+    let reserved = nstd_vec_reserved(vec);
+    if reserved < len {
+        #[allow(clippy::arithmetic_side_effects)]
+        let errc = nstd_vec_reserve(vec, len - reserved);
+        if errc != NSTD_ALLOC_ERROR_NONE {
+            return errc;
+        }
+    }
+    // Reserving may have reallocated the buffer, so the source pointer is computed afterwards.
+    // SAFETY: The source range is in bounds and never overlaps the vector's reserved memory.
+    #[allow(clippy::arithmetic_side_effects)]
+    unsafe {
+        let src = vec.ptr.add(start * vec.stride).cast();
+        nstd_core_mem_copy(vec.end().cast(), src, len * vec.stride);
+    }
+    vec.len = new_len;
+    NSTD_ALLOC_ERROR_NONE
+}
+
 /// Shortens a vector, keeping the first `len` elements.
 ///
 /// # Note
@@ -1081,11 +1443,8 @@ pub fn nstd_vec_reserve(vec: &mut NSTDVec<'_>, size: NSTDUInt) -> NSTDAllocError
     let Some(bytes_to_alloc) = size.checked_mul(vec.stride) else {
         return NSTDAllocError::NSTD_ALLOC_ERROR_INVALID_LAYOUT;
     };
+    // Vectors of zero-sized values never need to allocate.
     if bytes_to_alloc == 0 {
-        vec.cap = match vec.cap.checked_add(size) {
-            Some(cap) => cap,
-            _ => return NSTDAllocError::NSTD_ALLOC_ERROR_INVALID_LAYOUT,
-        };
         return NSTD_ALLOC_ERROR_NONE;
     }
     // Check if the vector has allocated.